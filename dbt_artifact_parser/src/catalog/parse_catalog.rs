@@ -65,7 +65,7 @@ impl Catalog {
         let mut de = serde_json::Deserializer::from_reader(reader);
         let catalog: Self = serde_path_to_error::deserialize(&mut de)
             .inspect_err(|e| {
-                println!("{}", e.path());
+                eprintln!("{}", e.path());
             })
             .context(format!(
                 "Unable to parse catalog JSON, delete it from {} and regenerate using 'dbt docs generate'\nSee: \x1b]8;;https://docs.getdbt.com/reference/artifacts/catalog-json\x1b\\dbt catalog documentation\x1b]8;;\x1b\\",
@@ -6,13 +6,28 @@ use serde::Deserialize;
 #[allow(dead_code)]
 pub struct ColumnLevelConstraint {
     #[serde(rename = "type")]
-    type_: String,
-    name: Option<String>,
-    expression: Option<String>,
-    warn_unenforced: Option<bool>,
-    warn_unsupported: Option<bool>,
-    to: Option<String>,
-    to_columns: Option<Vec<String>>,
+    pub type_: String,
+    pub name: Option<String>,
+    pub expression: Option<String>,
+    pub warn_unenforced: Option<bool>,
+    pub warn_unsupported: Option<bool>,
+    pub to: Option<String>,
+    pub to_columns: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct ModelLevelConstraint {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub name: Option<String>,
+    pub expression: Option<String>,
+    pub warn_unenforced: Option<bool>,
+    pub warn_unsupported: Option<bool>,
+    pub to: Option<String>,
+    pub to_columns: Option<Vec<String>>,
+    #[serde(default)]
+    pub columns: Vec<String>,
 }
 
 #[derive(Debug, Deserialize)]
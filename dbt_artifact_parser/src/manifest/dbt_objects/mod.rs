@@ -3,7 +3,7 @@ pub mod docs;
 pub mod meta;
 pub mod tags;
 
-pub use column::{Column, ColumnConfig, ColumnLevelConstraint};
+pub use column::{Column, ColumnConfig, ColumnLevelConstraint, ModelLevelConstraint};
 pub use docs::NodeDocs;
 pub use meta::Meta;
 pub use tags::Tags;
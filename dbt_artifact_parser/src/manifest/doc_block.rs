@@ -0,0 +1,28 @@
+use serde::Deserialize;
+
+// A {% docs %} block, referenced in descriptions with {{ doc('name') }}
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct DocBlock {
+    pub name: String,
+    pub resource_type: String,
+    pub package_name: String,
+    pub path: String,
+    pub original_file_path: String,
+    pub unique_id: String,
+    pub block_contents: String,
+}
+
+impl DocBlock {
+    pub const fn get_name(&self) -> &String {
+        &self.name
+    }
+
+    pub const fn get_package_name(&self) -> &String {
+        &self.package_name
+    }
+
+    pub const fn get_relative_path(&self) -> &String {
+        &self.original_file_path
+    }
+}
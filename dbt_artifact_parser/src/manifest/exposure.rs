@@ -4,7 +4,6 @@ use super::dbt_objects::{Meta, Tags};
 
 #[derive(Debug, Deserialize)]
 pub struct ExposureDependsOn {
-    #[allow(dead_code)]
     pub macros: Option<Vec<String>>,
     pub nodes: Option<Vec<String>>,
 }
@@ -2,15 +2,38 @@ use serde::Deserialize;
 
 use super::dbt_objects::Meta;
 
+#[derive(Debug, Deserialize, Default)]
+#[allow(dead_code)]
+pub struct MacroDependsOn {
+    #[serde(default)]
+    pub macros: Vec<String>,
+}
+
+// Argument as documented in the properties (YAML) file
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+pub struct MacroArgument {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: Option<String>,
+    pub description: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
 pub struct Macro {
     pub name: String,
     pub package_name: String,
+    #[serde(default)]
+    pub unique_id: String,
     pub original_file_path: String,
     pub macro_sql: String,
     pub description: Option<String>,
     pub meta: Option<Meta>,
+    #[serde(default)]
+    pub depends_on: MacroDependsOn,
+    #[serde(default)]
+    pub arguments: Vec<MacroArgument>,
 }
 
 impl Macro {
@@ -18,6 +41,10 @@ impl Macro {
         &self.name
     }
 
+    pub const fn get_unique_id(&self) -> &String {
+        &self.unique_id
+    }
+
     pub const fn get_package_name(&self) -> &String {
         &self.package_name
     }
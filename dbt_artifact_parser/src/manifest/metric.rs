@@ -4,4 +4,5 @@ use serde::Deserialize;
 #[allow(dead_code)]
 pub struct Metric {
     pub name: String,
+    pub description: Option<String>,
 }
@@ -1,4 +1,5 @@
 pub mod dbt_objects;
+pub mod doc_block;
 pub mod exposure;
 pub mod group;
 pub mod macro_obj;
@@ -12,9 +13,10 @@ pub mod source;
 pub mod unit_test;
 
 pub use dbt_objects::{Column, Meta, NodeDocs, Tags};
+pub use doc_block::DocBlock;
 pub use exposure::{Exposure, ExposureDependsOn};
 pub use group::{Group, GroupOwner};
-pub use macro_obj::Macro;
+pub use macro_obj::{Macro, MacroArgument, MacroDependsOn};
 pub use materialization::Materialization;
 pub use metric::Metric;
 pub use nodes::Node;
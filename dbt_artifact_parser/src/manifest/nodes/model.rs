@@ -1,3 +1,4 @@
+use super::super::dbt_objects::ModelLevelConstraint;
 use super::node::{CompiledNodeFields, NodeBase};
 use serde::Deserialize;
 
@@ -11,7 +12,7 @@ pub struct Model {
 
     // Model-specific fields
     pub access: Option<String>,
-    pub constraints: Option<Vec<serde_json::Value>>,
+    pub constraints: Option<Vec<ModelLevelConstraint>>,
     pub version: Option<serde_json::Value>,
     pub latest_version: Option<serde_json::Value>,
     pub deprecation_date: Option<String>,
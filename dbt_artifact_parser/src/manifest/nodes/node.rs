@@ -74,6 +74,14 @@ impl Node {
         &self.get_base().original_file_path
     }
 
+    /// Path of the properties (YAML) file relative to the project root, without the `package://` prefix
+    pub fn get_patch_path(&self) -> Option<&str> {
+        self.get_base()
+            .patch_path
+            .as_deref()
+            .map(|path| path.split_once("://").map_or(path, |(_, rest)| rest))
+    }
+
     pub fn get_materialization(&self) -> Option<&Materialization> {
         match self {
             Self::Model(_) => self
@@ -106,6 +114,7 @@ pub struct DependsOn {
 pub struct NodeConfig {
     pub contract: Option<Contract>,
     pub materialized: Option<Materialization>,
+    pub group: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -130,6 +139,8 @@ pub struct NodeBase {
     pub path: String,
     #[serde(default)]
     pub original_file_path: String,
+    // Properties (YAML) file of the node, e.g. `my_project://models/_models.yml`
+    pub patch_path: Option<String>,
     #[serde(default)]
     pub unique_id: String,
     #[serde(default)]
@@ -142,6 +153,8 @@ pub struct NodeBase {
     // Common optional fields
     pub tags: Option<Tags>,
     pub description: Option<String>,
+    // Unique ids of the doc blocks used in the description
+    pub doc_blocks: Option<Vec<String>>,
     pub meta: Option<Meta>,
     pub columns: Option<HashMap<String, Column>>,
     pub config: Option<NodeConfig>,
@@ -38,4 +38,18 @@ impl Test {
             |ns| Cow::Owned(format!("{}::{}", ns, metadata.name)), // Owned if namespaced
         ))
     }
+
+    // Whether the test is the generic test `name`, with or without its namespace,
+    // e.g. `dbt_utils.unique_combination_of_columns` or `unique_combination_of_columns`
+    pub fn matches_name(&self, name: &str) -> bool {
+        let Some(metadata) = self.test_metadata.as_ref() else {
+            return false;
+        };
+        match (name.split_once('.'), metadata.namespace.as_deref()) {
+            (Some((namespace, test_name)), Some(test_namespace)) => {
+                namespace == test_namespace && test_name == metadata.name
+            }
+            _ => name == metadata.name,
+        }
+    }
 }
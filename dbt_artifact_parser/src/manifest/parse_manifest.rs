@@ -1,3 +1,4 @@
+use super::doc_block::DocBlock;
 use super::exposure::Exposure;
 use super::group::Group;
 use super::macro_obj::Macro;
@@ -58,6 +59,8 @@ pub struct Manifest {
     #[serde(default)]
     pub exposures: HashMap<String, Exposure>,
     #[serde(default)]
+    pub docs: HashMap<String, DocBlock>,
+    #[serde(default)]
     pub metrics: HashMap<String, Metric>,
     #[serde(default)]
     pub groups: HashMap<String, Group>,
@@ -90,8 +93,8 @@ impl Manifest {
     // Get tests attached to a specific parent node
     pub fn get_tests_by_parent(&self, parent_unique_id: &str) -> Vec<&Test> {
         self.nodes
-            .iter()
-            .filter_map(|(_, node)| {
+            .values()
+            .filter_map(|node| {
                 if let Node::Test(test) = node {
                     if let Some(attached_node) = &test.attached_node {
                         if attached_node == parent_unique_id {
@@ -104,6 +107,19 @@ impl Manifest {
             .collect()
     }
 
+    // Get tests of all nodes at once, by the unique id of the node they are attached to
+    pub fn get_tests_by_attached_node(&self) -> HashMap<&str, Vec<&Test>> {
+        let mut tests: HashMap<&str, Vec<&Test>> = HashMap::new();
+        for node in self.nodes.values() {
+            if let Node::Test(test) = node {
+                if let Some(attached_node) = &test.attached_node {
+                    tests.entry(attached_node.as_str()).or_default().push(test);
+                }
+            }
+        }
+        tests
+    }
+
     /// Reads a manifest from a file and parses it into a `Manifest`.
     ///
     /// # Errors
@@ -126,7 +142,7 @@ impl Manifest {
 
         let mut manifest: Self = serde_path_to_error::deserialize(&mut de)
             .inspect_err(|e| {
-                println!("{}", e.path());
+                eprintln!("{}", e.path());
             })
             .context(format!(
                 "Unable to parse manifest JSON, delete it from {} and regenerate using eligible dbt commands.\n\
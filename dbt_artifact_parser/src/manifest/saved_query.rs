@@ -11,4 +11,5 @@ pub struct SavedQueryDependsOn {
 #[allow(dead_code)]
 pub struct SavedQuery {
     pub name: String,
+    pub description: Option<String>,
 }
@@ -10,7 +10,11 @@ pub struct Source {
     // Required fields
     pub database: Option<String>,
     pub name: String,
+    // Name of the source group the table belongs to (`source('<source_name>', '<name>')`)
+    #[serde(default)]
+    pub source_name: String,
     pub description: Option<String>,
+    pub doc_blocks: Option<Vec<String>>,
     pub package_name: String,
     pub original_file_path: String,
     pub unique_id: String,
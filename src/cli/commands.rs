@@ -24,6 +24,26 @@ pub enum Commands {
         #[command(flatten)]
         options: RunOptions,
     },
+    /// Fix findings by writing missing properties to the YAML files
+    Fix {
+        #[command(flatten)]
+        options: FixOptions,
+    },
+    /// Generate properties YAML for undocumented objects from the catalog
+    Scaffold {
+        #[command(flatten)]
+        options: ScaffoldOptions,
+    },
+    /// Start a language server (stdio) publishing findings as diagnostics
+    Lsp {
+        #[command(flatten)]
+        options: LspOptions,
+    },
+    /// Show a health report of the project: counts, coverage and the largest objects
+    Stats {
+        #[command(flatten)]
+        options: StatsOptions,
+    },
 }
 
 #[derive(Args, Debug, Clone)]
@@ -35,9 +55,14 @@ pub struct InitOptions {
     /// Configuration format to generate (yml, toml, or pyproject)
     #[arg(long, short = 'f', value_parser = ["yml", "yaml", "toml", "pyproject"], default_value = "yml")]
     pub format: String,
+
+    /// Path to a dbt manifest.json to tailor the generated config to an existing project
+    #[arg(long)]
+    pub from_manifest: Option<String>,
 }
 
 #[derive(Args, Debug)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct RunOptions {
     /// Path to dbt project root directory
     #[arg(long, default_value = ".")]
@@ -61,6 +86,104 @@ pub struct RunOptions {
     /// Hide warnings from the output (only show errors)
     #[arg(long, default_value_t = false)]
     pub hide_warnings: bool,
+
+    /// Re-run the analysis whenever the manifest, catalog or config file changes
+    #[arg(long, short = 'w', default_value_t = false)]
+    pub watch: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct FixOptions {
+    /// Path to dbt project root directory
+    #[arg(long, default_value = ".")]
+    pub entry_point: String,
+
+    #[arg(long, short = 'c')]
+    pub config_file: Option<String>,
+
+    #[arg(long, short = 'm', default_value = "target/manifest.json")]
+    pub manifest_file: String,
+
+    #[arg(long, short = 'g', default_value = "target/catalog.json")]
+    pub catalog_file: String,
+
+    #[arg(long, default_value_t = false)]
+    pub only_manifest: bool,
+
+    /// Show the changes as a unified diff without writing any files
+    #[arg(long, default_value_t = false)]
+    pub dry_run: bool,
+
+    /// Text written for missing descriptions
+    #[arg(long, default_value = "TODO: add a description")]
+    pub placeholder: String,
+}
+
+#[derive(Args, Debug)]
+pub struct ScaffoldOptions {
+    /// Path to dbt project root directory
+    #[arg(long, default_value = ".")]
+    pub entry_point: String,
+
+    #[arg(long, short = 'm', default_value = "target/manifest.json")]
+    pub manifest_file: String,
+
+    #[arg(long, short = 'g', default_value = "target/catalog.json")]
+    pub catalog_file: String,
+
+    /// Show the generated YAML as a unified diff without writing any files
+    #[arg(long, default_value_t = false)]
+    pub dry_run: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct LspOptions {
+    /// Path to dbt project root directory, defaults to the workspace root sent by the editor
+    #[arg(long)]
+    pub entry_point: Option<String>,
+
+    #[arg(long, short = 'c')]
+    pub config_file: Option<String>,
+
+    #[arg(long, short = 'm', default_value = "target/manifest.json")]
+    pub manifest_file: String,
+
+    #[arg(long, short = 'g', default_value = "target/catalog.json")]
+    pub catalog_file: String,
+
+    #[arg(long, default_value_t = false)]
+    pub only_manifest: bool,
+
+    /// Text written for missing descriptions by code actions
+    #[arg(long, default_value = "TODO: add a description")]
+    pub placeholder: String,
+}
+
+#[derive(Args, Debug)]
+pub struct StatsOptions {
+    /// Path to dbt project root directory
+    #[arg(long, default_value = ".")]
+    pub entry_point: String,
+
+    #[arg(long, short = 'c')]
+    pub config_file: Option<String>,
+
+    #[arg(long, short = 'm', default_value = "target/manifest.json")]
+    pub manifest_file: String,
+
+    #[arg(long, short = 'g', default_value = "target/catalog.json")]
+    pub catalog_file: String,
+
+    #[arg(long, default_value_t = false)]
+    pub only_manifest: bool,
+
+    /// Output format of the report
+    #[arg(long, short = 'f', value_parser = ["table", "json", "markdown"], default_value = "table")]
+    pub format: String,
+
+    /// Number of objects listed in the rankings (largest models, most depended-on objects, rules)
+    #[arg(long, default_value_t = 10)]
+    pub top: usize,
 }
 
 #[cfg(test)]
@@ -71,6 +194,7 @@ mod tests {
         InitOptions {
             location: ".".to_string(),
             format: "yml".to_string(),
+            from_manifest: None,
         }
     }
 
@@ -88,6 +212,7 @@ mod tests {
         let options = InitOptions {
             location: "/custom/path".to_string(),
             format: "toml".to_string(),
+            from_manifest: None,
         };
         assert_eq!(options.location, "/custom/path");
         assert_eq!(options.format, "toml");
@@ -98,6 +223,7 @@ mod tests {
         let options = InitOptions {
             location: ".".to_string(),
             format: "pyproject".to_string(),
+            from_manifest: None,
         };
         assert_eq!(options.format, "pyproject");
     }
@@ -112,6 +238,7 @@ mod tests {
             only_manifest: false,
             disable_hyperlinks: false,
             hide_warnings: false,
+            watch: false,
         };
         let debug_str = format!("{options:?}");
         assert!(debug_str.contains("RunOptions"));
@@ -129,6 +256,7 @@ mod tests {
             only_manifest: false,
             disable_hyperlinks: false,
             hide_warnings: false,
+            watch: false,
         };
 
         assert_eq!(options.entry_point, "./");
@@ -146,6 +274,7 @@ mod tests {
             only_manifest: true,
             disable_hyperlinks: false,
             hide_warnings: false,
+            watch: false,
         };
 
         assert_eq!(options.entry_point, "/path/to/project");
@@ -161,7 +290,13 @@ mod tests {
 
         match init_cmd {
             Commands::Init { options: _ } => {}
-            Commands::Run { .. } => panic!("Expected Init variant"),
+            Commands::Run { .. }
+            | Commands::Fix { .. }
+            | Commands::Scaffold { .. }
+            | Commands::Lsp { .. }
+            | Commands::Stats { .. } => {
+                panic!("Expected Init variant")
+            }
         }
 
         let run_cmd = Commands::Run {
@@ -173,12 +308,19 @@ mod tests {
                 only_manifest: false,
                 disable_hyperlinks: false,
                 hide_warnings: false,
+                watch: false,
             },
         };
 
         match run_cmd {
             Commands::Run { options: _ } => {}
-            Commands::Init { .. } => panic!("Expected Run variant"),
+            Commands::Init { .. }
+            | Commands::Fix { .. }
+            | Commands::Scaffold { .. }
+            | Commands::Lsp { .. }
+            | Commands::Stats { .. } => {
+                panic!("Expected Run variant")
+            }
         }
     }
 
@@ -216,6 +358,7 @@ mod tests {
                     only_manifest: false,
                     disable_hyperlinks: false,
                     hide_warnings: false,
+                    watch: false,
                 },
             }),
         };
@@ -248,6 +391,7 @@ mod tests {
                 only_manifest: false,
                 disable_hyperlinks: false,
                 hide_warnings: false,
+                watch: false,
             },
         };
         let debug_str = format!("{run_cmd:?}");
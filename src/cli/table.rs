@@ -8,6 +8,7 @@ use tabled::{
 use terminal_size::{terminal_size, Height as TerminalHeight, Width as TerminalWidth};
 
 use crate::core::config::severity::Severity;
+use crate::core::properties_file::{PropertiesFiles, PropertyRef};
 use std::path::Path;
 
 #[derive(Tabled, PartialEq, Eq, Debug, Clone)]
@@ -22,6 +23,15 @@ pub struct RuleResult {
     pub message: String,
     #[tabled(skip)]
     pub relative_path: Option<String>,
+    /// Line (1-based) in `relative_path`
+    #[tabled(skip)]
+    pub line: Option<usize>,
+    /// Column (1-based) in `relative_path`
+    #[tabled(skip)]
+    pub column: Option<usize>,
+    /// The property the finding is about, resolved to a `line` once the properties files are read
+    #[tabled(skip)]
+    pub property: Option<PropertyRef>,
 }
 
 impl RuleResult {
@@ -39,6 +49,38 @@ impl RuleResult {
             rule_name: rule_name.into(),
             message: message.into(),
             relative_path,
+            line: None,
+            column: None,
+            property: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_property(mut self, property: Option<PropertyRef>) -> Self {
+        self.property = property;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_location(mut self, line: usize, column: usize) -> Self {
+        self.line = Some(line);
+        self.column = Some(column);
+        self
+    }
+
+    /// Point the finding at its property in the properties file, if it can be found there.
+    /// Findings of objects without a properties file keep pointing at the SQL file.
+    pub fn resolve_location(&mut self, files: &PropertiesFiles) {
+        let Some(property) = &self.property else {
+            return;
+        };
+        if let Some((line, column)) = files
+            .get(&property.file)
+            .and_then(|file| file.locate(&property.object, &property.property))
+        {
+            self.relative_path = Some(property.file.clone());
+            self.line = Some(line);
+            self.column = Some(column);
         }
     }
 }
@@ -85,44 +127,7 @@ pub fn show_results_and_exit(
 
         let table_rows: Vec<RuleResult> = sorted_results
             .iter()
-            .map(|(row, _)| {
-                let mut new_row = (*row).clone();
-                if disable_hyperlinks {
-                    return new_row;
-                }
-                // Add file hyperlinks to message if relative_path is present
-                if let Some(ref path) = row.relative_path {
-                    let entry = entry_point.trim_end_matches('/');
-                    let path = path.trim_start_matches('/');
-                    let full_path = format!("{entry}/{path}");
-
-                    let abs_path = Path::new(&full_path)
-                        .canonicalize()
-                        .map(|p| p.to_string_lossy().into_owned())
-                        .unwrap_or(full_path);
-
-                    // Convert to proper file URL format
-                    // On Windows, paths like C:\foo need to become file:///C:/foo to follow RFC 8089
-                    // canonicalize() on Windows may return extended-length paths with \\?\ prefix
-                    let file_url = if cfg!(windows) {
-                        let path_with_slashes = abs_path.replace('\\', "/");
-                        let clean_path = path_with_slashes
-                            .strip_prefix("//?/")
-                            .or_else(|| path_with_slashes.strip_prefix("//./"))
-                            .unwrap_or(&path_with_slashes);
-                        format!("file:///{clean_path}")
-                    } else {
-                        format!("file://{abs_path}")
-                    };
-
-                    new_row.message = format!(
-                        "\x1b]8;;{url}\x1b\\{text}\x1b]8;;\x1b\\",
-                        url = file_url,
-                        text = row.message
-                    );
-                }
-                new_row
-            })
+            .map(|(row, _)| table_row(row, entry_point, disable_hyperlinks))
             .collect();
 
         let (width, _) = get_terminal_size();
@@ -147,6 +152,59 @@ pub fn show_results_and_exit(
     i32::from(error_count > 0)
 }
 
+/// Show where the finding is below its message, as a file hyperlink unless disabled
+fn table_row(row: &RuleResult, entry_point: &str, disable_hyperlinks: bool) -> RuleResult {
+    let mut new_row = row.clone();
+    let Some(path) = &row.relative_path else {
+        return new_row;
+    };
+    let location = location_text(path, row.line, row.column);
+    let location = if disable_hyperlinks {
+        location
+    } else {
+        format!(
+            "\x1b]8;;{url}\x1b\\{location}\x1b]8;;\x1b\\",
+            url = file_url(entry_point, path)
+        )
+    };
+    new_row.message = format!("{}\n{location}", row.message);
+    new_row
+}
+
+/// `path:line:column` as understood by editors and terminals, as far as the position is known
+fn location_text(path: &str, line: Option<usize>, column: Option<usize>) -> String {
+    match (line, column) {
+        (Some(line), Some(column)) => format!("{path}:{line}:{column}"),
+        (Some(line), None) => format!("{path}:{line}"),
+        _ => path.to_string(),
+    }
+}
+
+fn file_url(entry_point: &str, path: &str) -> String {
+    let entry = entry_point.trim_end_matches('/');
+    let path = path.trim_start_matches('/');
+    let full_path = format!("{entry}/{path}");
+
+    let abs_path = Path::new(&full_path)
+        .canonicalize()
+        .map(|p| p.to_string_lossy().into_owned())
+        .unwrap_or(full_path);
+
+    // Convert to proper file URL format
+    // On Windows, paths like C:\foo need to become file:///C:/foo to follow RFC 8089
+    // canonicalize() on Windows may return extended-length paths with \\?\ prefix
+    if cfg!(windows) {
+        let path_with_slashes = abs_path.replace('\\', "/");
+        let clean_path = path_with_slashes
+            .strip_prefix("//?/")
+            .or_else(|| path_with_slashes.strip_prefix("//./"))
+            .unwrap_or(&path_with_slashes);
+        format!("file:///{clean_path}")
+    } else {
+        format!("file://{abs_path}")
+    }
+}
+
 fn get_terminal_size() -> (usize, usize) {
     if let Some((TerminalWidth(width), TerminalHeight(height))) = terminal_size() {
         (width as usize, height as usize)
@@ -348,6 +406,45 @@ mod tests {
         assert_eq!(exit_code, 1);
     }
 
+    #[test]
+    fn test_table_row_shows_location() {
+        let result = RuleResult::new(
+            &Severity::Error,
+            "Model",
+            "has_description",
+            "Missing description",
+            Some("models/_models.yml".to_string()),
+        )
+        .with_location(12, 5);
+
+        let row = table_row(&result, ".", true);
+        assert_eq!(row.message, "Missing description\nmodels/_models.yml:12:5");
+
+        // The location is the hyperlink text
+        let row = table_row(&result, "/project", false);
+        assert_eq!(
+            row.message,
+            "Missing description\n\x1b]8;;file:///project/models/_models.yml\x1b\\models/_models.yml:12:5\x1b]8;;\x1b\\"
+        );
+
+        // Findings without a location keep their message
+        let row = table_row(&make_error_result("Macro", "rule_a", "message"), ".", false);
+        assert_eq!(row.message, "message");
+    }
+
+    #[test]
+    fn test_location_text() {
+        assert_eq!(location_text("models/a.sql", None, None), "models/a.sql");
+        assert_eq!(
+            location_text("models/a.yml", Some(3), None),
+            "models/a.yml:3"
+        );
+        assert_eq!(
+            location_text("models/a.yml", Some(3), Some(7)),
+            "models/a.yml:3:7"
+        );
+    }
+
     #[test]
     fn test_get_terminal_size_returns_default_in_test() {
         let (width, height) = get_terminal_size();
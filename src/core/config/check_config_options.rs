@@ -1,6 +1,9 @@
-use regex::Regex;
+use crate::core::config::includes_excludes::glob_match;
+use crate::core::manifest::dbt_objects::Column;
+use regex::{Regex, RegexBuilder};
 use serde::de::{self, Deserializer};
 use serde::Deserialize;
+use std::collections::HashMap;
 use strum_macros::{AsRefStr, EnumString};
 
 // HasTags
@@ -52,10 +55,313 @@ pub fn default_allowed_test_names() -> Vec<String> {
     ]
 }
 
+// HasPrimaryKeyTest
+pub fn default_unique_test_names() -> Vec<String> {
+    vec![
+        "unique".to_string(),
+        "dbt_utils.unique_combination_of_columns".to_string(),
+        "dbt_expectations.expect_compound_columns_to_be_unique".to_string(),
+        "dbt_constraints.primary_key".to_string(),
+    ]
+}
+
+pub fn default_not_null_test_names() -> Vec<String> {
+    vec![
+        "not_null".to_string(),
+        "dbt_constraints.primary_key".to_string(),
+    ]
+}
+
+// MacroIsUsed: macros dbt calls itself, overrides of dbt's own macros and adapter
+// implementations (`default__`, `snowflake__`, ...) called through `adapter.dispatch`
+/// # Panics
+/// Never, the default patterns are valid regexes
+pub fn default_macro_exemptions() -> Vec<ColumnNamePattern> {
+    [
+        "^generate_.*_name$",
+        "^test_.*",
+        "^materialization_.*",
+        "^.+__.+$",
+    ]
+    .into_iter()
+    .map(|pattern| ColumnNamePattern::Regex(Regex::new(pattern).expect("valid regex")))
+    .collect()
+}
+
+// NoDeprecatedReferences
+/// A deprecated macro or node, by unique id or a regex of unique ids
+#[derive(Debug, Deserialize)]
+pub struct DeprecatedReference {
+    pub unique_id: ColumnNamePattern,
+    /// What consumers should use instead
+    pub replacement: Option<String>,
+}
+
+// ModelVersions
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+// Findings about versioned models that can be allowed
+pub enum VersionIssue {
+    /// A version other than the latest without a `deprecation_date`
+    MissingDeprecationDate,
+    /// A version whose `deprecation_date` has passed
+    PastDeprecationDate,
+    /// A ref to a version that has a `deprecation_date`
+    DeprecatedRefs,
+    /// Contract columns removed or with another data type than in the previous version
+    BreakingChanges,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct VersionChecks {
+    /// Maximum number of versions that are not past their deprecation date
+    #[serde(default)]
+    pub max_live_versions: Option<usize>,
+    #[serde(default)]
+    pub allow: Vec<VersionIssue>,
+}
+
+impl VersionChecks {
+    pub fn allows(&self, issue: &VersionIssue) -> bool {
+        self.allow.contains(issue)
+    }
+}
+
+// HasRelationshipTests
+/// # Panics
+/// Never, the default pattern is a valid regex
+pub fn default_foreign_key_columns() -> Vec<ColumnNamePattern> {
+    vec![ColumnNamePattern::Regex(
+        Regex::new(".*_id$").expect("valid regex"),
+    )]
+}
+
+pub fn default_relationship_test_names() -> Vec<String> {
+    vec![
+        "relationships".to_string(),
+        "dbt_constraints.foreign_key".to_string(),
+    ]
+}
+
+// ColumnHasTests
+/// Selects the columns a rule is about. A column is selected when it matches every criterion that is set,
+/// for a list any of its values.
+#[derive(Debug, Default, Deserialize)]
+pub struct ColumnSelector {
+    /// Column names, in the same format as `ColumnNamePattern`
+    #[serde(default)]
+    pub columns: Vec<ColumnNamePattern>,
+    /// Data types (case-insensitive), `varchar` also selects `varchar(256)`
+    #[serde(default)]
+    pub data_types: Vec<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Meta keys with the value they must have
+    #[serde(default)]
+    pub meta: HashMap<String, serde_json::Value>,
+}
+
+impl ColumnSelector {
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+            && self.data_types.is_empty()
+            && self.tags.is_empty()
+            && self.meta.is_empty()
+    }
+
+    pub fn matches(&self, column: &Column) -> bool {
+        let data_type = column
+            .data_type
+            .as_deref()
+            .map(|data_type| data_type.split('(').next().unwrap_or(data_type).trim());
+        (self.columns.is_empty()
+            || self
+                .columns
+                .iter()
+                .any(|pattern| pattern.matches(&column.name)))
+            && (self.data_types.is_empty()
+                || data_type.is_some_and(|data_type| {
+                    self.data_types
+                        .iter()
+                        .any(|wanted| wanted.eq_ignore_ascii_case(data_type))
+                }))
+            && (self.tags.is_empty() || self.tags.iter().any(|tag| column.tags.contains(tag)))
+            && self.meta.iter().all(|(key, value)| {
+                column
+                    .meta
+                    .as_ref()
+                    .and_then(|meta| meta.0.get(key))
+                    .is_some_and(|actual| actual == value)
+            })
+    }
+}
+
+// DescriptionQuality
+/// A regex that is matched case-insensitively
+#[derive(Debug, Clone)]
+pub struct CaseInsensitiveRegex(pub Regex);
+
+impl<'de> Deserialize<'de> for CaseInsensitiveRegex {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        RegexBuilder::new(&s)
+            .case_insensitive(true)
+            .build()
+            .map(Self)
+            .map_err(de::Error::custom)
+    }
+}
+
+/// What a description needs besides being present
+#[derive(Debug, Default, Deserialize)]
+pub struct DescriptionCriteria {
+    /// Minimum number of characters, 0 to skip
+    #[serde(default)]
+    pub min_length: usize,
+    /// Minimum number of words, 0 to skip
+    #[serde(default)]
+    pub min_words: usize,
+    #[serde(default = "default_forbidden_patterns")]
+    pub forbidden_patterns: Vec<CaseInsensitiveRegex>,
+    /// Allow descriptions that say nothing more than the name of the object
+    #[serde(default)]
+    pub allow_restating_name: bool,
+    /// Require descriptions to end with `.`, `!` or `?`
+    #[serde(default)]
+    pub require_punctuation: bool,
+    /// Also check the descriptions of the columns
+    #[serde(default)]
+    pub include_columns: bool,
+}
+
+/// # Panics
+/// Never, the default patterns are valid regexes
+pub fn default_forbidden_patterns() -> Vec<CaseInsensitiveRegex> {
+    [
+        // Starts with a placeholder word, e.g. "TODO: add a description"
+        r"^\W*(todo|tbd|tba|fixme|wip|xxx)\b",
+        // Only a filler word or punctuation
+        r"^\W*(n/?a|none|null|empty|placeholder|description|tmp|test|\.+|-+|\?+)\W*$",
+    ]
+    .into_iter()
+    .map(|pattern| {
+        CaseInsensitiveRegex(
+            RegexBuilder::new(pattern)
+                .case_insensitive(true)
+                .build()
+                .expect("valid regex"),
+        )
+    })
+    .collect()
+}
+
 pub const fn default_max_code_lines() -> usize {
     150
 }
 
+// MaxChildren & MaxParents
+#[derive(Debug, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+// Resource types counted as children or parents
+pub enum DagResourceType {
+    Models,
+    Seeds,
+    Snapshots,
+    Sources,
+    Analyses,
+    Tests,
+    UnitTests,
+    Exposures,
+    SemanticModels,
+    Metrics,
+}
+
+impl DagResourceType {
+    pub fn matches(&self, resource_type: &str) -> bool {
+        match self {
+            Self::Models => resource_type == "model",
+            Self::Seeds => resource_type == "seed",
+            Self::Snapshots => resource_type == "snapshot",
+            Self::Sources => resource_type == "source",
+            Self::Analyses => resource_type == "analysis",
+            Self::Tests => resource_type == "test",
+            Self::UnitTests => resource_type == "unit_test",
+            Self::Exposures => resource_type == "exposure",
+            Self::SemanticModels => resource_type == "semantic_model",
+            Self::Metrics => resource_type == "metric",
+        }
+    }
+}
+
+pub fn default_children_resource_types() -> Vec<DagResourceType> {
+    vec![
+        DagResourceType::Models,
+        DagResourceType::Snapshots,
+        DagResourceType::Exposures,
+    ]
+}
+
+pub fn default_parents_resource_types() -> Vec<DagResourceType> {
+    vec![
+        DagResourceType::Models,
+        DagResourceType::Seeds,
+        DagResourceType::Snapshots,
+        DagResourceType::Sources,
+    ]
+}
+
+pub const fn default_max_children() -> usize {
+    10
+}
+
+pub const fn default_max_parents() -> usize {
+    7
+}
+
+/// A threshold for the objects of a layer, overriding the rule wide `max`
+#[derive(Debug, Deserialize)]
+pub struct LayerThreshold {
+    #[serde(flatten)]
+    pub layer: Layer,
+    pub max: usize,
+}
+
+// SourcesOnlyInStaging
+pub fn default_staging_prefixes() -> Vec<String> {
+    vec!["stg_".to_string()]
+}
+
+// AllowedDependencies
+/// A layer of the project (e.g. staging, intermediate, marts).
+/// An object belongs to the first layer with a matching path, name prefix, tag or resource type.
+#[derive(Debug, Deserialize)]
+pub struct Layer {
+    pub name: String,
+    /// Path patterns, in the same format as `includes`
+    #[serde(default)]
+    pub paths: Vec<String>,
+    #[serde(default)]
+    pub prefixes: Vec<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// e.g. `source` or `seed`
+    #[serde(default)]
+    pub resource_types: Vec<String>,
+}
+
+impl Layer {
+    pub fn matches(&self, resource_type: &str, name: &str, path: &str, tags: &[String]) -> bool {
+        let path = path.replace('\\', "/");
+        self.resource_types.iter().any(|t| t == resource_type)
+            || self.paths.iter().any(|pattern| glob_match(pattern, &path))
+            || self.prefixes.iter().any(|prefix| name.starts_with(prefix))
+            || self.tags.iter().any(|tag| tags.contains(tag))
+    }
+}
+
 /// `ColumnNamePattern` for `columns_canonical_name` rule
 /// Parse regex if the string looks like a regex pattern
 /// Otherwise, treat it as a literal string
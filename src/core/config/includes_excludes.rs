@@ -98,7 +98,7 @@ fn glob_to_regex(pattern: &str) -> String {
 }
 
 /// Match a glob pattern against a path
-fn glob_match(pattern: &str, path: &str) -> bool {
+pub fn glob_match(pattern: &str, path: &str) -> bool {
     let regex_pattern = glob_to_regex(pattern);
     Regex::new(&regex_pattern).is_ok_and(|re| re.is_match(path))
 }
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::vec;
 
 use anyhow::Context;
@@ -7,12 +8,18 @@ use serde::Deserialize;
 use crate::core::config::applies_to::AppliesTo;
 use crate::core::config::applies_to::RuleTarget;
 use crate::core::config::check_config_options::{
-    default_allowed_references, default_allowed_test_names, default_max_code_lines,
-    HasTagsCriteria, OrphanedReferenceType,
+    default_allowed_references, default_allowed_test_names, default_children_resource_types,
+    default_foreign_key_columns, default_macro_exemptions, default_max_children,
+    default_max_code_lines, default_max_parents, default_not_null_test_names,
+    default_parents_resource_types, default_relationship_test_names, default_staging_prefixes,
+    default_unique_test_names, ColumnNamePattern, ColumnSelector, DagResourceType,
+    DeprecatedReference, DescriptionCriteria, HasTagsCriteria, Layer, LayerThreshold,
+    OrphanedReferenceType, VersionChecks,
 };
 use crate::core::config::naming_convention::NamingConvention;
 use crate::core::config::severity::Severity;
 use crate::core::config::Materialization;
+use crate::core::properties_file::Property;
 use strum_macros::{AsRefStr, EnumIter, EnumString};
 
 #[derive(Debug, Deserialize, EnumIter, AsRefStr, EnumString)]
@@ -20,6 +27,10 @@ use strum_macros::{AsRefStr, EnumIter, EnumString};
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum ManifestSpecificRuleConfig {
     HasDescription {},
+    DescriptionQuality {
+        #[serde(flatten)]
+        criteria: DescriptionCriteria,
+    },
     NameConvention {
         #[serde(rename = "pattern")]
         convention: NamingConvention,
@@ -37,6 +48,50 @@ pub enum ManifestSpecificRuleConfig {
         #[serde(default = "default_allowed_test_names")]
         allowed_test_names: Vec<String>,
     },
+    HasPrimaryKeyTest {
+        // Tests that make the columns they test unique together
+        #[serde(default = "default_unique_test_names")]
+        unique_test_names: Vec<String>,
+        #[serde(default = "default_not_null_test_names")]
+        not_null_test_names: Vec<String>,
+    },
+    HasRelationshipTests {
+        // Columns that look like a foreign key
+        #[serde(default = "default_foreign_key_columns")]
+        columns: Vec<ColumnNamePattern>,
+        #[serde(default = "default_relationship_test_names")]
+        allowed_test_names: Vec<String>,
+        #[serde(default)]
+        exemptions: Vec<ColumnNamePattern>,
+    },
+    ColumnHasTests {
+        #[serde(flatten)]
+        selector: ColumnSelector,
+        required_tests: Vec<String>,
+    },
+    MacroIsUsed {
+        #[serde(default = "default_macro_exemptions")]
+        exemptions: Vec<ColumnNamePattern>,
+    },
+    MacroArgumentsDocumented {
+        #[serde(default)]
+        require_types: bool,
+    },
+    NoDeprecatedReferences {
+        #[serde(default)]
+        deprecated: Vec<DeprecatedReference>,
+    },
+    ModelVersions {
+        #[serde(flatten)]
+        checks: VersionChecks,
+    },
+    UsesDocBlocks {
+        #[serde(default)]
+        columns: Vec<ColumnNamePattern>,
+        // Columns that appear in more than this number of models
+        #[serde(default)]
+        used_in_more_than: Option<usize>,
+    },
     HasContractEnforced {},
     HasMetadataKeys {
         required_keys: Vec<String>,
@@ -48,12 +103,87 @@ pub enum ManifestSpecificRuleConfig {
         #[serde(default = "default_max_code_lines")]
         max_lines: usize,
     },
+    AllowedDependencies {
+        layers: Vec<Layer>,
+        // Layer name -> layers it may depend on. Layers without an entry may depend on anything.
+        #[serde(default)]
+        allowed: HashMap<String, Vec<String>>,
+    },
+    SourcesOnlyInStaging {
+        #[serde(default)]
+        staging_paths: Vec<String>,
+        #[serde(default = "default_staging_prefixes")]
+        staging_prefixes: Vec<String>,
+    },
+    MaxChildren {
+        #[serde(default = "default_max_children")]
+        max: usize,
+        #[serde(default = "default_children_resource_types")]
+        resource_types: Vec<DagResourceType>,
+        #[serde(default)]
+        layers: Vec<LayerThreshold>,
+    },
+    MaxParents {
+        #[serde(default = "default_max_parents")]
+        max: usize,
+        #[serde(default = "default_parents_resource_types")]
+        resource_types: Vec<DagResourceType>,
+        #[serde(default)]
+        layers: Vec<LayerThreshold>,
+    },
+    RedundantDependencies {
+        // Upstream objects (name or unique id) that may be depended on both directly and indirectly
+        #[serde(default)]
+        exemptions: Vec<String>,
+    },
+    MaxDagDepth {
+        // Longest path from the start of the DAG, in edges
+        #[serde(default)]
+        max_depth: Option<usize>,
+        // Longest chain of consecutive view/ephemeral models
+        #[serde(default)]
+        max_chained_views: Option<usize>,
+    },
+    NoHardcodedReferences {},
 }
 
 impl ManifestSpecificRuleConfig {
     pub fn as_str(&self) -> &str {
         self.as_ref()
     }
+
+    /// The property in the properties file a finding of this rule is about.
+    /// `None` for rules about the SQL of an object, their findings stay on the SQL file.
+    pub const fn property(&self) -> Option<Property> {
+        match self {
+            Self::HasDescription {} | Self::DescriptionQuality { .. } => {
+                Some(Property::Description)
+            }
+            Self::NameConvention { .. } => Some(Property::Entry),
+            Self::HasTags { .. } => Some(Property::Config("tags")),
+            Self::HasUniqueTest { .. }
+            | Self::HasPrimaryKeyTest { .. }
+            | Self::HasRelationshipTests { .. }
+            | Self::ColumnHasTests { .. }
+            | Self::UsesDocBlocks { .. } => Some(Property::Columns),
+            Self::HasContractEnforced {} => Some(Property::Config("contract")),
+            Self::HasMetadataKeys { .. } => Some(Property::Config("meta")),
+            Self::IsNotOrphaned { .. }
+            | Self::MacroIsUsed { .. }
+            | Self::MacroArgumentsDocumented { .. }
+            | Self::NoDeprecatedReferences { .. }
+            | Self::ModelVersions { .. }
+            | Self::HasRefs {}
+            | Self::MaxCodeLines { .. }
+            | Self::AllowedDependencies { .. }
+            | Self::SourcesOnlyInStaging { .. }
+            | Self::MaxChildren { .. }
+            | Self::MaxParents { .. }
+            | Self::RedundantDependencies { .. }
+            | Self::MaxDagDepth { .. }
+            | Self::NoHardcodedReferences {} => None,
+        }
+    }
 }
 
 const fn manifest_default_severity() -> Severity {
@@ -168,9 +298,85 @@ impl ManifestRule {
 
         Ok(())
     }
+
+    /// Validate the rule specific options
+    /// # Errors
+    /// Returns an error if `allowed_dependencies` refers to a layer that is not defined,
+    /// or if `max_dag_depth` has no thresholds
+    pub fn validate_options(&self) -> Result<()> {
+        if matches!(
+            self.rule,
+            ManifestSpecificRuleConfig::MaxDagDepth {
+                max_depth: None,
+                max_chained_views: None,
+            }
+        ) {
+            return Err(anyhow::anyhow!(
+                "Rule '{}' needs at least one of 'max_depth' or 'max_chained_views'",
+                self.get_name()
+            ));
+        }
+
+        if let ManifestSpecificRuleConfig::ColumnHasTests { selector, .. } = &self.rule {
+            if selector.is_empty() {
+                return Err(anyhow::anyhow!(
+                    "Rule '{}' needs at least one of 'columns', 'data_types', 'tags' or 'meta' to select columns",
+                    self.get_name()
+                ));
+            }
+        }
+
+        if let ManifestSpecificRuleConfig::UsesDocBlocks {
+            columns,
+            used_in_more_than: None,
+        } = &self.rule
+        {
+            if columns.is_empty() {
+                return Err(anyhow::anyhow!(
+                    "Rule '{}' needs at least one of 'columns' or 'used_in_more_than' to select columns",
+                    self.get_name()
+                ));
+            }
+        }
+
+        if let ManifestSpecificRuleConfig::AllowedDependencies { layers, allowed } = &self.rule {
+            let is_defined = |name: &String| layers.iter().any(|layer| &layer.name == name);
+            let mut undefined: Vec<&String> = allowed
+                .iter()
+                .flat_map(|(layer, dependencies)| std::iter::once(layer).chain(dependencies))
+                .filter(|name| !is_defined(name))
+                .collect();
+            undefined.sort_unstable();
+            undefined.dedup();
+            if !undefined.is_empty() {
+                return Err(anyhow::anyhow!(
+                    "Undefined layers {:?} in 'allowed' of rule '{}'. Defined layers are: {:?}",
+                    undefined,
+                    self.get_name(),
+                    layers.iter().map(|layer| &layer.name).collect::<Vec<_>>()
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+// Rules about the DAG between nodes only apply to node objects
+const fn nodes_only(node_objects: Vec<RuleTarget>) -> AppliesTo {
+    AppliesTo {
+        node_objects,
+        source_objects: vec![],
+        unit_test_objects: vec![],
+        macro_objects: vec![],
+        exposure_objects: vec![],
+        semantic_model_objects: vec![],
+        custom_objects: vec![],
+    }
 }
 
 // default options if applies_to is not set
+// A lookup table with an arm per rule, it grows with every rule
+#[allow(clippy::too_many_lines)]
 pub fn default_applies_to_for_manifest_rule(rule_type: &ManifestSpecificRuleConfig) -> AppliesTo {
     match rule_type {
         // has_description
@@ -183,6 +389,12 @@ pub fn default_applies_to_for_manifest_rule(rule_type: &ManifestSpecificRuleConf
             semantic_model_objects: vec![RuleTarget::SemanticModels],
             custom_objects: vec![],
         },
+        ManifestSpecificRuleConfig::DescriptionQuality { .. } => AppliesTo {
+            source_objects: vec![RuleTarget::Sources],
+            macro_objects: vec![RuleTarget::Macros],
+            exposure_objects: vec![RuleTarget::Exposures],
+            ..nodes_only(vec![RuleTarget::Models])
+        },
         // name_convention
         ManifestSpecificRuleConfig::NameConvention { .. } => AppliesTo {
             node_objects: vec![
@@ -223,6 +435,15 @@ pub fn default_applies_to_for_manifest_rule(rule_type: &ManifestSpecificRuleConf
             semantic_model_objects: vec![],
             custom_objects: vec![],
         },
+        ManifestSpecificRuleConfig::NoDeprecatedReferences { .. } => AppliesTo {
+            macro_objects: vec![RuleTarget::Macros],
+            exposure_objects: vec![RuleTarget::Exposures],
+            ..nodes_only(vec![
+                RuleTarget::Models,
+                RuleTarget::Snapshots,
+                RuleTarget::Analyses,
+            ])
+        },
         // has_contract_enforced
         ManifestSpecificRuleConfig::HasContractEnforced { .. } => AppliesTo {
             node_objects: vec![RuleTarget::Models],
@@ -242,6 +463,11 @@ pub fn default_applies_to_for_manifest_rule(rule_type: &ManifestSpecificRuleConf
             semantic_model_objects: vec![],
             custom_objects: vec![],
         },
+        ManifestSpecificRuleConfig::MacroIsUsed { .. }
+        | ManifestSpecificRuleConfig::MacroArgumentsDocumented { .. } => AppliesTo {
+            macro_objects: vec![RuleTarget::Macros],
+            ..nodes_only(vec![])
+        },
         // has_unique_test & has_metadata_keys
         ManifestSpecificRuleConfig::HasUniqueTest { .. }
         | ManifestSpecificRuleConfig::HasMetadataKeys { .. } => AppliesTo {
@@ -266,10 +492,36 @@ pub fn default_applies_to_for_manifest_rule(rule_type: &ManifestSpecificRuleConf
             semantic_model_objects: vec![],
             custom_objects: vec![],
         },
+        ManifestSpecificRuleConfig::AllowedDependencies { .. }
+        | ManifestSpecificRuleConfig::NoHardcodedReferences {} => {
+            nodes_only(vec![RuleTarget::Models, RuleTarget::Snapshots])
+        }
+        ManifestSpecificRuleConfig::SourcesOnlyInStaging { .. } => AppliesTo {
+            source_objects: vec![RuleTarget::Sources],
+            ..nodes_only(vec![RuleTarget::Models, RuleTarget::Snapshots])
+        },
+        ManifestSpecificRuleConfig::MaxChildren { .. } => AppliesTo {
+            source_objects: vec![RuleTarget::Sources],
+            ..nodes_only(vec![
+                RuleTarget::Models,
+                RuleTarget::Seeds,
+                RuleTarget::Snapshots,
+            ])
+        },
+        ManifestSpecificRuleConfig::ModelVersions { .. }
+        | ManifestSpecificRuleConfig::HasPrimaryKeyTest { .. }
+        | ManifestSpecificRuleConfig::HasRelationshipTests { .. }
+        | ManifestSpecificRuleConfig::ColumnHasTests { .. }
+        | ManifestSpecificRuleConfig::UsesDocBlocks { .. }
+        | ManifestSpecificRuleConfig::MaxParents { .. }
+        | ManifestSpecificRuleConfig::RedundantDependencies { .. }
+        | ManifestSpecificRuleConfig::MaxDagDepth { .. } => nodes_only(vec![RuleTarget::Models]),
     }
 }
 
 // All options a user can choose
+// A lookup table with an arm per rule, it grows with every rule
+#[allow(clippy::too_many_lines)]
 fn applies_to_options_for_manifest_rule(rule_type: &ManifestSpecificRuleConfig) -> AppliesTo {
     match rule_type {
         // has_description
@@ -297,6 +549,13 @@ fn applies_to_options_for_manifest_rule(rule_type: &ManifestSpecificRuleConfig)
             semantic_model_objects: vec![RuleTarget::SemanticModels],
             custom_objects: vec![],
         },
+        // The defaults of these rules are all options
+        ManifestSpecificRuleConfig::MacroIsUsed { .. }
+        | ManifestSpecificRuleConfig::MacroArgumentsDocumented { .. }
+        | ManifestSpecificRuleConfig::NoDeprecatedReferences { .. }
+        | ManifestSpecificRuleConfig::MaxChildren { .. } => {
+            default_applies_to_for_manifest_rule(rule_type)
+        }
         // has_tags
         ManifestSpecificRuleConfig::HasTags { .. } => AppliesTo {
             node_objects: vec![
@@ -340,6 +599,25 @@ fn applies_to_options_for_manifest_rule(rule_type: &ManifestSpecificRuleConfig)
             semantic_model_objects: vec![],
             custom_objects: vec![],
         },
+        ManifestSpecificRuleConfig::ModelVersions { .. }
+        | ManifestSpecificRuleConfig::HasPrimaryKeyTest { .. } => {
+            nodes_only(vec![RuleTarget::Models])
+        }
+        ManifestSpecificRuleConfig::MaxDagDepth { .. }
+        | ManifestSpecificRuleConfig::NoHardcodedReferences {} => {
+            nodes_only(vec![RuleTarget::Models, RuleTarget::Snapshots])
+        }
+        ManifestSpecificRuleConfig::DescriptionQuality { .. } => AppliesTo {
+            node_objects: vec![RuleTarget::Models, RuleTarget::Seeds, RuleTarget::Snapshots],
+            ..default_applies_to_for_manifest_rule(rule_type)
+        },
+        ManifestSpecificRuleConfig::HasRelationshipTests { .. }
+        | ManifestSpecificRuleConfig::ColumnHasTests { .. }
+        | ManifestSpecificRuleConfig::UsesDocBlocks { .. } => nodes_only(vec![
+            RuleTarget::Models,
+            RuleTarget::Seeds,
+            RuleTarget::Snapshots,
+        ]),
         ManifestSpecificRuleConfig::HasMetadataKeys { .. } => AppliesTo {
             node_objects: vec![RuleTarget::Models, RuleTarget::Seeds, RuleTarget::Snapshots],
             source_objects: vec![RuleTarget::Sources],
@@ -371,6 +649,21 @@ fn applies_to_options_for_manifest_rule(rule_type: &ManifestSpecificRuleConfig)
             semantic_model_objects: vec![RuleTarget::SemanticModels],
             custom_objects: vec![],
         },
+        ManifestSpecificRuleConfig::AllowedDependencies { .. }
+        | ManifestSpecificRuleConfig::MaxParents { .. }
+        | ManifestSpecificRuleConfig::RedundantDependencies { .. } => nodes_only(vec![
+            RuleTarget::Models,
+            RuleTarget::Snapshots,
+            RuleTarget::Analyses,
+        ]),
+        ManifestSpecificRuleConfig::SourcesOnlyInStaging { .. } => AppliesTo {
+            source_objects: vec![RuleTarget::Sources],
+            ..nodes_only(vec![
+                RuleTarget::Models,
+                RuleTarget::Snapshots,
+                RuleTarget::Analyses,
+            ])
+        },
     }
 }
 
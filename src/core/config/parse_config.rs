@@ -1,4 +1,5 @@
 use crate::core::config::manifest_rule::ManifestRule;
+use crate::core::config::project_rule::ProjectRule;
 use crate::core::config::{
     catalog_rule::default_applies_to_for_catalog_rule, catalog_rule::CatalogRule,
     manifest_rule::default_applies_to_for_manifest_rule,
@@ -48,6 +49,7 @@ impl ConfigFile {
 pub struct Config {
     pub manifest_tests: Option<Vec<ManifestRule>>,
     pub catalog_tests: Option<Vec<CatalogRule>>,
+    pub project_tests: Option<Vec<ProjectRule>>,
 }
 
 #[derive(Deserialize)]
@@ -236,6 +238,9 @@ impl Config {
                 rule.normalize_includes_excludes();
             }
         }
+        for rule in self.project_tests.iter_mut().flatten() {
+            rule.normalize_includes_excludes();
+        }
     }
     // Validate each manifest rule's applies_to targets
     //  # Errors
@@ -247,6 +252,7 @@ impl Config {
         if let Some(rules) = &self.manifest_tests {
             for rule in rules {
                 rule.validate_applies_to()?;
+                rule.validate_options()?;
             }
         }
         if let Some(rules) = &self.catalog_tests {
@@ -254,6 +260,9 @@ impl Config {
                 rule.validate_applies_to()?;
             }
         }
+        for rule in self.project_tests.iter().flatten() {
+            rule.validate_options()?;
+        }
         Ok(())
     }
 }
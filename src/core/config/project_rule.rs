@@ -0,0 +1,119 @@
+use anyhow::Result;
+use serde::Deserialize;
+
+use crate::core::config::severity::Severity;
+use strum_macros::{AsRefStr, EnumIter, EnumString};
+
+// Rules about the project as a whole, their findings aren't tied to a single object
+#[derive(Debug, Deserialize, EnumIter, AsRefStr, EnumString)]
+#[strum(serialize_all = "snake_case")]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ProjectSpecificRuleConfig {
+    DocumentationCoverage {
+        min_percent: f64,
+        #[serde(default)]
+        group_by: Option<CoverageGroupBy>,
+    },
+    TestCoverage {
+        min_percent: f64,
+        #[serde(default)]
+        group_by: Option<CoverageGroupBy>,
+    },
+    DuplicateDescriptions {},
+    ConsistentColumnDescriptions {
+        // Group columns by the canonical names of `columns_canonical_name` catalog rules
+        #[serde(default)]
+        use_canonical_names: bool,
+        #[serde(default)]
+        require_doc_blocks: bool,
+    },
+    UnusedDocBlocks {},
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CoverageGroupBy {
+    // Directory of the SQL file
+    Directory,
+    // dbt group the model belongs to
+    Group,
+}
+
+impl ProjectSpecificRuleConfig {
+    pub fn as_str(&self) -> &str {
+        self.as_ref()
+    }
+}
+
+const fn project_default_severity() -> Severity {
+    Severity::Error
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ProjectRule {
+    pub name: Option<String>,
+    #[serde(default = "project_default_severity")]
+    pub severity: Severity,
+    #[allow(dead_code)]
+    pub description: Option<String>, // Human-readable description of the rule, not used in logic
+    pub includes: Option<Vec<String>>,
+    pub excludes: Option<Vec<String>>,
+    #[serde(flatten)]
+    pub rule: ProjectSpecificRuleConfig,
+}
+
+impl ProjectRule {
+    pub fn get_name(&self) -> String {
+        self.name
+            .as_ref()
+            .map_or_else(|| self.rule.as_str().to_string(), Clone::clone)
+    }
+
+    pub fn normalize_includes_excludes(&mut self) {
+        let normalize = |paths: Vec<String>| {
+            paths
+                .into_iter()
+                .map(|s| {
+                    s.trim_start_matches("./")
+                        .trim_start_matches('/')
+                        .to_string()
+                })
+                .collect()
+        };
+        self.includes = self.includes.take().map(normalize);
+        self.excludes = self.excludes.take().map(normalize);
+    }
+
+    /// Validate the rule specific options
+    /// # Errors
+    /// Returns an error if `min_percent` is not between 0 and 100
+    pub fn validate_options(&self) -> Result<()> {
+        let (ProjectSpecificRuleConfig::DocumentationCoverage { min_percent, .. }
+        | ProjectSpecificRuleConfig::TestCoverage { min_percent, .. }) = &self.rule
+        else {
+            return Ok(());
+        };
+        if !(0.0..=100.0).contains(min_percent) {
+            return Err(anyhow::anyhow!(
+                "'min_percent' of rule '{}' must be between 0 and 100, got {min_percent}",
+                self.get_name()
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+impl ProjectRule {
+    /// Create a `ProjectRule` with sensible defaults for testing.
+    pub const fn from_specific_rule(rule: ProjectSpecificRuleConfig, severity: Severity) -> Self {
+        Self {
+            name: None,
+            severity,
+            description: None,
+            includes: None,
+            excludes: None,
+            rule,
+        }
+    }
+}
@@ -0,0 +1,532 @@
+use crate::cli::commands::FixOptions;
+use crate::core::catalog::Catalog;
+use crate::core::config::applies_to::RuleTargetable;
+use crate::core::config::catalog_rule::{CatalogRule, CatalogSpecificRuleConfig};
+use crate::core::config::check_config_options::HasTagsCriteria;
+use crate::core::config::includes_excludes::should_run_test;
+use crate::core::config::manifest_rule::{ManifestRule, ManifestSpecificRuleConfig};
+use crate::core::config::parse_config::resolve_config_path;
+use crate::core::config::Config;
+use crate::core::manifest::dbt_objects::{Column, Tags};
+use crate::core::manifest::{Manifest, Node};
+use crate::core::properties_file::{
+    node_target, ColumnEdit, ObjectEdit, ObjectPath, PropertiesFile,
+};
+use crate::core::rules::catalog::columns_are_documented::compare_column_names;
+use crate::core::rules::rule_config::has_description;
+use crate::core::rules::rule_config::has_description::Descriptable;
+use crate::core::rules::rule_config::has_metadata_keys::HasMetadata;
+use crate::core::rules::rule_config::has_tags::Tagable;
+use crate::core::utils::unwrap_or_exit;
+use anyhow::Context;
+use dbt_artifact_parser::catalog::CatalogColumn;
+use log::debug;
+use owo_colors::OwoColorize;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Value written for missing `meta` keys
+const META_PLACEHOLDER: &str = "TODO";
+
+/// Edits per properties file (relative to the project root), in the order they were planned
+pub type FixPlan = BTreeMap<String, Vec<(ObjectPath, ObjectEdit)>>;
+
+#[derive(Debug)]
+pub struct FileChange {
+    pub file: String,
+    pub path: PathBuf,
+    pub before: String,
+    pub after: String,
+}
+
+impl FileChange {
+    pub fn unified_diff(&self) -> String {
+        similar::TextDiff::from_lines(&self.before, &self.after)
+            .unified_diff()
+            .context_radius(3)
+            .header(&format!("a/{}", self.file), &format!("b/{}", self.file))
+            .to_string()
+    }
+}
+
+#[must_use]
+pub fn fix(options: &FixOptions, verbose: bool) -> i32 {
+    let config_path = resolve_config_path(&options.entry_point, options.config_file.as_ref());
+    let config = unwrap_or_exit(Config::from_file(config_path));
+
+    let manifest_path = PathBuf::from(format!("{}/{}", options.entry_point, options.manifest_file));
+    let manifest = unwrap_or_exit(Manifest::from_file(&manifest_path));
+
+    let catalog = if options.only_manifest {
+        None
+    } else {
+        let catalog_path =
+            PathBuf::from(format!("{}/{}", options.entry_point, options.catalog_file));
+        Some(unwrap_or_exit(Catalog::from_file(&catalog_path)))
+    };
+
+    let plan = plan_fixes(&manifest, catalog.as_ref(), &config, &options.placeholder);
+    if verbose {
+        debug!("Planned fixes: {plan:#?}");
+    }
+
+    let changes = unwrap_or_exit(apply_plan(Path::new(&options.entry_point), &plan));
+    if changes.is_empty() {
+        println!("{}", "Nothing to fix, no fixable findings found.".green());
+        return 0;
+    }
+
+    for change in &changes {
+        if options.dry_run {
+            print!("{}", change.unified_diff());
+        } else {
+            unwrap_or_exit(write_change(change));
+            println!("{} {}", "Updated".green(), change.file);
+        }
+    }
+
+    if options.dry_run {
+        println!(
+            "\n{} file(s) would be changed. Run without --dry-run to apply the changes.",
+            changes.len()
+        );
+    } else {
+        println!(
+            "\n{} file(s) changed. Search for '{}' to fill in the placeholders.",
+            changes.len(),
+            options.placeholder
+        );
+    }
+    0
+}
+
+/// Plan the edits that resolve fixable findings.
+/// Only `has_description`, `has_tags`, `has_metadata_keys`, `columns_all_documented`
+/// and `columns_have_description` can be fixed automatically.
+pub fn plan_fixes(
+    manifest: &Manifest,
+    catalog: Option<&Catalog>,
+    config: &Config,
+    placeholder: &str,
+) -> FixPlan {
+    plan_fixes_matching(manifest, catalog, config, placeholder, |_| true)
+}
+
+/// Plan the fixes for the objects defined in `file` (their sql/csv file or properties file),
+/// relative to the project root.
+pub fn plan_fixes_for_file(
+    manifest: &Manifest,
+    catalog: Option<&Catalog>,
+    config: &Config,
+    placeholder: &str,
+    file: &str,
+) -> FixPlan {
+    plan_fixes_matching(manifest, catalog, config, placeholder, |paths| {
+        paths.contains(&file)
+    })
+}
+
+fn plan_fixes_matching(
+    manifest: &Manifest,
+    catalog: Option<&Catalog>,
+    config: &Config,
+    placeholder: &str,
+    include: impl Fn(&[&str]) -> bool,
+) -> FixPlan {
+    let mut plan = FixPlan::new();
+    let case_insensitive = manifest
+        .metadata
+        .adapter_type
+        .as_ref()
+        .is_some_and(|adapter| adapter.eq_ignore_ascii_case("snowflake"));
+    let is_project_object = |package_name: &String| {
+        manifest
+            .metadata
+            .project_name
+            .as_ref()
+            .is_none_or(|project| project == package_name)
+    };
+
+    let mut nodes: Vec<&Node> = manifest
+        .nodes
+        .values()
+        .filter(|node| is_project_object(node.get_package_name()))
+        .collect();
+    nodes.sort_by_key(|node| node.get_unique_id());
+
+    for node in nodes {
+        let Some((file, path)) = node_target(node) else {
+            continue;
+        };
+        if !include(&[node.get_relative_path().as_str(), file.as_str()]) {
+            continue;
+        }
+
+        let edit = node_edit(node, catalog, config, placeholder, case_insensitive);
+        add_to_plan(&mut plan, file, path, edit);
+    }
+
+    let mut sources: Vec<_> = manifest
+        .sources
+        .values()
+        .filter(|source| is_project_object(source.get_package_name()))
+        .collect();
+    sources.sort_by_key(|source| source.get_unique_id());
+
+    for source in sources {
+        if !include(&[source.get_relative_path().as_str()]) {
+            continue;
+        }
+        let path = ObjectPath::SourceTable {
+            source_name: source.source_name.clone(),
+            table_name: source.name.clone(),
+        };
+
+        let mut edit = ObjectEdit::default();
+        for rule in config.manifest_tests.iter().flatten() {
+            let applies = rule
+                .applies_to
+                .as_ref()
+                .is_some_and(|applies| applies.source_objects.contains(&source.ruletarget()));
+            if applies && should_run_test(source, rule.includes.as_ref(), rule.excludes.as_ref()) {
+                merge_edit(&mut edit, manifest_rule_edit(source, rule, placeholder));
+            }
+        }
+
+        let catalog_source =
+            catalog.and_then(|catalog| catalog.sources.get(source.get_unique_id()));
+        if let Some(catalog_source) = catalog_source {
+            for rule in config.catalog_tests.iter().flatten() {
+                let applies = rule
+                    .applies_to
+                    .as_ref()
+                    .is_none_or(|applies| applies.source_objects.contains(&source.ruletarget()));
+                if applies {
+                    edit.columns.extend(catalog_rule_columns(
+                        rule,
+                        &catalog_source.columns,
+                        source.columns.as_ref(),
+                        case_insensitive,
+                        placeholder,
+                    ));
+                }
+            }
+        }
+
+        add_to_plan(&mut plan, source.get_relative_path().clone(), path, edit);
+    }
+
+    plan
+}
+
+/// The edit resolving the fixable findings of a node
+fn node_edit(
+    node: &Node,
+    catalog: Option<&Catalog>,
+    config: &Config,
+    placeholder: &str,
+    case_insensitive: bool,
+) -> ObjectEdit {
+    let mut edit = ObjectEdit::default();
+    for rule in config.manifest_tests.iter().flatten() {
+        let applies = rule
+            .applies_to
+            .as_ref()
+            .is_some_and(|applies| applies.node_objects.contains(&node.ruletarget()));
+        if applies
+            && should_run_test(node, rule.includes.as_ref(), rule.excludes.as_ref())
+            && materialization_allowed(node, rule.model_materializations.as_ref())
+        {
+            merge_edit(&mut edit, manifest_rule_edit(node, rule, placeholder));
+        }
+    }
+
+    let catalog_node = catalog.and_then(|catalog| catalog.nodes.get(node.get_unique_id()));
+    if let Some(catalog_node) = catalog_node {
+        for rule in config.catalog_tests.iter().flatten() {
+            let applies = rule
+                .applies_to
+                .as_ref()
+                .is_none_or(|applies| applies.node_objects.contains(&node.ruletarget()));
+            if applies && materialization_allowed(node, rule.model_materializations.as_ref()) {
+                edit.columns.extend(catalog_rule_columns(
+                    rule,
+                    &catalog_node.get_base().columns,
+                    node.get_base().columns.as_ref(),
+                    case_insensitive,
+                    placeholder,
+                ));
+            }
+        }
+    }
+    edit
+}
+
+/// Apply the plan to the properties files, returning the files whose content changed.
+///
+/// # Errors
+/// Returns an error if an existing properties file can't be read.
+pub fn apply_plan(entry_point: &Path, plan: &FixPlan) -> anyhow::Result<Vec<FileChange>> {
+    let mut changes = Vec::new();
+    for (file, edits) in plan {
+        let path = entry_point.join(file);
+        let before = if path.exists() {
+            fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read properties file '{}'", path.display()))?
+        } else {
+            String::new()
+        };
+
+        let mut properties = PropertiesFile::new(&before);
+        for (object, edit) in edits {
+            properties.apply(object, edit);
+        }
+        let after = properties.render();
+
+        if after != before {
+            changes.push(FileChange {
+                file: file.clone(),
+                path,
+                before,
+                after,
+            });
+        }
+    }
+    Ok(changes)
+}
+
+/// Write the new content of a changed properties file, creating directories where needed.
+///
+/// # Errors
+/// Returns an error if the file or its directory can't be written.
+pub fn write_change(change: &FileChange) -> anyhow::Result<()> {
+    if let Some(parent) = change.path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory '{}'", parent.display()))?;
+    }
+    fs::write(&change.path, &change.after).with_context(|| {
+        format!(
+            "Failed to write properties file '{}'",
+            change.path.display()
+        )
+    })
+}
+
+fn materialization_allowed(
+    node: &Node,
+    allowed: Option<&Vec<crate::core::config::Materialization>>,
+) -> bool {
+    match (allowed, node.get_materialization()) {
+        (Some(allowed), Some(materialization)) => allowed.contains(materialization),
+        _ => true,
+    }
+}
+
+fn manifest_rule_edit<T: Descriptable + Tagable + HasMetadata>(
+    object: &T,
+    rule: &ManifestRule,
+    placeholder: &str,
+) -> ObjectEdit {
+    let mut edit = ObjectEdit::default();
+    match &rule.rule {
+        ManifestSpecificRuleConfig::HasDescription {}
+            if has_description(object, rule).is_some() =>
+        {
+            edit.description = Some(placeholder.to_string());
+        }
+        ManifestSpecificRuleConfig::HasTags {
+            required_tags,
+            criteria,
+        } => edit.tags = missing_tags(object.get_tags(), required_tags, criteria),
+        ManifestSpecificRuleConfig::HasMetadataKeys { required_keys, .. } => {
+            let missing = object.get_metadata().map_or_else(
+                || required_keys.iter().collect(),
+                |meta| meta.missing_keys(required_keys),
+            );
+            edit.meta = missing
+                .into_iter()
+                .map(|key| (key.clone(), META_PLACEHOLDER.to_string()))
+                .collect();
+        }
+        // Not fixable without human judgement
+        _ => {}
+    }
+    edit
+}
+
+fn missing_tags(
+    tags: Option<&Tags>,
+    required_tags: &[String],
+    criteria: &HasTagsCriteria,
+) -> Vec<String> {
+    let has_tag = |tag: &String| tags.is_some_and(|tags| tags.contains(tag));
+    let matches = required_tags.iter().filter(|tag| has_tag(tag)).count();
+
+    match criteria {
+        HasTagsCriteria::All => required_tags
+            .iter()
+            .filter(|tag| !has_tag(tag))
+            .cloned()
+            .collect(),
+        HasTagsCriteria::Any | HasTagsCriteria::OneOf if matches == 0 => {
+            required_tags.first().cloned().into_iter().collect()
+        }
+        // Too many `one_of` tags: which one to remove is up to the user
+        _ => Vec::new(),
+    }
+}
+
+fn catalog_rule_columns(
+    rule: &CatalogRule,
+    catalog_columns: &HashMap<String, CatalogColumn>,
+    manifest_columns: Option<&HashMap<String, Column>>,
+    case_insensitive: bool,
+    placeholder: &str,
+) -> Vec<ColumnEdit> {
+    let column_edit = |name: &str| ColumnEdit {
+        // Snowflake reports upper case names, dbt projects use lower case
+        name: if case_insensitive {
+            name.to_lowercase()
+        } else {
+            name.to_string()
+        },
+        data_type: None,
+        description: Some(placeholder.to_string()),
+    };
+
+    let mut ordered_catalog_columns: Vec<&CatalogColumn> = catalog_columns.values().collect();
+    ordered_catalog_columns.sort_by_key(|column| column.index);
+    let catalog_names: Vec<&String> = ordered_catalog_columns.iter().map(|c| &c.name).collect();
+
+    let documented: Vec<&String> = manifest_columns
+        .map(|columns| columns.keys().collect())
+        .unwrap_or_default();
+
+    match &rule.rule {
+        CatalogSpecificRuleConfig::ColumnsAllDocumented {} => {
+            compare_column_names(&catalog_names, &documented, case_insensitive)
+                .iter()
+                .map(|name| column_edit(name))
+                .collect()
+        }
+        CatalogSpecificRuleConfig::ColumnsHaveDescription {} => {
+            let Some(columns) = manifest_columns.filter(|columns| !columns.is_empty()) else {
+                return catalog_names.iter().map(|name| column_edit(name)).collect();
+            };
+            let mut undescribed: Vec<&String> = columns
+                .iter()
+                .filter(|(_, column)| {
+                    column
+                        .description
+                        .as_ref()
+                        .is_none_or(|description| description.trim().is_empty())
+                })
+                .map(|(name, _)| name)
+                .collect();
+            undescribed.sort();
+            undescribed
+                .into_iter()
+                .map(|name| column_edit(name))
+                .collect()
+        }
+        // Not fixable without human judgement
+        _ => Vec::new(),
+    }
+}
+
+fn merge_edit(edit: &mut ObjectEdit, other: ObjectEdit) {
+    if edit.description.is_none() {
+        edit.description = other.description;
+    }
+    for tag in other.tags {
+        if !edit.tags.contains(&tag) {
+            edit.tags.push(tag);
+        }
+    }
+    for (key, value) in other.meta {
+        if !edit.meta.iter().any(|(existing, _)| existing == &key) {
+            edit.meta.push((key, value));
+        }
+    }
+    edit.columns.extend(other.columns);
+}
+
+pub fn add_to_plan(plan: &mut FixPlan, file: String, path: ObjectPath, edit: ObjectEdit) {
+    if edit.is_empty() {
+        return;
+    }
+    let edits = plan.entry(file).or_default();
+    // Versions of a model share a single properties entry
+    let index = if let Some(index) = edits.iter().position(|(existing, _)| existing == &path) {
+        merge_edit(&mut edits[index].1, edit);
+        index
+    } else {
+        edits.push((path, edit));
+        edits.len() - 1
+    };
+    let edit = &mut edits[index].1;
+
+    // Several catalog rules can propose the same column
+    let mut seen = Vec::new();
+    edit.columns.retain(|column| {
+        let name = column.name.to_lowercase();
+        let is_new = !seen.contains(&name);
+        seen.push(name);
+        is_new
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_tags_all() {
+        let tags: Tags = vec!["a".to_string()];
+        let required = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        assert_eq!(
+            missing_tags(Some(&tags), &required, &HasTagsCriteria::All),
+            vec!["b".to_string(), "c".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_missing_tags_any_and_one_of() {
+        let required = vec!["a".to_string(), "b".to_string()];
+        assert_eq!(
+            missing_tags(None, &required, &HasTagsCriteria::Any),
+            vec!["a".to_string()]
+        );
+        assert_eq!(
+            missing_tags(None, &required, &HasTagsCriteria::OneOf),
+            vec!["a".to_string()]
+        );
+
+        let both: Tags = vec!["a".to_string(), "b".to_string()];
+        assert!(missing_tags(Some(&both), &required, &HasTagsCriteria::OneOf).is_empty());
+        let one: Tags = vec!["b".to_string()];
+        assert!(missing_tags(Some(&one), &required, &HasTagsCriteria::Any).is_empty());
+    }
+
+    #[test]
+    fn test_merge_edit_deduplicates() {
+        let mut edit = ObjectEdit {
+            tags: vec!["a".to_string()],
+            meta: vec![("owner".to_string(), "TODO".to_string())],
+            ..ObjectEdit::default()
+        };
+        merge_edit(
+            &mut edit,
+            ObjectEdit {
+                description: Some("TODO".to_string()),
+                tags: vec!["a".to_string(), "b".to_string()],
+                meta: vec![("owner".to_string(), "TODO".to_string())],
+                ..ObjectEdit::default()
+            },
+        );
+        assert_eq!(edit.description.as_deref(), Some("TODO"));
+        assert_eq!(edit.tags, vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(edit.meta.len(), 1);
+    }
+}
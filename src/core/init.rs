@@ -1,4 +1,5 @@
 use crate::cli::commands::InitOptions;
+use crate::core::init_from_manifest::ProposedConfig;
 use log::debug;
 use owo_colors::OwoColorize;
 use std::fs;
@@ -250,15 +251,38 @@ pub fn create_config(options: &InitOptions) -> InitResult {
         return InitResult::Error(format!("Path is not a directory: {}", options.location));
     }
 
-    match options.format.as_str() {
-        "yml" | "yaml" => create_yaml_config(location),
-        "toml" => create_toml_config(location),
-        "pyproject" => create_or_update_pyproject(location),
-        _ => InitResult::Error(format!("Unknown format: {}", options.format)),
+    let Some(manifest_path) = options.from_manifest.as_ref() else {
+        return match options.format.as_str() {
+            "yml" | "yaml" => create_yaml_config(location, DEFAULT_YAML_CONFIG),
+            "toml" => create_toml_config(location, DEFAULT_TOML_CONFIG),
+            "pyproject" => create_or_update_pyproject(location, DEFAULT_PYPROJECT_CONFIG),
+            _ => InitResult::Error(format!("Unknown format: {}", options.format)),
+        };
+    };
+
+    let proposal = match ProposedConfig::from_manifest_file(manifest_path) {
+        Ok(proposal) => proposal,
+        Err(e) => return InitResult::Error(format!("{e:#}")),
+    };
+
+    let content = match options.format.as_str() {
+        "yml" | "yaml" => proposal.to_yaml(),
+        "toml" => proposal.to_toml(),
+        "pyproject" => proposal.to_pyproject(),
+        _ => return InitResult::Error(format!("Unknown format: {}", options.format)),
+    };
+
+    match content {
+        Ok(content) => match options.format.as_str() {
+            "toml" => create_toml_config(location, &content),
+            "pyproject" => create_or_update_pyproject(location, &content),
+            _ => create_yaml_config(location, &content),
+        },
+        Err(e) => InitResult::Error(format!("Failed to generate config from manifest: {e}")),
     }
 }
 
-fn create_yaml_config(location: &Path) -> InitResult {
+fn create_yaml_config(location: &Path, content: &str) -> InitResult {
     let file_path = location.join("dbtective.yml");
     let path_str = file_path.display().to_string();
 
@@ -266,13 +290,13 @@ fn create_yaml_config(location: &Path) -> InitResult {
         return InitResult::AlreadyExists(path_str);
     }
 
-    match fs::write(&file_path, DEFAULT_YAML_CONFIG) {
+    match fs::write(&file_path, content) {
         Ok(()) => InitResult::Created(path_str),
         Err(e) => InitResult::Error(format!("Failed to write {path_str}: {e}")),
     }
 }
 
-fn create_toml_config(location: &Path) -> InitResult {
+fn create_toml_config(location: &Path, content: &str) -> InitResult {
     let file_path = location.join("dbtective.toml");
     let path_str = file_path.display().to_string();
 
@@ -280,13 +304,13 @@ fn create_toml_config(location: &Path) -> InitResult {
         return InitResult::AlreadyExists(path_str);
     }
 
-    match fs::write(&file_path, DEFAULT_TOML_CONFIG) {
+    match fs::write(&file_path, content) {
         Ok(()) => InitResult::Created(path_str),
         Err(e) => InitResult::Error(format!("Failed to write {path_str}: {e}")),
     }
 }
 
-fn create_or_update_pyproject(location: &Path) -> InitResult {
+fn create_or_update_pyproject(location: &Path, section: &str) -> InitResult {
     let file_path = location.join("pyproject.toml");
     let path_str = file_path.display().to_string();
 
@@ -306,11 +330,7 @@ fn create_or_update_pyproject(location: &Path) -> InitResult {
     }
 
     // Append dbtective section to existing pyproject.toml
-    let new_content = format!(
-        "{}{}",
-        existing_content.trim_end(),
-        DEFAULT_PYPROJECT_CONFIG
-    );
+    let new_content = format!("{}{}", existing_content.trim_end(), section);
 
     match fs::write(&file_path, new_content) {
         Ok(()) => InitResult::PyprojectUpdated(path_str),
@@ -327,6 +347,7 @@ mod tests {
         InitOptions {
             location: temp_dir.path().to_string_lossy().to_string(),
             format: "yml".to_string(),
+            from_manifest: None,
         }
     }
 
@@ -360,6 +381,7 @@ mod tests {
         let options = InitOptions {
             location: temp_dir.path().to_string_lossy().to_string(),
             format: "toml".to_string(),
+            from_manifest: None,
         };
 
         let result = create_config(&options);
@@ -387,6 +409,7 @@ mod tests {
         let options = InitOptions {
             location: temp_dir.path().to_string_lossy().to_string(),
             format: "pyproject".to_string(),
+            from_manifest: None,
         };
 
         let result = create_config(&options);
@@ -413,6 +436,7 @@ requires = ["setuptools"]
         let options = InitOptions {
             location: temp_dir.path().to_string_lossy().to_string(),
             format: "pyproject".to_string(),
+            from_manifest: None,
         };
 
         let result = create_config(&options);
@@ -449,6 +473,7 @@ name = "my-project"
         let options = InitOptions {
             location: temp_dir.path().to_string_lossy().to_string(),
             format: "pyproject".to_string(),
+            from_manifest: None,
         };
 
         let result = create_config(&options);
@@ -475,6 +500,7 @@ name = "my-project"
         let options = InitOptions {
             location: "/nonexistent/path".to_string(),
             format: "yml".to_string(),
+            from_manifest: None,
         };
 
         let result = create_config(&options);
@@ -490,6 +516,7 @@ name = "my-project"
         let options = InitOptions {
             location: file_path.to_string_lossy().to_string(),
             format: "yml".to_string(),
+            from_manifest: None,
         };
 
         let result = create_config(&options);
@@ -502,6 +529,7 @@ name = "my-project"
         let options = InitOptions {
             location: temp_dir.path().to_string_lossy().to_string(),
             format: "yaml".to_string(),
+            from_manifest: None,
         };
 
         let result = create_config(&options);
@@ -0,0 +1,413 @@
+use crate::core::config::naming_convention::NamingConvention;
+use crate::core::manifest::{Manifest, Node};
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+// Share of models that must agree before something is considered a project convention
+const CONVENTION_THRESHOLD: f64 = 0.8;
+// Share of models that must carry a meta key before it is proposed as required
+const META_KEY_THRESHOLD: f64 = 0.5;
+// Percentile of model line counts used for the proposed `max_code_lines` threshold
+const CODE_LINES_PERCENTILE: f64 = 0.9;
+
+const CASING_CONVENTIONS: [&str; 4] = ["snake_case", "kebab-case", "camelCase", "PascalCase"];
+
+/// A single proposed rule, mirroring the fields of `ManifestRule` that `init` fills in.
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct ProposedRule {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub rule_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_tags: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub criteria: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_keys: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_lines: Option<usize>,
+    pub applies_to: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub includes: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub excludes: Vec<String>,
+}
+
+impl ProposedRule {
+    fn new(name: impl Into<String>, rule_type: &str, applies_to: &[&str]) -> Self {
+        Self {
+            name: name.into(),
+            rule_type: rule_type.to_string(),
+            description: None,
+            pattern: None,
+            required_tags: None,
+            criteria: None,
+            required_keys: None,
+            max_lines: None,
+            applies_to: applies_to.iter().map(ToString::to_string).collect(),
+            includes: None,
+            excludes: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Default)]
+pub struct ProposedConfig {
+    pub manifest_tests: Vec<ProposedRule>,
+}
+
+#[derive(Serialize)]
+struct PyProjectTool<'a> {
+    tool: PyProjectDbtective<'a>,
+}
+
+#[derive(Serialize)]
+struct PyProjectDbtective<'a> {
+    dbtective: &'a ProposedConfig,
+}
+
+impl ProposedConfig {
+    /// Inspect a manifest and propose a config that passes on the current state of the project.
+    /// Objects that do not follow the detected conventions are added to the `excludes` of each rule,
+    /// so the generated config acts as a baseline.
+    ///
+    /// # Errors
+    /// Returns an error if the manifest cannot be read or parsed
+    pub fn from_manifest_file<P: AsRef<Path>>(manifest_path: P) -> Result<Self> {
+        let manifest_path = manifest_path.as_ref();
+        let manifest = Manifest::from_file(manifest_path).context(format!(
+            "Unable to propose a config from manifest at {}",
+            manifest_path.display()
+        ))?;
+        Ok(Self::from_manifest(&manifest))
+    }
+
+    pub fn from_manifest(manifest: &Manifest) -> Self {
+        let mut models: Vec<&Node> = manifest
+            .nodes
+            .values()
+            .filter(|node| matches!(node, Node::Model(_)))
+            .collect();
+        models.sort_by(|a, b| a.get_unique_id().cmp(b.get_unique_id()));
+
+        let mut manifest_tests = vec![propose_has_description(&models, manifest)];
+        manifest_tests.extend(propose_name_conventions(&models));
+        manifest_tests.extend(propose_has_tags(&models));
+        manifest_tests.extend(propose_has_metadata_keys(&models));
+        manifest_tests.extend(propose_max_code_lines(&models));
+
+        Self { manifest_tests }
+    }
+
+    /// # Errors
+    /// Returns an error if the proposal cannot be serialized
+    pub fn to_yaml(&self) -> Result<String> {
+        Ok(format!(
+            "{}{}",
+            generated_header(),
+            serde_yaml::to_string(self)?
+        ))
+    }
+
+    /// # Errors
+    /// Returns an error if the proposal cannot be serialized
+    pub fn to_toml(&self) -> Result<String> {
+        Ok(format!("{}{}", generated_header(), toml::to_string(self)?))
+    }
+
+    /// Returns a `[tool.dbtective]` section to append to an existing pyproject.toml
+    ///
+    /// # Errors
+    /// Returns an error if the proposal cannot be serialized
+    pub fn to_pyproject(&self) -> Result<String> {
+        let section = toml::to_string(&PyProjectTool {
+            tool: PyProjectDbtective { dbtective: self },
+        })?;
+        Ok(format!(
+            "\n\n{}\n[tool.dbtective]\n\n{section}",
+            generated_header()
+        ))
+    }
+}
+
+const fn generated_header() -> &'static str {
+    "# dbtective configuration file, generated from an existing manifest\n\
+# Objects that did not pass at generation time are listed under `excludes` per rule.\n\
+# Documentation: https://feliblo.github.io/dbtective/docs/config\n\
+# Rules: https://feliblo.github.io/dbtective/docs/rules/\n\n"
+}
+
+fn sorted_paths<'a>(nodes: impl Iterator<Item = &'a Node>) -> Vec<String> {
+    nodes
+        .map(|node| node.get_relative_path().clone())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn share(count: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        count as f64 / total as f64
+    }
+}
+
+fn propose_has_description(models: &[&Node], manifest: &Manifest) -> ProposedRule {
+    let mut rule = ProposedRule::new("has_description", "has_description", &["models", "sources"]);
+    let is_missing = |desc: Option<&String>| desc.is_none_or(|d| d.trim().is_empty());
+
+    let mut excludes: BTreeSet<String> = models
+        .iter()
+        .filter(|node| is_missing(node.get_base().description.as_ref()))
+        .map(|node| node.get_relative_path().clone())
+        .collect();
+    excludes.extend(
+        manifest
+            .sources
+            .values()
+            .filter(|source| is_missing(source.description.as_ref()))
+            .map(|source| source.get_relative_path().clone()),
+    );
+    rule.excludes = excludes.into_iter().collect();
+    rule
+}
+
+/// Models are grouped by the directory directly below `models/` (e.g. `staging`, `marts`)
+fn layer_of(node: &Node) -> Option<String> {
+    let path = node.get_relative_path().replace('\\', "/");
+    let mut parts = path.split('/');
+    let root = parts.next()?;
+    let layer = parts.next()?;
+    // A file directly in the models root has no layer
+    parts.next()?;
+    Some(format!("{root}/{layer}"))
+}
+
+/// Detect the dominant naming convention for a set of names.
+/// A shared prefix (e.g. `stg_`) is preferred over a plain casing convention.
+fn detect_convention(names: &[&str]) -> Option<String> {
+    let total = names.len();
+    // Reversed so ties resolve to the first (most common) convention in the list
+    let (casing, casing_count) = CASING_CONVENTIONS
+        .iter()
+        .rev()
+        .map(|convention| {
+            let naming = NamingConvention::from_pattern(convention)
+                .expect("built-in naming conventions are valid");
+            let count = names.iter().filter(|name| naming.is_match(name)).count();
+            (*convention, count)
+        })
+        .max_by_key(|(_, count)| *count)?;
+
+    if share(casing_count, total) < CONVENTION_THRESHOLD {
+        return None;
+    }
+
+    if casing == "snake_case" && total > 1 {
+        let mut prefixes: BTreeMap<&str, usize> = BTreeMap::new();
+        for name in names {
+            if let Some((prefix, rest)) = name.split_once('_') {
+                if !prefix.is_empty() && !rest.is_empty() {
+                    *prefixes.entry(prefix).or_default() += 1;
+                }
+            }
+        }
+        if let Some((prefix, count)) = prefixes.into_iter().max_by_key(|(_, count)| *count) {
+            if count > 1 && share(count, total) >= CONVENTION_THRESHOLD {
+                return Some(format!("^{prefix}_[a-z0-9_]+$"));
+            }
+        }
+    }
+
+    Some(casing.to_string())
+}
+
+fn propose_name_conventions(models: &[&Node]) -> Vec<ProposedRule> {
+    let mut layers: BTreeMap<Option<String>, Vec<&Node>> = BTreeMap::new();
+    for node in models {
+        layers.entry(layer_of(node)).or_default().push(node);
+    }
+
+    layers
+        .into_iter()
+        .filter_map(|(layer, nodes)| {
+            let names: Vec<&str> = nodes.iter().map(|node| node.get_name().as_str()).collect();
+            let pattern = detect_convention(&names)?;
+            let convention = NamingConvention::from_pattern(&pattern).ok()?;
+
+            let (name, includes) = layer.as_ref().map_or_else(
+                || {
+                    (
+                        "naming_convention_models".to_string(),
+                        nodes
+                            .iter()
+                            .map(|node| node.get_relative_path().clone())
+                            .collect::<BTreeSet<_>>()
+                            .into_iter()
+                            .collect(),
+                    )
+                },
+                |layer| {
+                    let layer_name = layer.rsplit('/').next().unwrap_or(layer);
+                    (
+                        format!("naming_convention_{layer_name}"),
+                        vec![format!("^{layer}/**")],
+                    )
+                },
+            );
+
+            let mut rule = ProposedRule::new(name, "name_convention", &["models"]);
+            rule.pattern = Some(pattern);
+            rule.includes = Some(includes);
+            rule.excludes = sorted_paths(
+                nodes
+                    .iter()
+                    .copied()
+                    .filter(|node| !convention.is_match(node.get_name())),
+            );
+            Some(rule)
+        })
+        .collect()
+}
+
+fn propose_has_tags(models: &[&Node]) -> Option<ProposedRule> {
+    let tags_in_use: BTreeSet<&String> = models
+        .iter()
+        .filter_map(|node| node.get_base().tags.as_ref())
+        .flatten()
+        .collect();
+
+    if tags_in_use.is_empty() {
+        return None;
+    }
+
+    let mut rule = ProposedRule::new("has_tags", "has_tags", &["models"]);
+    rule.description = Some("Models need at least one of the tags used in this project.".into());
+    rule.required_tags = Some(tags_in_use.into_iter().cloned().collect());
+    rule.criteria = Some("any".to_string());
+    rule.excludes = sorted_paths(
+        models
+            .iter()
+            .copied()
+            .filter(|node| node.get_base().tags.as_ref().is_none_or(Vec::is_empty)),
+    );
+    Some(rule)
+}
+
+fn meta_keys(node: &Node) -> BTreeSet<&String> {
+    match node.get_base().meta.as_ref().map(|meta| &meta.0) {
+        Some(serde_json::Value::Object(map)) => map.keys().collect(),
+        _ => BTreeSet::new(),
+    }
+}
+
+fn propose_has_metadata_keys(models: &[&Node]) -> Option<ProposedRule> {
+    let mut key_counts: BTreeMap<&String, usize> = BTreeMap::new();
+    for node in models {
+        for key in meta_keys(node) {
+            *key_counts.entry(key).or_default() += 1;
+        }
+    }
+
+    let required_keys: Vec<String> = key_counts
+        .into_iter()
+        .filter(|(_, count)| share(*count, models.len()) >= META_KEY_THRESHOLD)
+        .map(|(key, _)| key.clone())
+        .collect();
+
+    if required_keys.is_empty() {
+        return None;
+    }
+
+    let mut rule = ProposedRule::new("has_metadata_keys", "has_metadata_keys", &["models"]);
+    rule.excludes = sorted_paths(models.iter().copied().filter(|node| {
+        let keys = meta_keys(node);
+        required_keys.iter().any(|key| !keys.contains(key))
+    }));
+    rule.required_keys = Some(required_keys);
+    Some(rule)
+}
+
+fn code_lines(node: &Node) -> usize {
+    node.get_base()
+        .raw_code
+        .as_deref()
+        .map_or(0, |code| code.lines().count())
+}
+
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+fn propose_max_code_lines(models: &[&Node]) -> Option<ProposedRule> {
+    let mut line_counts: Vec<usize> = models.iter().map(|node| code_lines(node)).collect();
+    line_counts.sort_unstable();
+    let last_index = line_counts.len().checked_sub(1)?;
+
+    let percentile_index = ((last_index as f64) * CODE_LINES_PERCENTILE).round() as usize;
+    // Round up to the next multiple of 10 to keep the threshold readable
+    let max_lines = line_counts[percentile_index].max(1).div_ceil(10) * 10;
+
+    let mut rule = ProposedRule::new("max_code_lines", "max_code_lines", &["models"]);
+    rule.max_lines = Some(max_lines);
+    rule.excludes = sorted_paths(models.iter().copied().filter(|node| {
+        let lines = code_lines(node);
+        lines == 0 || lines > max_lines
+    }));
+    Some(rule)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_convention_prefers_shared_prefix() {
+        let names = ["stg_orders", "stg_customers", "stg_payments"];
+        assert_eq!(
+            detect_convention(&names),
+            Some("^stg_[a-z0-9_]+$".to_string())
+        );
+    }
+
+    #[test]
+    fn test_detect_convention_falls_back_to_casing() {
+        let names = ["orders", "customers", "daily_payments"];
+        assert_eq!(detect_convention(&names), Some("snake_case".to_string()));
+    }
+
+    #[test]
+    fn test_detect_convention_no_dominant_convention() {
+        let names = ["orders", "Customers", "dailyPayments", "Daily-Payments"];
+        assert_eq!(detect_convention(&names), None);
+    }
+
+    #[test]
+    fn test_share_handles_empty_total() {
+        assert!(share(0, 0).abs() < f64::EPSILON);
+        assert!((share(1, 2) - 0.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_proposed_config_serializes_to_yaml() {
+        let mut rule = ProposedRule::new("has_description", "has_description", &["models"]);
+        rule.excludes = vec!["models/undocumented.sql".to_string()];
+        let config = ProposedConfig {
+            manifest_tests: vec![rule],
+        };
+        let yaml = config.to_yaml().unwrap();
+        assert!(yaml.contains("manifest_tests:"));
+        assert!(yaml.contains("type: has_description"));
+        assert!(yaml.contains("- models/undocumented.sql"));
+        assert!(!yaml.contains("pattern"));
+    }
+}
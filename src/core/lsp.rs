@@ -0,0 +1,462 @@
+use crate::cli::commands::LspOptions;
+use crate::cli::table::RuleResult;
+use crate::core::catalog::Catalog;
+use crate::core::config::severity::Severity;
+use crate::core::config::Config;
+use crate::core::fix::{apply_plan, plan_fixes_for_file, FileChange};
+use crate::core::manifest::Manifest;
+use crate::core::run::collect_findings;
+use crate::core::watch::watch_files;
+use anyhow::Context;
+use log::debug;
+use lsp_server::{Connection, ErrorCode, Message, Request, Response};
+use lsp_types::notification::{Notification, PublishDiagnostics, ShowMessage};
+use lsp_types::request::{CodeActionRequest, Request as _};
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams,
+    CodeActionProviderCapability, CreateFile, CreateFileOptions, Diagnostic, DiagnosticSeverity,
+    DocumentChangeOperation, DocumentChanges, InitializeParams, InitializeResult, MessageType,
+    NumberOrString, OneOf, OptionalVersionedTextDocumentIdentifier, Position,
+    PublishDiagnosticsParams, Range, ResourceOp, ServerCapabilities, ServerInfo, ShowMessageParams,
+    TextDocumentEdit, TextEdit, Url, WorkspaceEdit,
+};
+use owo_colors::OwoColorize;
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+#[must_use]
+pub fn lsp(options: &LspOptions, verbose: bool) -> i32 {
+    let (connection, io_threads) = Connection::stdio();
+    let result = serve(&connection, options, verbose);
+    // The writer thread only stops once the connection is dropped
+    drop(connection);
+    let result = result.and_then(|()| io_threads.join().map_err(anyhow::Error::from));
+
+    match result {
+        Ok(()) => 0,
+        Err(err) => {
+            eprintln!("{}", format!("Language server error: {err:#}").red());
+            1
+        }
+    }
+}
+
+/// Serve a language server over `connection` until the client shuts it down.
+///
+/// Findings are published as diagnostics on the file they were found in and
+/// re-published whenever the manifest, catalog or config changes.
+///
+/// # Errors
+/// Returns an error if the client doesn't follow the protocol (e.g. no `initialize` request).
+pub fn serve(connection: &Connection, options: &LspOptions, verbose: bool) -> anyhow::Result<()> {
+    let capabilities = ServerCapabilities {
+        code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+        ..ServerCapabilities::default()
+    };
+    let initialize_result = InitializeResult {
+        capabilities,
+        server_info: Some(ServerInfo {
+            name: env!("CARGO_PKG_NAME").to_string(),
+            version: Some(env!("CARGO_PKG_VERSION").to_string()),
+        }),
+    };
+
+    let (initialize_id, initialize_params) = connection.initialize_start()?;
+    let initialize_params: InitializeParams = serde_json::from_value(initialize_params)?;
+    connection.initialize_finish(initialize_id, serde_json::to_value(initialize_result)?)?;
+
+    let root = project_root(options, &initialize_params);
+    debug!("Serving dbt project at '{}'", root.display());
+    let mut server = Server {
+        connection,
+        options,
+        root,
+        verbose,
+        artifacts: None,
+        published: HashSet::new(),
+    };
+
+    let (sender, receiver) = crossbeam_channel::unbounded();
+    let watcher = watch_files(&server.watched_files(), move || {
+        sender.send(()).ok();
+    });
+    let changes = match &watcher {
+        Ok(_) => receiver,
+        Err(err) => {
+            server.show_message(MessageType::WARNING, &format!("{err:#}"));
+            crossbeam_channel::never()
+        }
+    };
+
+    server.refresh()?;
+    loop {
+        crossbeam_channel::select! {
+            recv(connection.receiver) -> message => {
+                let Ok(message) = message else {
+                    return Ok(());
+                };
+                if let Message::Request(request) = message {
+                    if connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+                    server.handle_request(request)?;
+                }
+            }
+            recv(changes) -> _ => server.refresh()?,
+        }
+    }
+}
+
+/// The `--entry-point`, or the workspace opened in the editor
+fn project_root(options: &LspOptions, params: &InitializeParams) -> PathBuf {
+    #[allow(deprecated)]
+    let workspace = params
+        .workspace_folders
+        .as_ref()
+        .and_then(|folders| folders.first())
+        .map(|folder| &folder.uri)
+        .or(params.root_uri.as_ref())
+        .and_then(|uri| uri.to_file_path().ok());
+
+    let root = options
+        .entry_point
+        .as_ref()
+        .map(PathBuf::from)
+        .or(workspace)
+        .unwrap_or_else(|| PathBuf::from("."));
+    root.canonicalize().unwrap_or(root)
+}
+
+struct Artifacts {
+    config: Config,
+    manifest: Manifest,
+    catalog: Option<Catalog>,
+}
+
+struct Server<'a> {
+    connection: &'a Connection,
+    options: &'a LspOptions,
+    root: PathBuf,
+    verbose: bool,
+    artifacts: Option<Artifacts>,
+    published: HashSet<Url>,
+}
+
+impl Server<'_> {
+    fn config_path(&self) -> anyhow::Result<PathBuf> {
+        if let Some(config_file) = &self.options.config_file {
+            return Ok(self.root.join(config_file));
+        }
+        let (config_file, _) = Config::find_config_in_dir(&self.root)?;
+        Ok(self.root.join(config_file))
+    }
+
+    fn watched_files(&self) -> Vec<PathBuf> {
+        let mut files = vec![self.root.join(&self.options.manifest_file)];
+        if !self.options.only_manifest {
+            files.push(self.root.join(&self.options.catalog_file));
+        }
+        if let Ok(config_path) = self.config_path() {
+            files.push(config_path);
+        }
+        files
+    }
+
+    fn load(&self) -> anyhow::Result<Artifacts> {
+        let config = Config::from_file(self.config_path()?)?;
+        let manifest = Manifest::from_file(self.root.join(&self.options.manifest_file))?;
+        let catalog = if self.options.only_manifest {
+            None
+        } else {
+            Some(Catalog::from_file(
+                self.root.join(&self.options.catalog_file),
+            )?)
+        };
+        Ok(Artifacts {
+            config,
+            manifest,
+            catalog,
+        })
+    }
+
+    /// Reload the artifacts and re-publish the diagnostics.
+    /// Errors are shown in the editor, the previous diagnostics stay until the artifacts load again.
+    fn refresh(&mut self) -> anyhow::Result<()> {
+        let artifacts = match self.load() {
+            Ok(artifacts) => artifacts,
+            Err(err) => {
+                self.show_message(MessageType::ERROR, &format!("{err:#}"));
+                return Ok(());
+            }
+        };
+
+        // Rule output is written to stdout when verbose, which would corrupt the protocol
+        let findings = match collect_findings(
+            &artifacts.config,
+            &artifacts.manifest,
+            artifacts.catalog.as_ref(),
+            &self.root,
+            false,
+        ) {
+            Ok(findings) => findings,
+            Err(err) => {
+                self.show_message(MessageType::ERROR, &format!("{err:#}"));
+                return Ok(());
+            }
+        };
+        if self.verbose {
+            debug!("Publishing {} finding(s)", findings.len());
+        }
+
+        let diagnostics = diagnostics_by_file(&findings, &self.root);
+        // Clear the diagnostics of files that no longer have findings
+        let mut published = HashSet::new();
+        for uri in self.published.drain() {
+            if !diagnostics.contains_key(&uri) {
+                publish(self.connection, uri, Vec::new())?;
+            }
+        }
+        for (uri, file_diagnostics) in diagnostics {
+            published.insert(uri.clone());
+            publish(self.connection, uri, file_diagnostics)?;
+        }
+        self.published = published;
+        self.artifacts = Some(artifacts);
+        Ok(())
+    }
+
+    fn handle_request(&self, request: Request) -> anyhow::Result<()> {
+        let response = if request.method == CodeActionRequest::METHOD {
+            let params: CodeActionParams = serde_json::from_value(request.params)?;
+            match self.code_actions(&params.text_document.uri) {
+                Ok(actions) => Response::new_ok(request.id, actions),
+                Err(err) => Response::new_err(
+                    request.id,
+                    ErrorCode::InternalError as i32,
+                    format!("{err:#}"),
+                ),
+            }
+        } else {
+            Response::new_err(
+                request.id,
+                ErrorCode::MethodNotFound as i32,
+                format!("Unsupported request '{}'", request.method),
+            )
+        };
+        self.connection.sender.send(response.into())?;
+        Ok(())
+    }
+
+    /// A quick fix writing the missing properties of the objects defined in the document
+    fn code_actions(&self, uri: &Url) -> anyhow::Result<Vec<CodeActionOrCommand>> {
+        let Some(artifacts) = &self.artifacts else {
+            return Ok(Vec::new());
+        };
+        let Some(file) = uri
+            .to_file_path()
+            .ok()
+            .and_then(|path| relative_file(&path, &self.root))
+        else {
+            return Ok(Vec::new());
+        };
+
+        let plan = plan_fixes_for_file(
+            &artifacts.manifest,
+            artifacts.catalog.as_ref(),
+            &artifacts.config,
+            &self.options.placeholder,
+            &file,
+        );
+        let changes = apply_plan(&self.root, &plan)?;
+        if changes.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Ok(vec![CodeActionOrCommand::CodeAction(CodeAction {
+            title: "Fix missing properties with dbtective".to_string(),
+            kind: Some(CodeActionKind::QUICKFIX),
+            edit: Some(workspace_edit(&changes)?),
+            ..CodeAction::default()
+        })])
+    }
+
+    fn show_message(&self, typ: MessageType, message: &str) {
+        let params = ShowMessageParams {
+            typ,
+            message: format!("dbtective: {message}"),
+        };
+        let notification = lsp_server::Notification::new(ShowMessage::METHOD.to_string(), params);
+        self.connection.sender.send(notification.into()).ok();
+    }
+}
+
+fn publish(connection: &Connection, uri: Url, diagnostics: Vec<Diagnostic>) -> anyhow::Result<()> {
+    let params = PublishDiagnosticsParams {
+        uri,
+        diagnostics,
+        version: None,
+    };
+    let notification =
+        lsp_server::Notification::new(PublishDiagnostics::METHOD.to_string(), params);
+    connection.sender.send(notification.into())?;
+    Ok(())
+}
+
+/// Group findings by the file they were found in, findings without a file are skipped
+fn diagnostics_by_file(
+    findings: &[(RuleResult, &Severity)],
+    root: &Path,
+) -> BTreeMap<Url, Vec<Diagnostic>> {
+    let mut diagnostics: BTreeMap<Url, Vec<Diagnostic>> = BTreeMap::new();
+    for (result, severity) in findings {
+        let Some(uri) = result
+            .relative_path
+            .as_ref()
+            .and_then(|path| Url::from_file_path(root.join(path)).ok())
+        else {
+            continue;
+        };
+        diagnostics
+            .entry(uri)
+            .or_default()
+            .push(to_diagnostic(result, severity));
+    }
+    diagnostics
+}
+
+fn to_diagnostic(result: &RuleResult, severity: &Severity) -> Diagnostic {
+    Diagnostic {
+        range: diagnostic_range(result),
+        severity: Some(match severity {
+            Severity::Error => DiagnosticSeverity::ERROR,
+            Severity::Warning => DiagnosticSeverity::WARNING,
+        }),
+        code: Some(NumberOrString::String(result.rule_name.clone())),
+        source: Some("dbtective".to_string()),
+        message: result.message.clone(),
+        ..Diagnostic::default()
+    }
+}
+
+/// The rest of the line of the property the finding is about, or the first line of the file
+fn diagnostic_range(result: &RuleResult) -> Range {
+    let line = result.line.map_or(0, |line| line.saturating_sub(1));
+    let column = result.column.map_or(0, |column| column.saturating_sub(1));
+    let to_u32 = |value: usize| u32::try_from(value).unwrap_or(u32::MAX);
+    Range::new(
+        Position::new(to_u32(line), to_u32(column)),
+        Position::new(to_u32(line + 1), 0),
+    )
+}
+
+/// Path relative to the project root with `/` separators, as used in the manifest
+fn relative_file(path: &Path, root: &Path) -> Option<String> {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    path.strip_prefix(root)
+        .ok()
+        .map(|relative| relative.to_string_lossy().replace('\\', "/"))
+}
+
+/// Replace the content of every changed file, creating the files that don't exist yet
+fn workspace_edit(changes: &[FileChange]) -> anyhow::Result<WorkspaceEdit> {
+    let mut operations = Vec::new();
+    for change in changes {
+        let uri = Url::from_file_path(&change.path)
+            .ok()
+            .with_context(|| format!("Invalid file path '{}'", change.path.display()))?;
+        if !change.path.exists() {
+            operations.push(DocumentChangeOperation::Op(ResourceOp::Create(
+                CreateFile {
+                    uri: uri.clone(),
+                    options: Some(CreateFileOptions {
+                        overwrite: Some(false),
+                        ignore_if_exists: Some(true),
+                    }),
+                    annotation_id: None,
+                },
+            )));
+        }
+        operations.push(DocumentChangeOperation::Edit(TextDocumentEdit {
+            text_document: OptionalVersionedTextDocumentIdentifier { uri, version: None },
+            edits: vec![OneOf::Left(TextEdit {
+                range: Range::new(Position::new(0, 0), end_position(&change.before)),
+                new_text: change.after.clone(),
+            })],
+        }));
+    }
+    Ok(WorkspaceEdit {
+        document_changes: Some(DocumentChanges::Operations(operations)),
+        ..WorkspaceEdit::default()
+    })
+}
+
+/// Position after the last character, LSP counts characters in UTF-16 code units
+fn end_position(text: &str) -> Position {
+    let line = text.matches('\n').count();
+    let last_line = text.rsplit('\n').next().unwrap_or_default();
+    Position::new(
+        u32::try_from(line).unwrap_or(u32::MAX),
+        u32::try_from(last_line.encode_utf16().count()).unwrap_or(u32::MAX),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_end_position() {
+        assert_eq!(end_position(""), Position::new(0, 0));
+        assert_eq!(end_position("version: 2"), Position::new(0, 10));
+        assert_eq!(end_position("version: 2\n"), Position::new(1, 0));
+        // Characters outside the BMP count as two UTF-16 code units
+        assert_eq!(end_position("a\ndescription: 😀"), Position::new(1, 15));
+    }
+
+    #[test]
+    fn test_diagnostics_by_file() {
+        let root = std::env::temp_dir();
+        let error = Severity::Error;
+        let warning = Severity::Warning;
+        let findings = vec![
+            (
+                RuleResult::new(
+                    &error,
+                    "Model",
+                    "has_description",
+                    "Model is missing a description",
+                    Some("models/stg_orders.sql".to_string()),
+                ),
+                &error,
+            ),
+            (
+                RuleResult::new(
+                    &warning,
+                    "Model",
+                    "has_tags",
+                    "Model is missing tags",
+                    Some("models/stg_orders.sql".to_string()),
+                ),
+                &warning,
+            ),
+            (
+                RuleResult::new(&error, "Macro", "has_description", "No file", None),
+                &error,
+            ),
+        ];
+
+        let diagnostics = diagnostics_by_file(&findings, &root);
+        assert_eq!(diagnostics.len(), 1);
+        let (uri, file_diagnostics) = diagnostics.iter().next().unwrap();
+        assert!(uri.path().ends_with("models/stg_orders.sql"));
+        assert_eq!(file_diagnostics.len(), 2);
+        assert_eq!(
+            file_diagnostics[0].severity,
+            Some(DiagnosticSeverity::ERROR)
+        );
+        assert_eq!(
+            file_diagnostics[1].code,
+            Some(NumberOrString::String("has_tags".to_string()))
+        );
+    }
+}
@@ -0,0 +1,15 @@
+// Trait implementations for DocBlock that stay in dbtective
+use crate::core::config::includes_excludes::IncludeExcludable;
+use dbt_artifact_parser::manifest::DocBlock;
+
+impl IncludeExcludable for DocBlock {
+    fn get_relative_path(&self) -> &String {
+        self.get_relative_path()
+    }
+}
+
+impl IncludeExcludable for &DocBlock {
+    fn get_relative_path(&self) -> &String {
+        (*self).get_relative_path()
+    }
+}
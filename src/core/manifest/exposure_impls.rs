@@ -103,6 +103,10 @@ impl CanReference for Exposure {
         }
     }
 
+    fn get_depends_on_macros(&self) -> &[String] {
+        self.depends_on.macros.as_deref().unwrap_or_default()
+    }
+
     fn get_object_type(&self) -> &str {
         Self::get_object_type()
     }
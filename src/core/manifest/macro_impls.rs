@@ -3,6 +3,7 @@ use crate::core::config::applies_to::{RuleTarget, RuleTargetable};
 use crate::core::config::includes_excludes::IncludeExcludable;
 use crate::core::rules::rule_config::has_description::Descriptable;
 use crate::core::rules::rule_config::has_metadata_keys::HasMetadata;
+use crate::core::rules::rule_config::has_refs::CanReference;
 use crate::core::rules::rule_config::max_code_lines::HasCode;
 use crate::core::rules::rule_config::name_convention::NameAble;
 use dbt_artifact_parser::manifest::dbt_objects::Meta;
@@ -94,3 +95,22 @@ impl HasCode for Macro {
         Self::get_object_type()
     }
 }
+
+// Macros only call other macros
+impl CanReference for Macro {
+    fn get_depends_on_nodes(&self) -> &[String] {
+        &[]
+    }
+    fn get_depends_on_macros(&self) -> &[String] {
+        &self.depends_on.macros
+    }
+    fn get_object_type(&self) -> &str {
+        Self::get_object_type()
+    }
+    fn get_object_string(&self) -> &str {
+        self.get_name()
+    }
+    fn get_relative_path(&self) -> Option<&String> {
+        Some(self.get_relative_path())
+    }
+}
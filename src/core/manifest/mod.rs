@@ -1,13 +1,13 @@
 // Re-export types from dbt_artifact_parser
 #[allow(unused_imports)]
 pub use dbt_artifact_parser::manifest::{
-    Exposure, Group, Macro, Manifest, Node, SavedQuery, SemanticModel, Source, UnitTest,
+    DocBlock, Exposure, Group, Macro, Manifest, Node, SavedQuery, SemanticModel, Source, UnitTest,
 };
 
 // Re-export dbt_objects for backward compatibility
 #[allow(unused_imports)]
 pub mod dbt_objects {
-    pub use dbt_artifact_parser::manifest::dbt_objects::{Meta, Tags};
+    pub use dbt_artifact_parser::manifest::dbt_objects::{Column, Meta, Tags};
 }
 
 // Re-export parse_manifest for backward compatibility
@@ -18,6 +18,7 @@ pub mod parse_manifest {
 }
 
 // Trait implementation modules (these stay in dbtective)
+mod doc_block_impls;
 mod exposure_impls;
 mod macro_impls;
 mod node_impls;
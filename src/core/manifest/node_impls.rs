@@ -2,6 +2,7 @@
 use crate::core::config::applies_to::{RuleTarget, RuleTargetable};
 use crate::core::config::includes_excludes::IncludeExcludable;
 use crate::core::rules::common_traits::Columnable;
+use crate::core::rules::rule_config::allowed_dependencies::Layered;
 use crate::core::rules::rule_config::child_map::ChildMappable;
 use crate::core::rules::rule_config::has_contract_enforced::ContractAble;
 use crate::core::rules::rule_config::has_description::Descriptable;
@@ -309,6 +310,31 @@ impl CanReference for Node {
             ),
         }
     }
+    fn get_depends_on_macros(&self) -> &[String] {
+        self.get_base()
+            .depends_on
+            .macros
+            .as_deref()
+            .unwrap_or_default()
+    }
+    fn get_object_type(&self) -> &str {
+        self.get_object_type()
+    }
+
+    fn get_object_string(&self) -> &str {
+        self.get_object_string()
+    }
+
+    fn get_relative_path(&self) -> Option<&String> {
+        Some(self.get_relative_path())
+    }
+}
+
+impl Layered for Node {
+    fn get_unique_id(&self) -> &str {
+        self.get_unique_id()
+    }
+
     fn get_object_type(&self) -> &str {
         self.get_object_type()
     }
@@ -317,7 +343,25 @@ impl CanReference for Node {
         self.get_object_string()
     }
 
+    fn get_path(&self) -> &str {
+        self.get_relative_path()
+    }
+
+    fn get_tags(&self) -> &[String] {
+        self.get_base().tags.as_deref().unwrap_or_default()
+    }
+
     fn get_relative_path(&self) -> Option<&String> {
         Some(self.get_relative_path())
     }
+
+    fn get_parents<'a>(&'a self, manifest: &'a Manifest) -> Vec<&'a str> {
+        let depends_on = self.get_base().depends_on.nodes.iter().flatten();
+        let parent_map = manifest
+            .parent_map
+            .get(self.get_unique_id())
+            .into_iter()
+            .flatten();
+        depends_on.chain(parent_map).map(String::as_str).collect()
+    }
 }
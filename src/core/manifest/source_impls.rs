@@ -2,6 +2,7 @@
 use crate::core::config::applies_to::{RuleTarget, RuleTargetable};
 use crate::core::config::includes_excludes::IncludeExcludable;
 use crate::core::rules::common_traits::Columnable;
+use crate::core::rules::rule_config::allowed_dependencies::Layered;
 use crate::core::rules::rule_config::child_map::ChildMappable;
 use crate::core::rules::rule_config::has_description::Descriptable;
 use crate::core::rules::rule_config::has_metadata_keys::HasMetadata;
@@ -179,3 +180,29 @@ impl HasMetadata for Source {
         Some(self.get_relative_path())
     }
 }
+
+impl Layered for Source {
+    fn get_unique_id(&self) -> &str {
+        self.get_unique_id()
+    }
+
+    fn get_object_type(&self) -> &str {
+        Self::get_object_type()
+    }
+
+    fn get_object_string(&self) -> &str {
+        self.get_name()
+    }
+
+    fn get_path(&self) -> &str {
+        self.get_relative_path()
+    }
+
+    fn get_tags(&self) -> &[String] {
+        self.tags.as_deref().unwrap_or_default()
+    }
+
+    fn get_relative_path(&self) -> Option<&String> {
+        Some(self.get_relative_path())
+    }
+}
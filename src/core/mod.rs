@@ -1,7 +1,14 @@
 pub mod catalog;
 pub mod config;
+pub mod fix;
 pub mod init;
+pub mod init_from_manifest;
+pub mod lsp;
 pub mod manifest;
+pub mod properties_file;
 pub mod rules;
 pub mod run;
+pub mod scaffold;
+pub mod stats;
 pub mod utils;
+pub mod watch;
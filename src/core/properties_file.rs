@@ -0,0 +1,1095 @@
+// Line based editing of dbt properties (YAML) files.
+// Round-tripping through serde_yaml drops comments, ordering and formatting.
+// Instead, entries are located by indentation and edits only insert or replace individual lines,
+// so everything that is not touched stays exactly as the user wrote it.
+
+use crate::core::manifest::{Node, Source};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Where an object lives inside a properties file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ObjectPath {
+    /// Entry with the given name in a top-level list, e.g. `models:` or `exposures:`
+    Node {
+        resource_key: &'static str,
+        name: String,
+    },
+    /// `sources:` entry and the table within it
+    SourceTable {
+        source_name: String,
+        table_name: String,
+    },
+}
+
+/// The property of an object a finding is about.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Property {
+    /// The entry of the object itself
+    Entry,
+    Description,
+    /// A property that may live under `config:` or directly on the object, e.g. `tags` or `meta`
+    Config(&'static str),
+    Columns,
+    Column(String),
+}
+
+/// A property of an object in a properties file (relative to the project root).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertyRef {
+    pub file: String,
+    pub object: ObjectPath,
+    pub property: Property,
+}
+
+impl PropertyRef {
+    /// A property of a model, seed or snapshot in its properties file
+    pub fn of_node(node: &Node, property: Property) -> Option<Self> {
+        node_target(node).map(|(file, object)| Self {
+            file,
+            object,
+            property,
+        })
+    }
+
+    pub fn of_source(source: &Source, property: Property) -> Self {
+        Self {
+            file: source.get_relative_path().clone(),
+            object: ObjectPath::SourceTable {
+                source_name: source.source_name.clone(),
+                table_name: source.name.clone(),
+            },
+            property,
+        }
+    }
+
+    /// A property of an object defined in a properties file, e.g. an exposure
+    pub fn of_entry(
+        file: &str,
+        resource_key: &'static str,
+        name: &str,
+        property: Property,
+    ) -> Self {
+        Self {
+            file: file.to_string(),
+            object: ObjectPath::Node {
+                resource_key,
+                name: name.to_string(),
+            },
+            property,
+        }
+    }
+}
+
+/// Properties files of a project, by their path relative to the project root
+#[derive(Debug, Default)]
+pub struct PropertiesFiles {
+    files: HashMap<String, PropertiesFile>,
+}
+
+impl PropertiesFiles {
+    /// Read the given files, files that can't be read are skipped
+    pub fn read<'a>(entry_point: &Path, files: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut read = HashMap::new();
+        for file in files {
+            if read.contains_key(file) {
+                continue;
+            }
+            if let Ok(content) = fs::read_to_string(entry_point.join(file)) {
+                read.insert(file.to_string(), PropertiesFile::new(&content));
+            }
+        }
+        Self { files: read }
+    }
+
+    pub fn get(&self, file: &str) -> Option<&PropertiesFile> {
+        self.files.get(file)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ColumnEdit {
+    pub name: String,
+    pub data_type: Option<String>,
+    pub description: Option<String>,
+}
+
+/// Properties to add to an object. Existing values are never overwritten,
+/// only missing or empty properties are filled in.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ObjectEdit {
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    pub meta: Vec<(String, String)>,
+    pub columns: Vec<ColumnEdit>,
+}
+
+impl ObjectEdit {
+    pub const fn is_empty(&self) -> bool {
+        self.description.is_none()
+            && self.tags.is_empty()
+            && self.meta.is_empty()
+            && self.columns.is_empty()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PropertiesFile {
+    lines: Vec<String>,
+    trailing_newline: bool,
+}
+
+impl PropertiesFile {
+    pub fn new(content: &str) -> Self {
+        let mut lines: Vec<String> = content.lines().map(ToString::to_string).collect();
+        if lines.is_empty() {
+            lines.push("version: 2".to_string());
+        }
+        Self {
+            lines,
+            trailing_newline: content.is_empty() || content.ends_with('\n'),
+        }
+    }
+
+    pub fn render(&self) -> String {
+        let mut content = self.lines.join("\n");
+        if self.trailing_newline {
+            content.push('\n');
+        }
+        content
+    }
+
+    /// Apply an edit to an object, creating the object entry if it does not exist yet.
+    pub fn apply(&mut self, path: &ObjectPath, edit: &ObjectEdit) {
+        if edit.is_empty() {
+            return;
+        }
+        let item_line = self.ensure_object(path);
+
+        if let Some(description) = &edit.description {
+            set_scalar_if_empty(&mut self.lines, item_line, "description", description);
+        }
+        if !edit.tags.is_empty() {
+            let item_line = self.ensure_object(path);
+            add_tags(&mut self.lines, item_line, &edit.tags);
+        }
+        if !edit.meta.is_empty() {
+            let item_line = self.ensure_object(path);
+            add_meta(&mut self.lines, item_line, &edit.meta);
+        }
+        for column in &edit.columns {
+            let item_line = self.ensure_object(path);
+            add_column(&mut self.lines, item_line, column);
+        }
+    }
+
+    /// Line and column (both 1-based) of a property, falling back to the entry of the object
+    /// when the property isn't set. Returns `None` if the object is not in this file.
+    pub fn locate(&self, path: &ObjectPath, property: &Property) -> Option<(usize, usize)> {
+        let item_line = self.find_object(path)?;
+        let item = Mapping::of_item(&self.lines, item_line);
+        let columns = || item.find(&self.lines, "columns");
+
+        let line = match property {
+            Property::Entry => None,
+            Property::Description => item.find(&self.lines, "description"),
+            Property::Config(key) => locate_config_property(&self.lines, item_line, key).ok(),
+            Property::Columns => columns(),
+            Property::Column(name) => columns().map(|columns_line| {
+                find_list_item(&self.lines, columns_line, name).unwrap_or(columns_line)
+            }),
+        }
+        .unwrap_or_else(|| item.find(&self.lines, "name").unwrap_or(item_line));
+
+        let col = parse_key(&self.lines[line]).map_or(0, |(col, _, _)| col);
+        Some((line + 1, col + 1))
+    }
+
+    fn find_object(&self, path: &ObjectPath) -> Option<usize> {
+        match path {
+            ObjectPath::Node { resource_key, name } => {
+                let key_line = find_top_level_key(&self.lines, resource_key)?;
+                find_list_item(&self.lines, key_line, name)
+            }
+            ObjectPath::SourceTable {
+                source_name,
+                table_name,
+            } => {
+                let key_line = find_top_level_key(&self.lines, "sources")?;
+                let source_line = find_list_item(&self.lines, key_line, source_name)?;
+                let tables_line =
+                    Mapping::of_item(&self.lines, source_line).find(&self.lines, "tables")?;
+                find_list_item(&self.lines, tables_line, table_name)
+            }
+        }
+    }
+
+    fn ensure_object(&mut self, path: &ObjectPath) -> usize {
+        match path {
+            ObjectPath::Node { resource_key, name } => {
+                let key_line = ensure_top_level_key(&mut self.lines, resource_key);
+                ensure_list_item(&mut self.lines, key_line, name)
+            }
+            ObjectPath::SourceTable {
+                source_name,
+                table_name,
+            } => {
+                let key_line = ensure_top_level_key(&mut self.lines, "sources");
+                let source_line = ensure_list_item(&mut self.lines, key_line, source_name);
+                let tables_line = ensure_key(&mut self.lines, source_line, "tables");
+                ensure_list_item(&mut self.lines, tables_line, table_name)
+            }
+        }
+    }
+}
+
+/// The properties file and entry of a node. Nodes without a properties file
+/// get one next to their sql/csv file (`_models.yml`, `_seeds.yml`, `_snapshots.yml`).
+pub fn node_target(node: &Node) -> Option<(String, ObjectPath)> {
+    let resource_key = match node {
+        Node::Model(_) => "models",
+        Node::Seed(_) => "seeds",
+        Node::Snapshot(_) => "snapshots",
+        _ => return None,
+    };
+
+    let file = node.get_patch_path().map_or_else(
+        || {
+            let directory = Path::new(node.get_relative_path())
+                .parent()
+                .map(|dir| dir.to_string_lossy().replace('\\', "/"))
+                .unwrap_or_default();
+            if directory.is_empty() {
+                format!("_{resource_key}.yml")
+            } else {
+                format!("{directory}/_{resource_key}.yml")
+            }
+        },
+        ToString::to_string,
+    );
+
+    Some((
+        file,
+        ObjectPath::Node {
+            resource_key,
+            name: node.get_name().clone(),
+        },
+    ))
+}
+
+/// Format a value as a YAML scalar, quoting it when a plain scalar would be ambiguous.
+pub fn yaml_scalar(value: &str) -> String {
+    let reserved = matches!(
+        value.to_ascii_lowercase().as_str(),
+        "true" | "false" | "yes" | "no" | "on" | "off" | "null" | "~"
+    );
+    let plain = !value.is_empty()
+        && !reserved
+        && value.parse::<f64>().is_err()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '/' | ' '))
+        && !value.starts_with(['-', '.', ' '])
+        && !value.ends_with(' ');
+
+    if plain {
+        value.to_string()
+    } else {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+fn is_ignorable(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed.is_empty() || trimmed.starts_with('#')
+}
+
+fn is_list_item(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed == "-" || trimmed.starts_with("- ")
+}
+
+/// Parse `key: value` (optionally prefixed by a list dash).
+/// Returns the column of the key, the key and the raw value.
+fn parse_key(line: &str) -> Option<(usize, &str, &str)> {
+    let mut col = indent_of(line);
+    let mut rest = &line[col..];
+    if is_list_item(rest) {
+        let after_dash = &rest[1..];
+        let trimmed = after_dash.trim_start();
+        col += 1 + after_dash.len() - trimmed.len();
+        rest = trimmed;
+    }
+    if rest.starts_with('#') {
+        return None;
+    }
+
+    let colon = rest
+        .char_indices()
+        .find(|(i, c)| *c == ':' && rest[i + 1..].chars().next().is_none_or(char::is_whitespace))
+        .map(|(i, _)| i)?;
+    let key = unquote(rest[..colon].trim());
+    Some((col, key, rest[colon + 1..].trim()))
+}
+
+fn unquote(value: &str) -> &str {
+    let value = value.trim();
+    for quote in ['"', '\''] {
+        if let Some(inner) = value
+            .strip_prefix(quote)
+            .and_then(|v| v.strip_suffix(quote))
+        {
+            return inner;
+        }
+    }
+    value
+}
+
+/// Characters of a value outside quoted scalars, with their byte offset.
+/// Quotes only open a scalar at its start, so `it's` stays plain.
+fn unquoted_chars(value: &str) -> Vec<(usize, char)> {
+    let mut chars = Vec::new();
+    let mut quote = None;
+    let mut escaped = false;
+    let mut previous = None;
+    for (i, c) in value.char_indices() {
+        if let Some(open) = quote {
+            if escaped {
+                escaped = false;
+            } else if open == '"' && c == '\\' {
+                escaped = true;
+            } else if c == open {
+                quote = None;
+                previous = Some(c);
+            }
+            continue;
+        }
+        if matches!(c, '"' | '\'') && matches!(previous, None | Some('[' | '{' | ',' | ':')) {
+            quote = Some(c);
+            continue;
+        }
+        if !c.is_whitespace() {
+            previous = Some(c);
+        }
+        chars.push((i, c));
+    }
+    chars
+}
+
+/// Remove a trailing `# comment` from a value
+fn strip_comment(value: &str) -> &str {
+    unquoted_chars(value)
+        .into_iter()
+        .find(|&(i, c)| c == '#' && (i == 0 || value[..i].ends_with(char::is_whitespace)))
+        .map_or(value, |(i, _)| value[..i].trim_end())
+}
+
+/// Split a value into the value and its trailing ` # comment`, so the comment can be kept
+fn split_comment(value: &str) -> (&str, &str) {
+    value.split_at(strip_comment(value).len())
+}
+
+/// Items of the inside of a flow list or mapping, split on its top-level commas only
+fn split_flow(inner: &str) -> Vec<&str> {
+    let mut items = Vec::new();
+    let mut depth = 0_usize;
+    let mut start = 0;
+    for (i, c) in unquoted_chars(inner) {
+        match c {
+            '[' | '{' => depth += 1,
+            ']' | '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                items.push(&inner[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    items.push(&inner[start..]);
+    items
+        .into_iter()
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+/// End (exclusive) of the block starting at `start`, excluding trailing blank lines and comments.
+/// Children are indented deeper than `indent`; for mapping keys, list items may also sit
+/// at the same indentation as the key (`tables:\n- name: ...`).
+fn block_end(lines: &[String], start: usize, indent: usize, same_indent_items: bool) -> usize {
+    let mut end = start + 1;
+    for (i, line) in lines.iter().enumerate().skip(start + 1) {
+        if is_ignorable(line) {
+            continue;
+        }
+        let line_indent = indent_of(line);
+        if line_indent > indent
+            || (same_indent_items && line_indent == indent && is_list_item(line))
+        {
+            end = i + 1;
+        } else {
+            break;
+        }
+    }
+    end
+}
+
+/// A mapping: the lines in `start..end` whose keys start at column `col`.
+struct Mapping {
+    start: usize,
+    end: usize,
+    col: usize,
+}
+
+impl Mapping {
+    /// The mapping of a list item, starting at its `- ` line
+    fn of_item(lines: &[String], item_line: usize) -> Self {
+        let col =
+            parse_key(&lines[item_line]).map_or_else(|| indent_of(&lines[item_line]) + 2, |k| k.0);
+        Self {
+            start: item_line,
+            end: block_end(lines, item_line, indent_of(&lines[item_line]), false),
+            col,
+        }
+    }
+
+    /// The mapping nested under a `key:` line
+    fn of_key(lines: &[String], key_line: usize) -> Self {
+        let key_col = parse_key(&lines[key_line]).map_or(0, |k| k.0);
+        let end = block_end(lines, key_line, key_col, true);
+        let col = lines[key_line + 1..end]
+            .iter()
+            .find(|line| !is_ignorable(line))
+            .map_or(key_col + 2, |line| indent_of(line));
+        Self {
+            start: key_line + 1,
+            end,
+            col,
+        }
+    }
+
+    fn find(&self, lines: &[String], key: &str) -> Option<usize> {
+        (self.start..self.end).find(|&i| {
+            parse_key(&lines[i]).is_some_and(|(col, found, _)| col == self.col && found == key)
+        })
+    }
+}
+
+fn find_top_level_key(lines: &[String], key: &str) -> Option<usize> {
+    lines.iter().position(|line| {
+        indent_of(line) == 0
+            && !is_list_item(line)
+            && parse_key(line).is_some_and(|(_, found, _)| found == key)
+    })
+}
+
+/// Lines of the list items under a `key:` line
+fn list_items(lines: &[String], key_line: usize) -> Vec<usize> {
+    let key_col = parse_key(&lines[key_line]).map_or(0, |k| k.0);
+    let end = block_end(lines, key_line, key_col, true);
+    let Some(dash_indent) = lines[key_line + 1..end]
+        .iter()
+        .find(|line| is_list_item(line))
+        .map(|line| indent_of(line))
+    else {
+        return Vec::new();
+    };
+    (key_line + 1..end)
+        .filter(|&i| is_list_item(&lines[i]) && indent_of(&lines[i]) == dash_indent)
+        .collect()
+}
+
+fn item_name(lines: &[String], item_line: usize) -> Option<String> {
+    let item = Mapping::of_item(lines, item_line);
+    let name_line = item.find(lines, "name")?;
+    parse_key(&lines[name_line]).map(|(_, _, value)| unquote(strip_comment(value)).to_string())
+}
+
+fn find_list_item(lines: &[String], key_line: usize, name: &str) -> Option<usize> {
+    list_items(lines, key_line)
+        .into_iter()
+        .find(|&item| item_name(lines, item).is_some_and(|found| found.eq_ignore_ascii_case(name)))
+}
+
+fn ensure_top_level_key(lines: &mut Vec<String>, key: &str) -> usize {
+    if let Some(line) = find_top_level_key(lines, key) {
+        return line;
+    }
+    if lines.last().is_some_and(|line| !line.trim().is_empty()) {
+        lines.push(String::new());
+    }
+    lines.push(format!("{key}:"));
+    lines.len() - 1
+}
+
+/// Find a list item by name under a `key:` line, or append a new `- name: ...` item.
+fn ensure_list_item(lines: &mut Vec<String>, key_line: usize, name: &str) -> usize {
+    if let Some(item) = find_list_item(lines, key_line, name) {
+        return item;
+    }
+
+    let (key_col, key, value) = parse_key(&lines[key_line]).unwrap_or((0, "", ""));
+    let (value, comment) = split_comment(value);
+    // An inline empty list (`columns: []`) becomes a block list
+    if !value.is_empty() {
+        lines[key_line] = format!("{}{key}:{comment}", " ".repeat(key_col));
+    }
+
+    let items = list_items(lines, key_line);
+    let dash_indent = items
+        .first()
+        .map_or(key_col + 2, |&item| indent_of(&lines[item]));
+    let blank_separated = items.len() > 1 && lines[items[1] - 1].trim().is_empty();
+
+    let mut insert_at = block_end(lines, key_line, key_col, true);
+    if blank_separated {
+        lines.insert(insert_at, String::new());
+        insert_at += 1;
+    }
+    lines.insert(
+        insert_at,
+        format!("{}- name: {}", " ".repeat(dash_indent), yaml_scalar(name)),
+    );
+    insert_at
+}
+
+/// Find a key in the mapping of a list item, or append it (without a value).
+fn ensure_key(lines: &mut Vec<String>, item_line: usize, key: &str) -> usize {
+    let item = Mapping::of_item(lines, item_line);
+    if let Some(line) = item.find(lines, key) {
+        return line;
+    }
+    lines.insert(item.end, format!("{}{key}:", " ".repeat(item.col)));
+    item.end
+}
+
+fn is_empty_scalar(value: &str) -> bool {
+    matches!(strip_comment(value), "" | "\"\"" | "''" | "null" | "~")
+}
+
+/// Set `key: value` on a list item, unless the key already has a non-empty value.
+/// New keys are placed directly below the `name` of the item.
+fn set_scalar_if_empty(lines: &mut Vec<String>, item_line: usize, key: &str, value: &str) {
+    let item = Mapping::of_item(lines, item_line);
+    let new_line = format!("{}{key}: {}", " ".repeat(item.col), yaml_scalar(value));
+
+    if let Some(line) = item.find(lines, key) {
+        let (_, _, current) = parse_key(&lines[line]).unwrap_or((0, "", ""));
+        let has_children = block_end(lines, line, item.col, false) > line + 1;
+        if is_empty_scalar(current) && !has_children {
+            lines[line] = new_line;
+        }
+        return;
+    }
+
+    let insert_at = item.find(lines, "name").map_or(item.end, |line| line + 1);
+    lines.insert(insert_at, new_line);
+}
+
+/// The line of a property that may live under `config:` or directly on the object.
+/// Returns the line of the key if it exists, otherwise the mapping it should be added to.
+fn locate_config_property(lines: &[String], item_line: usize, key: &str) -> Result<usize, Mapping> {
+    let item = Mapping::of_item(lines, item_line);
+    let config = item
+        .find(lines, "config")
+        .map(|config_line| Mapping::of_key(lines, config_line));
+
+    if let Some(line) = config.as_ref().and_then(|config| config.find(lines, key)) {
+        return Ok(line);
+    }
+    if let Some(line) = item.find(lines, key) {
+        return Ok(line);
+    }
+    Err(config.unwrap_or(item))
+}
+
+/// Values that are not in `existing` yet, formatted as YAML scalars
+fn new_values(values: &[String], existing: &[String]) -> Vec<String> {
+    values
+        .iter()
+        .filter(|value| !existing.contains(value))
+        .map(|value| yaml_scalar(value))
+        .collect()
+}
+
+fn add_tags(lines: &mut Vec<String>, item_line: usize, tags: &[String]) {
+    match locate_config_property(lines, item_line, "tags") {
+        Ok(line) => {
+            let (col, _, value) = parse_key(&lines[line]).unwrap_or((0, "", ""));
+            let (value, comment) = split_comment(value);
+            let (value, comment) = (value.to_string(), comment.to_string());
+            let prefix = lines[line][..col].to_string();
+            if let Some(inner) = value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+                let mut items: Vec<String> = split_flow(inner)
+                    .into_iter()
+                    .map(ToString::to_string)
+                    .collect();
+                let existing: Vec<String> =
+                    items.iter().map(|item| unquote(item).to_string()).collect();
+                let new_tags = new_values(tags, &existing);
+                if !new_tags.is_empty() {
+                    items.extend(new_tags);
+                    lines[line] = format!("{prefix}tags: [{}]{comment}", items.join(", "));
+                }
+            } else if !value.is_empty() {
+                let new_tags = new_values(tags, &[unquote(&value).to_string()]);
+                if !new_tags.is_empty() {
+                    lines[line] =
+                        format!("{prefix}tags: [{value}, {}]{comment}", new_tags.join(", "));
+                }
+            } else {
+                let list = Mapping::of_key(lines, line);
+                let existing: Vec<String> = lines[list.start..list.end]
+                    .iter()
+                    .filter(|line| is_list_item(line))
+                    .map(|line| unquote(strip_comment(line.trim_start()[1..].trim())).to_string())
+                    .collect();
+                let dash_indent = " ".repeat(list.col);
+                for (offset, tag) in new_values(tags, &existing).iter().enumerate() {
+                    lines.insert(list.end + offset, format!("{dash_indent}- {tag}"));
+                }
+            }
+        }
+        Err(mapping) => {
+            let indent = " ".repeat(mapping.col);
+            lines.insert(mapping.end, format!("{indent}tags:"));
+            for (offset, tag) in new_values(tags, &[]).iter().enumerate() {
+                lines.insert(mapping.end + 1 + offset, format!("{indent}  - {tag}"));
+            }
+        }
+    }
+}
+
+fn add_meta(lines: &mut Vec<String>, item_line: usize, meta: &[(String, String)]) {
+    let entries = |existing: &[String]| -> Vec<String> {
+        meta.iter()
+            .filter(|(key, _)| !existing.contains(key))
+            .map(|(key, value)| format!("{}: {}", yaml_scalar(key), yaml_scalar(value)))
+            .collect()
+    };
+
+    let (meta_line, mapping_col) = match locate_config_property(lines, item_line, "meta") {
+        Ok(line) => {
+            let (col, _, value) = parse_key(&lines[line]).unwrap_or((0, "", ""));
+            let (value, comment) = split_comment(value);
+            let comment = comment.to_string();
+            if let Some(inner) = value.strip_prefix('{').and_then(|v| v.strip_suffix('}')) {
+                let inner = inner.trim().to_string();
+                if !inner.is_empty() {
+                    let existing: Vec<String> = split_flow(&inner)
+                        .into_iter()
+                        .filter_map(parse_key)
+                        .map(|(_, key, _)| key.to_string())
+                        .collect();
+                    let new_entries = entries(&existing);
+                    if !new_entries.is_empty() {
+                        let prefix = &lines[line][..col];
+                        lines[line] = format!(
+                            "{prefix}meta: {{{inner}, {}}}{comment}",
+                            new_entries.join(", ")
+                        );
+                    }
+                    return;
+                }
+                lines[line] = format!("{}meta:{comment}", &lines[line][..col]);
+            }
+            (line, col)
+        }
+        Err(mapping) => {
+            lines.insert(mapping.end, format!("{}meta:", " ".repeat(mapping.col)));
+            (mapping.end, mapping.col)
+        }
+    };
+
+    let children = Mapping::of_key(lines, meta_line);
+    let col = if children.end > meta_line + 1 {
+        children.col
+    } else {
+        mapping_col + 2
+    };
+    let existing: Vec<String> = lines[children.start..children.end]
+        .iter()
+        .filter_map(|line| parse_key(line))
+        .filter(|(key_col, _, _)| *key_col == col)
+        .map(|(_, key, _)| key.to_string())
+        .collect();
+    for (offset, entry) in entries(&existing).iter().enumerate() {
+        lines.insert(children.end + offset, format!("{}{entry}", " ".repeat(col)));
+    }
+}
+
+fn add_column(lines: &mut Vec<String>, item_line: usize, column: &ColumnEdit) {
+    let list_line = ensure_key(lines, item_line, "columns");
+    let column_line = ensure_list_item(lines, list_line, &column.name);
+
+    if let Some(data_type) = &column.data_type {
+        set_scalar_if_empty(lines, column_line, "data_type", data_type);
+    }
+    if let Some(description) = &column.description {
+        set_scalar_if_empty(lines, column_line, "description", description);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model(name: &str) -> ObjectPath {
+        ObjectPath::Node {
+            resource_key: "models",
+            name: name.to_string(),
+        }
+    }
+
+    fn apply(content: &str, path: &ObjectPath, edit: &ObjectEdit) -> String {
+        let mut file = PropertiesFile::new(content);
+        file.apply(path, edit);
+        file.render()
+    }
+
+    const MODELS: &str = "version: 2
+
+models:
+  # Customers, one row per customer
+  - name: stg_customers
+    description: Staging table for customer data.
+    config:
+      meta:
+        maturity: gold
+    columns:
+      - name: customer_id
+        tests:
+          - unique
+
+  - name: stg_orders
+    columns:
+      - name: order_id
+";
+
+    #[test]
+    fn test_locate_existing_properties() {
+        let file = PropertiesFile::new(MODELS);
+        let customers = model("stg_customers");
+        assert_eq!(
+            file.locate(&customers, &Property::Description),
+            Some((6, 5))
+        );
+        // `meta` lives under `config:`
+        assert_eq!(
+            file.locate(&customers, &Property::Config("meta")),
+            Some((8, 7))
+        );
+        assert_eq!(
+            file.locate(&customers, &Property::Column("customer_id".to_string())),
+            Some((11, 9))
+        );
+    }
+
+    #[test]
+    fn test_locate_missing_properties() {
+        let file = PropertiesFile::new(MODELS);
+        let orders = model("stg_orders");
+        // Missing properties point at the entry of the object
+        assert_eq!(file.locate(&orders, &Property::Description), Some((15, 5)));
+        assert_eq!(
+            file.locate(&orders, &Property::Config("tags")),
+            Some((15, 5))
+        );
+        // Undocumented columns point at the `columns:` block
+        assert_eq!(
+            file.locate(&orders, &Property::Column("status".to_string())),
+            Some((16, 5))
+        );
+        assert_eq!(file.locate(&model("fct_orders"), &Property::Entry), None);
+    }
+
+    #[test]
+    fn test_locate_source_table() {
+        let file = PropertiesFile::new(
+            "version: 2\nsources:\n  - name: raw\n    tables:\n      - name: orders\n        description: Orders\n",
+        );
+        let orders = ObjectPath::SourceTable {
+            source_name: "raw".to_string(),
+            table_name: "orders".to_string(),
+        };
+        assert_eq!(file.locate(&orders, &Property::Description), Some((6, 9)));
+        assert_eq!(file.locate(&orders, &Property::Columns), Some((5, 9)));
+    }
+
+    #[test]
+    fn test_yaml_scalar_quoting() {
+        assert_eq!(yaml_scalar("daily"), "daily");
+        assert_eq!(
+            yaml_scalar("TODO: add a description"),
+            "\"TODO: add a description\""
+        );
+        assert_eq!(yaml_scalar("yes"), "\"yes\"");
+        assert_eq!(yaml_scalar("123"), "\"123\"");
+        assert_eq!(yaml_scalar("say \"hi\""), "\"say \\\"hi\\\"\"");
+    }
+
+    #[test]
+    fn test_empty_edit_changes_nothing() {
+        assert_eq!(
+            apply(MODELS, &model("stg_orders"), &ObjectEdit::default()),
+            MODELS
+        );
+    }
+
+    #[test]
+    fn test_add_description_below_name() {
+        let edit = ObjectEdit {
+            description: Some("TODO".to_string()),
+            ..ObjectEdit::default()
+        };
+        let result = apply(MODELS, &model("stg_orders"), &edit);
+        assert!(result.contains("  - name: stg_orders\n    description: TODO\n    columns:"));
+        // Comments and other entries are preserved
+        assert!(result.contains("  # Customers, one row per customer\n"));
+        assert!(result.contains("    description: Staging table for customer data.\n"));
+    }
+
+    #[test]
+    fn test_existing_description_is_not_overwritten() {
+        let edit = ObjectEdit {
+            description: Some("TODO".to_string()),
+            ..ObjectEdit::default()
+        };
+        assert_eq!(apply(MODELS, &model("stg_customers"), &edit), MODELS);
+    }
+
+    #[test]
+    fn test_empty_description_is_replaced() {
+        let content = "models:\n  - name: a\n    description: \"\"\n";
+        let edit = ObjectEdit {
+            description: Some("TODO".to_string()),
+            ..ObjectEdit::default()
+        };
+        assert_eq!(
+            apply(content, &model("a"), &edit),
+            "models:\n  - name: a\n    description: TODO\n"
+        );
+    }
+
+    #[test]
+    fn test_add_meta_to_existing_config_meta() {
+        let edit = ObjectEdit {
+            meta: vec![("owner".to_string(), "TODO".to_string())],
+            ..ObjectEdit::default()
+        };
+        let result = apply(MODELS, &model("stg_customers"), &edit);
+        assert!(result
+            .contains("      meta:\n        maturity: gold\n        owner: TODO\n    columns:"));
+    }
+
+    #[test]
+    fn test_add_meta_and_tags_without_config() {
+        let edit = ObjectEdit {
+            tags: vec!["daily".to_string()],
+            meta: vec![("owner".to_string(), "TODO".to_string())],
+            ..ObjectEdit::default()
+        };
+        let result = apply(MODELS, &model("stg_orders"), &edit);
+        assert!(result.contains(
+            "  - name: stg_orders\n    columns:\n      - name: order_id\n    tags:\n      - daily\n    meta:\n      owner: TODO\n"
+        ));
+    }
+
+    #[test]
+    fn test_add_tags_to_config() {
+        let edit = ObjectEdit {
+            tags: vec!["daily".to_string()],
+            ..ObjectEdit::default()
+        };
+        let result = apply(MODELS, &model("stg_customers"), &edit);
+        assert!(
+            result.contains("        maturity: gold\n      tags:\n        - daily\n    columns:")
+        );
+    }
+
+    #[test]
+    fn test_add_tags_to_flow_list() {
+        let content = "models:\n  - name: a\n    tags: [hourly] # schedule\n";
+        let edit = ObjectEdit {
+            tags: vec!["daily".to_string()],
+            ..ObjectEdit::default()
+        };
+        assert_eq!(
+            apply(content, &model("a"), &edit),
+            "models:\n  - name: a\n    tags: [hourly, daily] # schedule\n"
+        );
+    }
+
+    #[test]
+    fn test_flow_list_is_split_on_top_level_commas() {
+        let content = "models:\n  - name: a\n    tags: [\"hourly, daily\", 'it''s, ok']\n";
+        let edit = ObjectEdit {
+            tags: vec!["hourly, daily".to_string()],
+            ..ObjectEdit::default()
+        };
+        assert_eq!(apply(content, &model("a"), &edit), content);
+    }
+
+    #[test]
+    fn test_add_tags_to_block_list() {
+        let content = "models:\n  - name: a\n    tags:\n      - hourly\n    columns: []\n";
+        let edit = ObjectEdit {
+            tags: vec!["daily".to_string()],
+            ..ObjectEdit::default()
+        };
+        assert_eq!(
+            apply(content, &model("a"), &edit),
+            "models:\n  - name: a\n    tags:\n      - hourly\n      - daily\n    columns: []\n"
+        );
+    }
+
+    #[test]
+    fn test_add_meta_to_inline_mapping() {
+        let content = "models:\n  - name: a\n    meta: {}\n";
+        let edit = ObjectEdit {
+            meta: vec![("owner".to_string(), "TODO".to_string())],
+            ..ObjectEdit::default()
+        };
+        assert_eq!(
+            apply(content, &model("a"), &edit),
+            "models:\n  - name: a\n    meta:\n      owner: TODO\n"
+        );
+    }
+
+    #[test]
+    fn test_add_meta_to_inline_mapping_keeps_comment() {
+        let content = "models:\n  - name: a\n    meta: {owners: {lead: x, team: y}} # c\n";
+        let edit = ObjectEdit {
+            meta: vec![
+                ("owners".to_string(), "TODO".to_string()),
+                ("team".to_string(), "TODO".to_string()),
+            ],
+            ..ObjectEdit::default()
+        };
+        assert_eq!(
+            apply(content, &model("a"), &edit),
+            "models:\n  - name: a\n    meta: {owners: {lead: x, team: y}, team: TODO} # c\n"
+        );
+    }
+
+    #[test]
+    fn test_add_columns_and_describe_existing_column() {
+        let edit = ObjectEdit {
+            columns: vec![
+                ColumnEdit {
+                    name: "order_id".to_string(),
+                    description: Some("TODO".to_string()),
+                    data_type: None,
+                },
+                ColumnEdit {
+                    name: "status".to_string(),
+                    description: Some("TODO".to_string()),
+                    data_type: Some("varchar".to_string()),
+                },
+            ],
+            ..ObjectEdit::default()
+        };
+        let result = apply(MODELS, &model("stg_orders"), &edit);
+        assert!(result.ends_with(
+            "  - name: stg_orders\n    columns:\n      - name: order_id\n        description: TODO\n      - name: status\n        description: TODO\n        data_type: varchar\n"
+        ));
+    }
+
+    #[test]
+    fn test_add_columns_to_inline_empty_list() {
+        let content = "models:\n  - name: a\n    columns: []\n";
+        let edit = ObjectEdit {
+            columns: vec![ColumnEdit {
+                name: "id".to_string(),
+                ..ColumnEdit::default()
+            }],
+            ..ObjectEdit::default()
+        };
+        assert_eq!(
+            apply(content, &model("a"), &edit),
+            "models:\n  - name: a\n    columns:\n      - name: id\n"
+        );
+    }
+
+    #[test]
+    fn test_add_columns_to_inline_empty_list_keeps_comment() {
+        let content = "models:\n  - name: a\n    columns: [] # c\n";
+        let edit = ObjectEdit {
+            columns: vec![ColumnEdit {
+                name: "id".to_string(),
+                ..ColumnEdit::default()
+            }],
+            ..ObjectEdit::default()
+        };
+        assert_eq!(
+            apply(content, &model("a"), &edit),
+            "models:\n  - name: a\n    columns: # c\n      - name: id\n"
+        );
+    }
+
+    #[test]
+    fn test_new_model_entry_keeps_blank_line_separation() {
+        let content = "models:\n  - name: a\n\n  - name: b\n";
+        let edit = ObjectEdit {
+            description: Some("TODO".to_string()),
+            ..ObjectEdit::default()
+        };
+        assert_eq!(
+            apply(content, &model("c"), &edit),
+            "models:\n  - name: a\n\n  - name: b\n\n  - name: c\n    description: TODO\n"
+        );
+    }
+
+    #[test]
+    fn test_new_file_gets_version_header() {
+        let edit = ObjectEdit {
+            description: Some("TODO".to_string()),
+            ..ObjectEdit::default()
+        };
+        assert_eq!(
+            apply("", &model("a"), &edit),
+            "version: 2\n\nmodels:\n  - name: a\n    description: TODO\n"
+        );
+    }
+
+    #[test]
+    fn test_source_table_with_same_indent_list() {
+        let content = "sources:\n  - name: raw\n    tables:\n    - name: customers\n      description: Customers\n";
+        let path = ObjectPath::SourceTable {
+            source_name: "raw".to_string(),
+            table_name: "customers".to_string(),
+        };
+        let edit = ObjectEdit {
+            tags: vec!["pii".to_string()],
+            ..ObjectEdit::default()
+        };
+        assert_eq!(
+            apply(content, &path, &edit),
+            "sources:\n  - name: raw\n    tables:\n    - name: customers\n      description: Customers\n      tags:\n        - pii\n"
+        );
+
+        let new_table = ObjectPath::SourceTable {
+            source_name: "raw".to_string(),
+            table_name: "orders".to_string(),
+        };
+        let result = apply(content, &new_table, &edit);
+        assert!(result.ends_with("    - name: orders\n      tags:\n        - pii\n"));
+    }
+
+    #[test]
+    fn test_existing_tags_and_meta_are_not_duplicated() {
+        let content = "models:\n  - name: a\n    tags: [daily]\n    meta:\n      owner: me\n  - name: b\n    tags:\n      - daily\n    meta: {owner: me}\n";
+        let edit = ObjectEdit {
+            tags: vec!["daily".to_string()],
+            meta: vec![("owner".to_string(), "TODO".to_string())],
+            ..ObjectEdit::default()
+        };
+        assert_eq!(apply(content, &model("a"), &edit), content);
+        assert_eq!(apply(content, &model("b"), &edit), content);
+    }
+}
@@ -7,9 +7,10 @@ use crate::{
             severity::Severity, Config,
         },
         manifest::Manifest,
+        properties_file::{Property, PropertyRef},
         rules::catalog::{
             column_name_convention, columns_are_documented, columns_canonical_name,
-            columns_have_description,
+            columns_have_description, columns_have_description::column_without_description,
         },
     },
 };
@@ -41,7 +42,7 @@ pub fn apply_catalog_node_rules<'a>(
         .flat_map(|catalog_node| catalog_tests.iter().map(move |rule| (catalog_node, rule)))
         .try_fold(Vec::new(), |mut acc, (catalog_node, rule)| -> anyhow::Result<_> {
             let Some(manifest_node) = manifest.get_node(catalog_node.get_unique_id()) else {
-                println!(
+                eprintln!(
                     "{}",
                     format!(
                         "Warning: No matching manifest node found for catalog node '{}'.\n\
@@ -111,7 +112,14 @@ pub fn apply_catalog_node_rules<'a>(
             };
 
             if let Some(rule_row) = rule_row_result {
-                acc.push((rule_row, &rule.severity));
+                let property = match &rule.rule {
+                    CatalogSpecificRuleConfig::ColumnsHaveDescription {} => {
+                        column_without_description(manifest_node)
+                    }
+                    _ => Property::Columns,
+                };
+                let property = PropertyRef::of_node(manifest_node, property);
+                acc.push((rule_row.with_property(property), &rule.severity));
             }
 
             Ok(acc)
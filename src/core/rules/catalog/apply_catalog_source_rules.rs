@@ -7,9 +7,10 @@ use crate::{
             severity::Severity, Config,
         },
         manifest::Manifest,
+        properties_file::{Property, PropertyRef},
         rules::catalog::{
             column_name_convention, columns_are_documented, columns_canonical_name,
-            columns_have_description,
+            columns_have_description, columns_have_description::column_without_description,
         },
     },
 };
@@ -42,7 +43,7 @@ pub fn apply_catalog_source_rules<'a>(
         .try_fold(Vec::new(), |mut acc, (catalog_source, rule)| -> anyhow::Result<_> {
             let Some(manifest_source) = manifest.get_source(catalog_source.get_unique_id()) else {
                 // Mismatch between catalog and manifest sources
-                println!(
+                eprintln!(
                     "{}",
                     format!(
                         "Warning: No matching manifest source found for catalog source '{}'.\n\
@@ -103,7 +104,14 @@ pub fn apply_catalog_source_rules<'a>(
             };
 
             if let Some(rule_row) = rule_row_result {
-                acc.push((rule_row, &rule.severity));
+                let property = match &rule.rule {
+                    CatalogSpecificRuleConfig::ColumnsHaveDescription {} => {
+                        column_without_description(manifest_source)
+                    }
+                    _ => Property::Columns,
+                };
+                let property = PropertyRef::of_source(manifest_source, property);
+                acc.push((rule_row.with_property(Some(property)), &rule.severity));
             }
 
             Ok(acc)
@@ -82,7 +82,7 @@ pub fn columns_are_documented<C: Columnable, M: Columnable>(
     ))
 }
 
-fn compare_column_names(
+pub fn compare_column_names(
     catalog_columns: &Vec<&String>,
     manifest_columns: &Vec<&String>,
     case_insensitive: bool,
@@ -1,6 +1,9 @@
 use crate::{
     cli::table::RuleResult,
-    core::{config::catalog_rule::CatalogRule, rules::common_traits::Columnable},
+    core::{
+        config::catalog_rule::CatalogRule, properties_file::Property,
+        rules::common_traits::Columnable,
+    },
 };
 
 // Remember, the manifest object contains the descriptions, but the manifest isn't always exhaustive
@@ -72,6 +75,21 @@ pub fn columns_have_description<C: Columnable, M: Columnable>(
     ))
 }
 
+/// The column entry a finding should point at: the first documented column without a description.
+pub fn column_without_description<M: Columnable>(manifest_object: &M) -> Property {
+    manifest_object
+        .get_columns_with_descriptions()
+        .and_then(|columns| {
+            columns
+                .into_iter()
+                .filter(|(_, description)| description.trim().is_empty())
+                .map(|(name, _)| name)
+                .min()
+                .cloned()
+        })
+        .map_or(Property::Columns, Property::Column)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
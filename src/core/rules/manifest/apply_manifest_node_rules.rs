@@ -1,14 +1,27 @@
+use std::cell::OnceCell;
+use std::collections::{HashMap, HashSet};
+
 use crate::cli::table::RuleResult;
 use crate::core::config::applies_to::RuleTargetable;
-use crate::core::config::manifest_rule::ManifestSpecificRuleConfig;
+use crate::core::config::manifest_rule::{ManifestRule, ManifestSpecificRuleConfig};
 use crate::core::rules::rule_config::{
-    check_name_convention, child_map::is_not_orphaned, has_contract_enforced, has_description,
-    has_metadata_keys, has_refs, has_tags, has_unique_test, max_code_lines,
+    allowed_dependencies, check_name_convention,
+    child_map::is_not_orphaned,
+    column_has_tests, description_quality, has_contract_enforced, has_description,
+    has_metadata_keys, has_primary_key_test, has_refs, has_relationship_tests, has_tags,
+    has_unique_test, max_children, max_code_lines, max_dag_depth,
+    max_dag_depth::DagChains,
+    max_parents, model_versions, no_deprecated_references,
+    no_deprecated_references::today,
+    no_hardcoded_references, redundant_dependencies, source_outside_staging, uses_doc_blocks,
+    uses_doc_blocks::{doc_block_contents, models_per_column},
 };
 
 use crate::core::config::severity::Severity;
 use crate::core::config::{includes_excludes::should_run_test, Config};
-use crate::core::manifest::Manifest;
+use crate::core::manifest::{Manifest, Node};
+use crate::core::properties_file::PropertyRef;
+use crate::core::rules::common_traits::Columnable;
 
 /// Applies node rules to the manifest.
 ///
@@ -20,6 +33,7 @@ pub fn apply_manifest_node_rules<'a>(
     _verbose: bool,
 ) -> anyhow::Result<Vec<(RuleResult, &'a Severity)>> {
     let results = if let Some(manifest_tests) = &config.manifest_tests {
+        let lookups = ManifestLookups::default();
         manifest
             .nodes
             .values()
@@ -60,6 +74,16 @@ pub fn apply_manifest_node_rules<'a>(
                     ManifestSpecificRuleConfig::HasUniqueTest { allowed_test_names } => {
                         has_unique_test(node, rule, manifest, allowed_test_names)
                     }
+                    ManifestSpecificRuleConfig::HasPrimaryKeyTest {
+                        unique_test_names,
+                        not_null_test_names,
+                    } => has_primary_key_test(
+                        node,
+                        rule,
+                        manifest,
+                        unique_test_names,
+                        not_null_test_names,
+                    ),
                     ManifestSpecificRuleConfig::HasContractEnforced {} => {
                         has_contract_enforced(node, rule)
                     }
@@ -71,10 +95,43 @@ pub fn apply_manifest_node_rules<'a>(
                         max_code_lines(node, rule, *max_lines)
                     }
                     ManifestSpecificRuleConfig::HasRefs {} => has_refs(node, rule),
+                    // Only applies to macros
+                    ManifestSpecificRuleConfig::MacroIsUsed { .. }
+                    | ManifestSpecificRuleConfig::MacroArgumentsDocumented { .. } => None,
+                    ManifestSpecificRuleConfig::MaxChildren {
+                        max,
+                        resource_types,
+                        layers,
+                    } => max_children(node, rule, *max, resource_types, layers, manifest),
+                    ManifestSpecificRuleConfig::MaxParents {
+                        max,
+                        resource_types,
+                        layers,
+                    } => max_parents(node, rule, *max, resource_types, layers, manifest),
+                    // Rules with a result per finding (e.g. per illegal edge)
+                    ManifestSpecificRuleConfig::AllowedDependencies { .. }
+                    | ManifestSpecificRuleConfig::ColumnHasTests { .. }
+                    | ManifestSpecificRuleConfig::DescriptionQuality { .. }
+                    | ManifestSpecificRuleConfig::HasRelationshipTests { .. }
+                    | ManifestSpecificRuleConfig::MaxDagDepth { .. }
+                    | ManifestSpecificRuleConfig::ModelVersions { .. }
+                    | ManifestSpecificRuleConfig::NoDeprecatedReferences { .. }
+                    | ManifestSpecificRuleConfig::NoHardcodedReferences {}
+                    | ManifestSpecificRuleConfig::RedundantDependencies { .. }
+                    | ManifestSpecificRuleConfig::SourcesOnlyInStaging { .. }
+                    | ManifestSpecificRuleConfig::UsesDocBlocks { .. } => {
+                        let results = apply_multi_result_rule(node, rule, manifest, &lookups);
+                        acc.extend(results.into_iter().map(|result| (result, &rule.severity)));
+                        return Ok(acc);
+                    }
                 };
 
                 if let Some(rule_row) = rule_row_result {
-                    acc.push((rule_row, &rule.severity));
+                    let property = rule
+                        .rule
+                        .property()
+                        .and_then(|property| PropertyRef::of_node(node, property));
+                    acc.push((rule_row.with_property(property), &rule.severity));
                 }
 
                 Ok(acc)
@@ -86,3 +143,90 @@ pub fn apply_manifest_node_rules<'a>(
 
     Ok(results)
 }
+
+// Lookups over the whole manifest, built when a rule first needs them and shared by all nodes
+#[derive(Default)]
+struct ManifestLookups<'a> {
+    dag_chains: OnceCell<DagChains<'a>>,
+    models_per_column: OnceCell<HashMap<String, usize>>,
+    doc_block_contents: OnceCell<HashSet<String>>,
+}
+
+// Rules that can have several findings per node
+fn apply_multi_result_rule<'a>(
+    node: &Node,
+    rule: &ManifestRule,
+    manifest: &'a Manifest,
+    lookups: &ManifestLookups<'a>,
+) -> Vec<RuleResult> {
+    match &rule.rule {
+        ManifestSpecificRuleConfig::AllowedDependencies { layers, allowed } => {
+            allowed_dependencies(node, rule, layers, allowed, manifest)
+        }
+        ManifestSpecificRuleConfig::ColumnHasTests {
+            selector,
+            required_tests,
+        } => column_has_tests(node, rule, manifest, selector, required_tests),
+        ManifestSpecificRuleConfig::DescriptionQuality { criteria } => {
+            let columns = node.get_columns_with_descriptions().unwrap_or_default();
+            description_quality(node, &columns, rule, criteria)
+                .into_iter()
+                .map(|(result, property)| {
+                    result.with_property(PropertyRef::of_node(node, property))
+                })
+                .collect()
+        }
+        ManifestSpecificRuleConfig::HasRelationshipTests {
+            columns,
+            allowed_test_names,
+            exemptions,
+        } => has_relationship_tests(
+            node,
+            rule,
+            manifest,
+            columns,
+            allowed_test_names,
+            exemptions,
+        ),
+        ManifestSpecificRuleConfig::UsesDocBlocks {
+            columns,
+            used_in_more_than,
+        } => uses_doc_blocks(
+            node,
+            rule,
+            lookups
+                .models_per_column
+                .get_or_init(|| models_per_column(manifest)),
+            lookups
+                .doc_block_contents
+                .get_or_init(|| doc_block_contents(manifest)),
+            columns,
+            *used_in_more_than,
+        ),
+        ManifestSpecificRuleConfig::MaxDagDepth {
+            max_depth,
+            max_chained_views,
+        } => max_dag_depth(
+            node,
+            rule,
+            *max_depth,
+            *max_chained_views,
+            lookups.dag_chains.get_or_init(|| DagChains::new(manifest)),
+        ),
+        ManifestSpecificRuleConfig::ModelVersions { checks } => {
+            model_versions(node, rule, checks, manifest, &today())
+        }
+        ManifestSpecificRuleConfig::NoDeprecatedReferences { deprecated } => {
+            no_deprecated_references(node, rule, deprecated, manifest, &today())
+        }
+        ManifestSpecificRuleConfig::NoHardcodedReferences {} => no_hardcoded_references(node, rule),
+        ManifestSpecificRuleConfig::RedundantDependencies { exemptions } => {
+            redundant_dependencies(node, rule, exemptions, manifest)
+        }
+        ManifestSpecificRuleConfig::SourcesOnlyInStaging {
+            staging_paths,
+            staging_prefixes,
+        } => source_outside_staging(node, rule, staging_paths, staging_prefixes, manifest),
+        _ => Vec::new(),
+    }
+}
@@ -1,7 +1,10 @@
 use crate::core::config::applies_to::RuleTargetable;
+use crate::core::rules::common_traits::Columnable;
 use crate::core::rules::rule_config::{
-    check_name_convention, has_description, has_metadata_keys, has_refs, has_tags, has_unique_test,
-    is_not_orphaned, max_code_lines,
+    check_name_convention, description_quality, duplicate_staging_models, has_description,
+    has_metadata_keys, has_refs, has_tags, has_unique_test, is_not_orphaned,
+    macro_arguments_documented, macro_is_used, macro_is_used::called_macros, max_children,
+    max_code_lines, no_deprecated_references, no_deprecated_references::today,
 };
 use crate::{
     cli::table::RuleResult,
@@ -11,6 +14,7 @@ use crate::{
             severity::Severity, Config,
         },
         manifest::Manifest,
+        properties_file::PropertyRef,
     },
 };
 
@@ -66,6 +70,18 @@ fn apply_source_rules<'a>(
 
                 let rule_row_result = match &rule.rule {
                     ManifestSpecificRuleConfig::HasDescription {} => has_description(source, rule),
+                    ManifestSpecificRuleConfig::DescriptionQuality { criteria } => {
+                        let columns = source.get_columns_with_descriptions().unwrap_or_default();
+                        acc.extend(
+                            description_quality(source, &columns, rule, criteria)
+                                .into_iter()
+                                .map(|(result, property)| {
+                                    let property = PropertyRef::of_source(source, property);
+                                    (result.with_property(Some(property)), &rule.severity)
+                                }),
+                        );
+                        return Ok(acc);
+                    }
                     ManifestSpecificRuleConfig::NameConvention { convention } => {
                         check_name_convention(source, rule, convention)
                     }
@@ -83,15 +99,47 @@ fn apply_source_rules<'a>(
                         required_keys,
                         custom_message,
                     } => has_metadata_keys(source, rule, required_keys, custom_message.as_ref()),
+                    ManifestSpecificRuleConfig::MaxChildren {
+                        max,
+                        resource_types,
+                        layers,
+                    } => max_children(source, rule, *max, resource_types, layers, manifest),
+                    ManifestSpecificRuleConfig::SourcesOnlyInStaging {
+                        staging_paths,
+                        staging_prefixes,
+                    } => duplicate_staging_models(
+                        source,
+                        rule,
+                        staging_paths,
+                        staging_prefixes,
+                        manifest,
+                    ),
 
                     // These can't be implemented for exposures
                     ManifestSpecificRuleConfig::HasRefs {}
                     | ManifestSpecificRuleConfig::MaxCodeLines { .. }
+                    | ManifestSpecificRuleConfig::AllowedDependencies { .. }
+                    | ManifestSpecificRuleConfig::MaxParents { .. }
+                    | ManifestSpecificRuleConfig::RedundantDependencies { .. }
+                    | ManifestSpecificRuleConfig::MaxDagDepth { .. }
+                    | ManifestSpecificRuleConfig::NoHardcodedReferences {}
+                    | ManifestSpecificRuleConfig::HasPrimaryKeyTest { .. }
+                    | ManifestSpecificRuleConfig::HasRelationshipTests { .. }
+                    | ManifestSpecificRuleConfig::ColumnHasTests { .. }
+                    | ManifestSpecificRuleConfig::ModelVersions { .. }
+                    | ManifestSpecificRuleConfig::UsesDocBlocks { .. }
+                    | ManifestSpecificRuleConfig::MacroIsUsed { .. }
+                    | ManifestSpecificRuleConfig::MacroArgumentsDocumented { .. }
+                    | ManifestSpecificRuleConfig::NoDeprecatedReferences { .. }
                     | ManifestSpecificRuleConfig::HasContractEnforced {} => return Ok(acc), // Models only
                 };
 
                 if let Some(rule_row) = rule_row_result {
-                    acc.push((rule_row, &rule.severity));
+                    let property = rule
+                        .rule
+                        .property()
+                        .map(|property| PropertyRef::of_source(source, property));
+                    acc.push((rule_row.with_property(property), &rule.severity));
                 }
 
                 Ok(acc)
@@ -112,6 +160,7 @@ fn apply_macro_rules<'a>(
     _verbose: bool,
 ) -> anyhow::Result<Vec<(RuleResult, &'a Severity)>> {
     let results = if let Some(manifest_tests) = &config.manifest_tests {
+        let called_macros = called_macros(manifest);
         manifest
             .macros
             .values()
@@ -133,6 +182,14 @@ fn apply_macro_rules<'a>(
                         ManifestSpecificRuleConfig::HasDescription {} => {
                             has_description(macro_obj, rule)
                         }
+                        ManifestSpecificRuleConfig::DescriptionQuality { criteria } => {
+                            acc.extend(
+                                description_quality(macro_obj, &[], rule, criteria)
+                                    .into_iter()
+                                    .map(|(result, _)| (result, &rule.severity)),
+                            );
+                            return Ok(acc);
+                        }
                         ManifestSpecificRuleConfig::NameConvention { convention } => {
                             check_name_convention(macro_obj, rule, convention)
                         }
@@ -148,11 +205,45 @@ fn apply_macro_rules<'a>(
                         ManifestSpecificRuleConfig::MaxCodeLines { max_lines } => {
                             max_code_lines(macro_obj, rule, *max_lines)
                         }
+                        ManifestSpecificRuleConfig::MacroIsUsed { exemptions } => {
+                            macro_is_used(macro_obj, rule, exemptions, &called_macros)
+                        }
+                        ManifestSpecificRuleConfig::MacroArgumentsDocumented { require_types } => {
+                            acc.extend(
+                                macro_arguments_documented(macro_obj, rule, *require_types)
+                                    .into_iter()
+                                    .map(|result| (result, &rule.severity)),
+                            );
+                            return Ok(acc);
+                        }
+                        ManifestSpecificRuleConfig::NoDeprecatedReferences { deprecated } => {
+                            let results = no_deprecated_references(
+                                macro_obj,
+                                rule,
+                                deprecated,
+                                manifest,
+                                &today(),
+                            );
+                            acc.extend(results.into_iter().map(|result| (result, &rule.severity)));
+                            return Ok(acc);
+                        }
                         // These can't be implemented for macros
                         ManifestSpecificRuleConfig::HasTags { .. }
                         | ManifestSpecificRuleConfig::IsNotOrphaned { .. }
                         | ManifestSpecificRuleConfig::HasUniqueTest { .. }
                         | ManifestSpecificRuleConfig::HasRefs {}
+                        | ManifestSpecificRuleConfig::AllowedDependencies { .. }
+                        | ManifestSpecificRuleConfig::SourcesOnlyInStaging { .. }
+                        | ManifestSpecificRuleConfig::MaxChildren { .. }
+                        | ManifestSpecificRuleConfig::MaxParents { .. }
+                        | ManifestSpecificRuleConfig::RedundantDependencies { .. }
+                        | ManifestSpecificRuleConfig::MaxDagDepth { .. }
+                        | ManifestSpecificRuleConfig::NoHardcodedReferences {}
+                        | ManifestSpecificRuleConfig::HasPrimaryKeyTest { .. }
+                        | ManifestSpecificRuleConfig::HasRelationshipTests { .. }
+                        | ManifestSpecificRuleConfig::ColumnHasTests { .. }
+                        | ManifestSpecificRuleConfig::ModelVersions { .. }
+                        | ManifestSpecificRuleConfig::UsesDocBlocks { .. }
                         | ManifestSpecificRuleConfig::HasContractEnforced {} => return Ok(acc), //
                     };
 
@@ -173,6 +264,8 @@ fn apply_macro_rules<'a>(
 /// Applies exposure rules to the manifest.
 /// # Errors
 /// Returns an error if a rule has invalid configuration (e.g., invalid regex pattern).
+// The match has an arm per rule, it grows with every rule
+#[allow(clippy::too_many_lines)]
 fn apply_exposure_rules<'a>(
     manifest: &'a Manifest,
     config: &'a Config,
@@ -200,6 +293,22 @@ fn apply_exposure_rules<'a>(
                         ManifestSpecificRuleConfig::HasDescription {} => {
                             has_description(exposure, rule)
                         }
+                        ManifestSpecificRuleConfig::DescriptionQuality { criteria } => {
+                            acc.extend(
+                                description_quality(exposure, &[], rule, criteria)
+                                    .into_iter()
+                                    .map(|(result, property)| {
+                                        let property = PropertyRef::of_entry(
+                                            exposure.get_relative_path(),
+                                            "exposures",
+                                            &exposure.name,
+                                            property,
+                                        );
+                                        (result.with_property(Some(property)), &rule.severity)
+                                    }),
+                            );
+                            return Ok(acc);
+                        }
                         ManifestSpecificRuleConfig::NameConvention { convention } => {
                             check_name_convention(exposure, rule, convention)
                         }
@@ -217,15 +326,48 @@ fn apply_exposure_rules<'a>(
                             custom_message.as_ref(),
                         ),
                         ManifestSpecificRuleConfig::HasRefs {} => has_refs(exposure, rule),
+                        ManifestSpecificRuleConfig::NoDeprecatedReferences { deprecated } => {
+                            let results = no_deprecated_references(
+                                exposure,
+                                rule,
+                                deprecated,
+                                manifest,
+                                &today(),
+                            );
+                            acc.extend(results.into_iter().map(|result| (result, &rule.severity)));
+                            return Ok(acc);
+                        }
                         // These can't be implemented for exposures
                         ManifestSpecificRuleConfig::IsNotOrphaned { .. }
                         | ManifestSpecificRuleConfig::MaxCodeLines { .. }
+                        | ManifestSpecificRuleConfig::AllowedDependencies { .. }
+                        | ManifestSpecificRuleConfig::SourcesOnlyInStaging { .. }
+                        | ManifestSpecificRuleConfig::MaxChildren { .. }
+                        | ManifestSpecificRuleConfig::MaxParents { .. }
+                        | ManifestSpecificRuleConfig::RedundantDependencies { .. }
+                        | ManifestSpecificRuleConfig::MaxDagDepth { .. }
+                        | ManifestSpecificRuleConfig::NoHardcodedReferences {}
+                        | ManifestSpecificRuleConfig::HasPrimaryKeyTest { .. }
+                        | ManifestSpecificRuleConfig::HasRelationshipTests { .. }
+                        | ManifestSpecificRuleConfig::ColumnHasTests { .. }
+                        | ManifestSpecificRuleConfig::ModelVersions { .. }
+                        | ManifestSpecificRuleConfig::UsesDocBlocks { .. }
+                        | ManifestSpecificRuleConfig::MacroIsUsed { .. }
+                        | ManifestSpecificRuleConfig::MacroArgumentsDocumented { .. }
                         | ManifestSpecificRuleConfig::HasUniqueTest { .. }
                         | ManifestSpecificRuleConfig::HasContractEnforced {} => return Ok(acc),
                     };
 
                     if let Some(rule_row) = rule_row_result {
-                        acc.push((rule_row, &rule.severity));
+                        let property = rule.rule.property().map(|property| {
+                            PropertyRef::of_entry(
+                                exposure.get_relative_path(),
+                                "exposures",
+                                &exposure.name,
+                                property,
+                            )
+                        });
+                        acc.push((rule_row.with_property(property), &rule.severity));
                     }
 
                     Ok(acc)
@@ -276,13 +418,37 @@ fn apply_semantic_model_rules<'a>(
                     // These can't be implemented for semantic models
                     ManifestSpecificRuleConfig::HasTags { .. }
                     | ManifestSpecificRuleConfig::MaxCodeLines { .. }
+                    | ManifestSpecificRuleConfig::AllowedDependencies { .. }
+                    | ManifestSpecificRuleConfig::SourcesOnlyInStaging { .. }
+                    | ManifestSpecificRuleConfig::MaxChildren { .. }
+                    | ManifestSpecificRuleConfig::MaxParents { .. }
+                    | ManifestSpecificRuleConfig::RedundantDependencies { .. }
+                    | ManifestSpecificRuleConfig::MaxDagDepth { .. }
+                    | ManifestSpecificRuleConfig::NoHardcodedReferences {}
+                    | ManifestSpecificRuleConfig::HasPrimaryKeyTest { .. }
+                    | ManifestSpecificRuleConfig::HasRelationshipTests { .. }
+                    | ManifestSpecificRuleConfig::ColumnHasTests { .. }
+                    | ManifestSpecificRuleConfig::ModelVersions { .. }
+                    | ManifestSpecificRuleConfig::UsesDocBlocks { .. }
+                    | ManifestSpecificRuleConfig::MacroIsUsed { .. }
+                    | ManifestSpecificRuleConfig::MacroArgumentsDocumented { .. }
+                    | ManifestSpecificRuleConfig::NoDeprecatedReferences { .. }
+                    | ManifestSpecificRuleConfig::DescriptionQuality { .. }
                     | ManifestSpecificRuleConfig::IsNotOrphaned { .. }
                     | ManifestSpecificRuleConfig::HasUniqueTest { .. }
                     | ManifestSpecificRuleConfig::HasContractEnforced {} => return Ok(acc),
                 };
 
                 if let Some(rule_row) = rule_row_result {
-                    acc.push((rule_row, &rule.severity));
+                    let property = rule.rule.property().map(|property| {
+                        PropertyRef::of_entry(
+                            sm.get_relative_path(),
+                            "semantic_models",
+                            &sm.name,
+                            property,
+                        )
+                    });
+                    acc.push((rule_row.with_property(property), &rule.severity));
                 }
 
                 Ok(acc)
@@ -331,6 +497,22 @@ fn apply_unit_test_rules<'a>(
                     | ManifestSpecificRuleConfig::IsNotOrphaned { .. }
                     | ManifestSpecificRuleConfig::HasUniqueTest { .. }
                     | ManifestSpecificRuleConfig::HasContractEnforced {}
+                    | ManifestSpecificRuleConfig::AllowedDependencies { .. }
+                    | ManifestSpecificRuleConfig::SourcesOnlyInStaging { .. }
+                    | ManifestSpecificRuleConfig::MaxChildren { .. }
+                    | ManifestSpecificRuleConfig::MaxParents { .. }
+                    | ManifestSpecificRuleConfig::RedundantDependencies { .. }
+                    | ManifestSpecificRuleConfig::MaxDagDepth { .. }
+                    | ManifestSpecificRuleConfig::NoHardcodedReferences {}
+                    | ManifestSpecificRuleConfig::HasPrimaryKeyTest { .. }
+                    | ManifestSpecificRuleConfig::HasRelationshipTests { .. }
+                    | ManifestSpecificRuleConfig::ColumnHasTests { .. }
+                    | ManifestSpecificRuleConfig::ModelVersions { .. }
+                    | ManifestSpecificRuleConfig::UsesDocBlocks { .. }
+                    | ManifestSpecificRuleConfig::MacroIsUsed { .. }
+                    | ManifestSpecificRuleConfig::MacroArgumentsDocumented { .. }
+                    | ManifestSpecificRuleConfig::NoDeprecatedReferences { .. }
+                    | ManifestSpecificRuleConfig::DescriptionQuality { .. }
                     | ManifestSpecificRuleConfig::HasMetadataKeys { .. } => return Ok(acc),
                 };
 
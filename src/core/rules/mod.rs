@@ -1,4 +1,7 @@
 pub mod catalog;
 pub mod common_traits;
 pub mod manifest;
+pub mod project;
 pub mod rule_config;
+#[cfg(test)]
+pub mod test_utils;
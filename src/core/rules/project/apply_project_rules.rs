@@ -0,0 +1,97 @@
+use std::cell::OnceCell;
+
+use crate::cli::table::RuleResult;
+use crate::core::config::includes_excludes::should_run_test;
+use crate::core::config::project_rule::ProjectSpecificRuleConfig;
+use crate::core::config::severity::Severity;
+use crate::core::config::Config;
+use crate::core::manifest::{Manifest, Node};
+use crate::core::rules::project::consistent_column_descriptions::CanonicalName;
+use crate::core::rules::project::{
+    consistent_column_descriptions, documentation_coverage, duplicate_descriptions, test_coverage,
+    unused_doc_blocks,
+};
+
+/// Applies project rules to the manifest.
+///
+/// Project rules look at all models of the project at once (models of installed packages are
+/// not counted), their findings aren't tied to a single object.
+pub fn apply_project_rules<'a>(
+    manifest: &Manifest,
+    config: &'a Config,
+) -> Vec<(RuleResult, &'a Severity)> {
+    let Some(project_tests) = &config.project_tests else {
+        return Vec::new();
+    };
+
+    let models = project_models(manifest);
+    let tests_by_parent = OnceCell::new();
+    project_tests
+        .iter()
+        .flat_map(|rule| {
+            let models: Vec<&Node> = models
+                .iter()
+                .copied()
+                .filter(|model| {
+                    should_run_test(model, rule.includes.as_ref(), rule.excludes.as_ref())
+                })
+                .collect();
+            let results = match &rule.rule {
+                ProjectSpecificRuleConfig::DocumentationCoverage {
+                    min_percent,
+                    group_by,
+                } => documentation_coverage(&models, rule, *min_percent, *group_by),
+                ProjectSpecificRuleConfig::TestCoverage {
+                    min_percent,
+                    group_by,
+                } => test_coverage(
+                    &models,
+                    rule,
+                    *min_percent,
+                    *group_by,
+                    tests_by_parent.get_or_init(|| manifest.get_tests_by_attached_node()),
+                ),
+                ProjectSpecificRuleConfig::DuplicateDescriptions {} => {
+                    duplicate_descriptions(&models, rule, manifest)
+                }
+                ProjectSpecificRuleConfig::ConsistentColumnDescriptions {
+                    use_canonical_names,
+                    require_doc_blocks,
+                } => {
+                    let canonical_names = if *use_canonical_names {
+                        CanonicalName::from_config(config)
+                    } else {
+                        Vec::new()
+                    };
+                    consistent_column_descriptions(
+                        &models,
+                        rule,
+                        &canonical_names,
+                        *require_doc_blocks,
+                        manifest,
+                    )
+                }
+                ProjectSpecificRuleConfig::UnusedDocBlocks {} => unused_doc_blocks(manifest, rule),
+            };
+            results.into_iter().map(|result| (result, &rule.severity))
+        })
+        .collect()
+}
+
+/// Models of the project itself (not of installed packages), sorted by unique id.
+pub fn project_models(manifest: &Manifest) -> Vec<&Node> {
+    let mut models: Vec<&Node> = manifest
+        .nodes
+        .values()
+        .filter(|node| matches!(node, Node::Model(_)))
+        .filter(|node| {
+            manifest
+                .metadata
+                .project_name
+                .as_ref()
+                .is_none_or(|project| project == node.get_package_name())
+        })
+        .collect();
+    models.sort_by_key(|node| node.get_unique_id());
+    models
+}
@@ -0,0 +1,323 @@
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+use crate::{
+    cli::table::RuleResult,
+    core::{
+        config::{
+            catalog_rule::CatalogSpecificRuleConfig, check_config_options::ColumnNamePattern,
+            project_rule::ProjectRule, Config,
+        },
+        manifest::{dbt_objects::Column, DocBlock, Manifest, Node},
+        properties_file::{Property, PropertyRef},
+        rules::{
+            project::duplicate_descriptions::{excerpt, normalize_description},
+            rule_config::allowed_dependencies::parents_of,
+        },
+    },
+};
+
+/// A `columns_canonical_name` catalog rule, columns matching `invalid_names` are the `canonical` column.
+pub struct CanonicalName<'a> {
+    canonical: &'a str,
+    invalid_names: &'a [ColumnNamePattern],
+    exceptions: &'a [ColumnNamePattern],
+}
+
+impl<'a> CanonicalName<'a> {
+    pub fn from_config(config: &'a Config) -> Vec<Self> {
+        config
+            .catalog_tests
+            .iter()
+            .flatten()
+            .filter_map(|rule| match &rule.rule {
+                CatalogSpecificRuleConfig::ColumnsCanonicalName {
+                    canonical,
+                    invalid_names,
+                    exceptions,
+                } => Some(Self {
+                    canonical,
+                    invalid_names,
+                    exceptions: exceptions.as_deref().unwrap_or_default(),
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn matches(&self, column: &str) -> bool {
+        !self
+            .exceptions
+            .iter()
+            .any(|pattern| pattern.matches(column))
+            && self
+                .invalid_names
+                .iter()
+                .any(|pattern| pattern.matches(column))
+    }
+}
+
+/// Lower-cased name columns are grouped by, the canonical name when one applies.
+fn group_name(column: &str, canonical_names: &[CanonicalName]) -> String {
+    canonical_names
+        .iter()
+        .find(|canonical| canonical.matches(column))
+        .map_or(column, |canonical| canonical.canonical)
+        .to_lowercase()
+}
+
+fn ancestors<'a>(unique_id: &str, manifest: &'a Manifest) -> HashSet<&'a str> {
+    let mut ancestors = HashSet::new();
+    let mut queue: VecDeque<&str> = parents_of(unique_id, manifest).into();
+    while let Some(current) = queue.pop_front() {
+        if ancestors.insert(current) {
+            queue.extend(parents_of(current, manifest));
+        }
+    }
+    ancestors
+}
+
+/// Doc blocks by their (normalized, lower-cased) contents, a description with the same text reuses the block.
+fn doc_blocks_by_contents(manifest: &Manifest) -> HashMap<String, &DocBlock> {
+    let mut blocks: Vec<&DocBlock> = manifest.docs.values().collect();
+    blocks.sort_by_key(|block| &block.unique_id);
+    let mut by_contents = HashMap::new();
+    for block in blocks {
+        if let Some(contents) = normalize_description(Some(&block.block_contents)) {
+            by_contents.entry(contents.to_lowercase()).or_insert(block);
+        }
+    }
+    by_contents
+}
+
+/// Report columns that are described differently than the same column upstream.
+///
+/// Columns are grouped by name (or canonical name), the description of the upstream-most model
+/// in a group is the one the others should use.
+pub fn consistent_column_descriptions(
+    models: &[&Node],
+    rule: &ProjectRule,
+    canonical_names: &[CanonicalName],
+    require_doc_blocks: bool,
+    manifest: &Manifest,
+) -> Vec<RuleResult> {
+    let doc_blocks = doc_blocks_by_contents(manifest);
+
+    let mut groups: BTreeMap<String, Vec<(&Node, &Column, String)>> = BTreeMap::new();
+    for model in models {
+        for column in model
+            .get_base()
+            .columns
+            .iter()
+            .flat_map(|columns| columns.values())
+        {
+            if let Some(description) = normalize_description(column.description.as_ref()) {
+                groups
+                    .entry(group_name(&column.name, canonical_names))
+                    .or_default()
+                    .push((model, column, description));
+            }
+        }
+    }
+
+    // A model has columns in many groups, its ancestors are only looked up once
+    let ancestors_of: HashMap<&str, HashSet<&str>> = models
+        .iter()
+        .map(|model| {
+            let unique_id = model.get_unique_id().as_str();
+            (unique_id, ancestors(unique_id, manifest))
+        })
+        .collect();
+
+    let mut results = Vec::new();
+    for columns in groups.values_mut().filter(|columns| columns.len() > 1) {
+        columns.sort_by_key(|(model, column, _)| (model.get_unique_id(), &column.name));
+        let ancestors: Vec<&HashSet<&str>> = columns
+            .iter()
+            .map(|(model, _, _)| &ancestors_of[model.get_unique_id().as_str()])
+            .collect();
+        // No other model of the group upstream, and the fewest ancestors of those
+        let upstream_index = (0..columns.len())
+            .filter(|&index| {
+                !columns
+                    .iter()
+                    .any(|(model, _, _)| ancestors[index].contains(model.get_unique_id().as_str()))
+            })
+            .min_by_key(|&index| ancestors[index].len())
+            .unwrap_or_default();
+
+        let (upstream, _, expected) = &columns[upstream_index];
+        let expected = expected.to_lowercase();
+        let suggestion = doc_blocks.get(&expected).map_or_else(
+            || format!("\"{}\"", excerpt(&columns[upstream_index].2)),
+            |block| format!("{{{{ doc('{}') }}}}", block.name),
+        );
+
+        for (index, (model, column, description)) in columns.iter().enumerate() {
+            if index == upstream_index {
+                continue;
+            }
+            let name = &column.name;
+            let description = description.to_lowercase();
+            // Manifests before dbt 1.10 have no doc_blocks, then the rendered text is all we have
+            let uses_doc_block = column.doc_blocks.as_ref().map_or_else(
+                || doc_blocks.contains_key(&description),
+                |blocks| !blocks.is_empty(),
+            );
+            let message = if description != expected {
+                format!(
+                    "Column '{name}' of {} is described differently than in {}, suggested description: {suggestion}",
+                    model.get_name(),
+                    upstream.get_name()
+                )
+            } else if require_doc_blocks && !uses_doc_block {
+                format!(
+                    "Column '{name}' of {} repeats the description of {}, reuse a doc block instead",
+                    model.get_name(),
+                    upstream.get_name()
+                )
+            } else {
+                continue;
+            };
+            results.push(
+                RuleResult::new(
+                    &rule.severity,
+                    model.get_object_type(),
+                    rule.get_name(),
+                    message,
+                    Some(model.get_relative_path().clone()),
+                )
+                .with_property(PropertyRef::of_node(model, Property::Column(name.clone()))),
+            );
+        }
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::project_rule::ProjectSpecificRuleConfig;
+    use crate::core::config::severity::Severity;
+    use crate::core::rules::test_utils::{messages, ManifestBuilder};
+    use serde_json::json;
+
+    fn manifest() -> Manifest {
+        ManifestBuilder::default()
+            .model(
+                "stg_customers",
+                json!({
+                    "columns": {
+                        "customer_id": {"name": "customer_id", "description": "Unique id of a customer.", "tags": []},
+                        "zip_code": {"name": "zip_code", "description": "Postal code", "tags": []}
+                    }
+                }),
+            )
+            .model(
+                "customers",
+                json!({
+                    "depends_on": {"nodes": ["model.p.stg_customers"]},
+                    "columns": {
+                        "customer_id": {"name": "customer_id", "description": "unique id of a  customer.", "tags": []},
+                        "zipcode": {"name": "zipcode", "description": "Zip", "tags": []}
+                    }
+                }),
+            )
+            .model(
+                "orders",
+                json!({
+                    "depends_on": {"nodes": ["model.p.customers"]},
+                    "columns": {
+                        "customer_id": {"name": "customer_id", "description": "The customer", "tags": []}
+                    }
+                }),
+            )
+            .doc("customer_id", "Unique id of a customer.\n")
+            .build()
+    }
+
+    fn inconsistencies(
+        manifest: &Manifest,
+        canonical_names: &[CanonicalName],
+        require_doc_blocks: bool,
+    ) -> Vec<String> {
+        let mut models: Vec<&Node> = manifest.nodes.values().collect();
+        models.sort_by_key(|node| node.get_unique_id());
+        let rule = ProjectRule::from_specific_rule(
+            ProjectSpecificRuleConfig::ConsistentColumnDescriptions {
+                use_canonical_names: false,
+                require_doc_blocks,
+            },
+            Severity::Warning,
+        );
+        messages(consistent_column_descriptions(
+            &models,
+            &rule,
+            canonical_names,
+            require_doc_blocks,
+            manifest,
+        ))
+    }
+
+    #[test]
+    fn test_consistent_column_descriptions() {
+        assert_eq!(
+            inconsistencies(&manifest(), &[], false),
+            vec!["Column 'customer_id' of orders is described differently than in stg_customers, suggested description: {{ doc('customer_id') }}"]
+        );
+    }
+
+    #[test]
+    fn test_consistent_column_descriptions_canonical_names() {
+        let invalid_names = vec![ColumnNamePattern::Literal("zipcode".to_string())];
+        let canonical_names = vec![CanonicalName {
+            canonical: "zip_code",
+            invalid_names: &invalid_names,
+            exceptions: &[],
+        }];
+        assert_eq!(
+            inconsistencies(&manifest(), &canonical_names, false)[1],
+            "Column 'zipcode' of customers is described differently than in stg_customers, suggested description: \"Postal code\""
+        );
+    }
+
+    #[test]
+    fn test_consistent_column_descriptions_doc_blocks() {
+        let mut manifest = manifest();
+        manifest.docs.clear();
+        assert_eq!(
+            inconsistencies(&manifest, &[], true),
+            vec![
+                "Column 'customer_id' of customers repeats the description of stg_customers, reuse a doc block instead",
+                "Column 'customer_id' of orders is described differently than in stg_customers, suggested description: \"Unique id of a customer.\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_consistent_column_descriptions_empty_doc_blocks() {
+        // The text equals a doc block, but dbt recorded that the column uses none
+        let column = |doc_blocks: &[&str]| {
+            json!({"customer_id": {
+                "name": "customer_id",
+                "description": "Unique id of a customer.",
+                "doc_blocks": doc_blocks,
+                "tags": []
+            }})
+        };
+        let manifest = ManifestBuilder::default()
+            .model(
+                "stg_customers",
+                json!({"columns": column(&["doc.p.customer_id"])}),
+            )
+            .model(
+                "customers",
+                json!({"depends_on": {"nodes": ["model.p.stg_customers"]}, "columns": column(&[])}),
+            )
+            .doc("customer_id", "Unique id of a customer.")
+            .build();
+        assert_eq!(
+            inconsistencies(&manifest, &[], true),
+            vec!["Column 'customer_id' of customers repeats the description of stg_customers, reuse a doc block instead"]
+        );
+    }
+}
@@ -0,0 +1,301 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::BuildHasher;
+
+use crate::{
+    cli::table::RuleResult,
+    core::{
+        config::project_rule::{CoverageGroupBy, ProjectRule},
+        manifest::Node,
+    },
+};
+use dbt_artifact_parser::manifest::nodes::Test;
+
+/// Number of covered items out of the total.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Coverage {
+    pub covered: usize,
+    pub total: usize,
+}
+
+impl Coverage {
+    pub fn add(&mut self, covered: bool) {
+        self.covered += usize::from(covered);
+        self.total += 1;
+    }
+
+    /// Share of covered items, 0 when there are no items.
+    pub fn percent(self) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        let to_f64 = |value: usize| f64::from(u32::try_from(value).unwrap_or(u32::MAX));
+        to_f64(self.covered) * 100.0 / to_f64(self.total)
+    }
+}
+
+/// The group a model is counted in, `None` for models that are not part of any group.
+fn group_of(model: &Node, group_by: Option<CoverageGroupBy>) -> Option<&str> {
+    match group_by {
+        None => Some(""),
+        Some(CoverageGroupBy::Directory) => {
+            let path = model.get_relative_path();
+            Some(
+                path.rsplit_once('/')
+                    .map_or(".", |(directory, _)| directory),
+            )
+        }
+        Some(CoverageGroupBy::Group) => model
+            .get_base()
+            .config
+            .as_ref()
+            .and_then(|config| config.group.as_deref()),
+    }
+}
+
+pub fn is_documented(description: Option<&String>) -> bool {
+    description.is_some_and(|description| !description.trim().is_empty())
+}
+
+/// One finding per group with a coverage below `min_percent`.
+fn below_minimum(
+    rule: &ProjectRule,
+    what: &str,
+    coverage: &BTreeMap<&str, Coverage>,
+    min_percent: f64,
+    group_by: Option<CoverageGroupBy>,
+) -> Vec<RuleResult> {
+    coverage
+        .iter()
+        .filter(|(_, coverage)| coverage.total > 0 && coverage.percent() < min_percent)
+        .map(|(group, coverage)| {
+            let (object_type, scope) = match group_by {
+                None => ("Project", String::new()),
+                Some(CoverageGroupBy::Directory) => {
+                    ("Directory", format!(" in directory '{group}'"))
+                }
+                Some(CoverageGroupBy::Group) => ("Group", format!(" in group '{group}'")),
+            };
+            RuleResult::new(
+                &rule.severity,
+                object_type,
+                rule.get_name(),
+                format!(
+                    "{what}{scope} is {:.1}% ({} of {}), below the minimum of {min_percent}%",
+                    coverage.percent(),
+                    coverage.covered,
+                    coverage.total,
+                ),
+                None,
+            )
+        })
+        .collect()
+}
+
+/// Report groups where the share of models, or of their columns, with a description is too low.
+pub fn documentation_coverage(
+    models: &[&Node],
+    rule: &ProjectRule,
+    min_percent: f64,
+    group_by: Option<CoverageGroupBy>,
+) -> Vec<RuleResult> {
+    let mut model_coverage: BTreeMap<&str, Coverage> = BTreeMap::new();
+    let mut column_coverage: BTreeMap<&str, Coverage> = BTreeMap::new();
+
+    for model in models {
+        let Some(group) = group_of(model, group_by) else {
+            continue;
+        };
+        model_coverage
+            .entry(group)
+            .or_default()
+            .add(is_documented(model.get_base().description.as_ref()));
+        for column in model
+            .get_base()
+            .columns
+            .iter()
+            .flat_map(|columns| columns.values())
+        {
+            column_coverage
+                .entry(group)
+                .or_default()
+                .add(is_documented(column.description.as_ref()));
+        }
+    }
+
+    let mut results = below_minimum(
+        rule,
+        "Documentation coverage of models",
+        &model_coverage,
+        min_percent,
+        group_by,
+    );
+    results.extend(below_minimum(
+        rule,
+        "Documentation coverage of columns",
+        &column_coverage,
+        min_percent,
+        group_by,
+    ));
+    results
+}
+
+/// Report groups where the share of models with at least one test is too low.
+/// `tests_by_parent` holds the tests of the manifest by the node they are attached to.
+pub fn test_coverage<S: BuildHasher>(
+    models: &[&Node],
+    rule: &ProjectRule,
+    min_percent: f64,
+    group_by: Option<CoverageGroupBy>,
+    tests_by_parent: &HashMap<&str, Vec<&Test>, S>,
+) -> Vec<RuleResult> {
+    let mut coverage: BTreeMap<&str, Coverage> = BTreeMap::new();
+    for model in models {
+        if let Some(group) = group_of(model, group_by) {
+            let tested = tests_by_parent
+                .get(model.get_unique_id().as_str())
+                .is_some_and(|tests| !tests.is_empty());
+            coverage.entry(group).or_default().add(tested);
+        }
+    }
+    below_minimum(
+        rule,
+        "Test coverage of models",
+        &coverage,
+        min_percent,
+        group_by,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::project_rule::ProjectSpecificRuleConfig;
+    use crate::core::config::severity::Severity;
+    use crate::core::manifest::Manifest;
+    use crate::core::rules::test_utils::{messages, ManifestBuilder};
+    use serde_json::json;
+
+    fn rule() -> ProjectRule {
+        ProjectRule::from_specific_rule(
+            ProjectSpecificRuleConfig::DocumentationCoverage {
+                min_percent: 50.0,
+                group_by: None,
+            },
+            Severity::Error,
+        )
+    }
+
+    fn manifest() -> Manifest {
+        ManifestBuilder::default()
+            .model(
+                "stg_orders",
+                json!({
+                    "original_file_path": "models/staging/stg_orders.sql", "description": "Orders",
+                    "config": {"group": "sales"},
+                    "columns": {
+                        "id": {"name": "id", "description": "Id", "tags": []},
+                        "status": {"name": "status", "description": "", "tags": []}
+                    }
+                }),
+            )
+            .model(
+                "stg_customers",
+                json!({
+                    "original_file_path": "models/staging/stg_customers.sql", "description": " ",
+                    "config": {"group": "sales"}
+                }),
+            )
+            .model(
+                "fct_orders",
+                json!({
+                    "original_file_path": "models/marts/fct_orders.sql",
+                    "columns": {"id": {"name": "id", "tags": []}}
+                }),
+            )
+            .node(
+                "test",
+                "unique_stg_orders_id",
+                json!({"attached_node": "model.p.stg_orders"}),
+            )
+            .build()
+    }
+
+    fn models(manifest: &Manifest) -> Vec<&Node> {
+        let mut models: Vec<&Node> = manifest
+            .nodes
+            .values()
+            .filter(|node| matches!(node, Node::Model(_)))
+            .collect();
+        models.sort_by_key(|node| node.get_unique_id());
+        models
+    }
+
+    #[test]
+    fn test_documentation_coverage() {
+        let manifest = manifest();
+        let models = models(&manifest);
+
+        assert_eq!(
+            messages(documentation_coverage(&models, &rule(), 50.0, None)),
+            vec![
+                "Documentation coverage of models is 33.3% (1 of 3), below the minimum of 50%",
+                "Documentation coverage of columns is 33.3% (1 of 3), below the minimum of 50%",
+            ]
+        );
+        assert!(documentation_coverage(&models, &rule(), 33.0, None).is_empty());
+    }
+
+    #[test]
+    fn test_documentation_coverage_grouped() {
+        let manifest = manifest();
+        let models = models(&manifest);
+
+        let results =
+            documentation_coverage(&models, &rule(), 50.0, Some(CoverageGroupBy::Directory));
+        assert_eq!(results[0].object_type, "Directory");
+        assert_eq!(
+            messages(results),
+            vec![
+                "Documentation coverage of models in directory 'models/marts' is 0.0% (0 of 1), below the minimum of 50%",
+                "Documentation coverage of columns in directory 'models/marts' is 0.0% (0 of 1), below the minimum of 50%",
+            ]
+        );
+
+        // Models outside of a group are not counted
+        assert_eq!(
+            messages(documentation_coverage(
+                &models,
+                &rule(),
+                75.0,
+                Some(CoverageGroupBy::Group)
+            )),
+            vec![
+                "Documentation coverage of models in group 'sales' is 50.0% (1 of 2), below the minimum of 75%",
+                "Documentation coverage of columns in group 'sales' is 50.0% (1 of 2), below the minimum of 75%",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_test_coverage() {
+        let manifest = manifest();
+        let models = models(&manifest);
+
+        let tests_by_parent = manifest.get_tests_by_attached_node();
+
+        let results = test_coverage(&models, &rule(), 50.0, None, &tests_by_parent);
+        assert_eq!(results[0].object_type, "Project");
+        assert_eq!(
+            messages(results),
+            vec!["Test coverage of models is 33.3% (1 of 3), below the minimum of 50%"]
+        );
+        assert!(test_coverage(
+            &models,
+            &rule(),
+            50.0,
+            Some(CoverageGroupBy::Group),
+            &tests_by_parent
+        )
+        .is_empty());
+    }
+}
@@ -0,0 +1,346 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    cli::table::RuleResult,
+    core::{
+        config::{includes_excludes::should_run_test, project_rule::ProjectRule},
+        manifest::{Exposure, Macro, Manifest, Node, Source},
+    },
+};
+
+// Longer descriptions are cut off in the message
+const EXCERPT_LENGTH: usize = 50;
+
+/// Description with collapsed whitespace, `None` when there is nothing to compare.
+pub fn normalize_description(description: Option<&String>) -> Option<String> {
+    let words: Vec<&str> = description?.split_whitespace().collect();
+    (!words.is_empty()).then(|| words.join(" "))
+}
+
+pub fn excerpt(description: &str) -> String {
+    if description.chars().count() <= EXCERPT_LENGTH {
+        return description.to_string();
+    }
+    let cut: String = description.chars().take(EXCERPT_LENGTH).collect();
+    format!("{}...", cut.trim_end())
+}
+
+// Object type and name of a described object
+type Object<'a> = (&'a str, &'a str);
+
+/// Objects sharing a description, grouped by the case-insensitive description.
+/// Groups where all names are the same (case-insensitive) are skipped, the same text is expected there.
+fn duplicates<'a>(
+    described: impl Iterator<Item = (&'a str, &'a str, Option<&'a String>)>,
+) -> Vec<(String, Vec<Object<'a>>)> {
+    // Sorted, so the text shown for a group doesn't depend on the order of the manifest
+    let mut described: Vec<_> = described.collect();
+    described.sort_unstable();
+
+    let mut groups: BTreeMap<String, (String, Vec<Object>)> = BTreeMap::new();
+    for (object_type, name, description) in described {
+        if let Some(description) = normalize_description(description) {
+            groups
+                .entry(description.to_lowercase())
+                .or_insert_with(|| (description, Vec::new()))
+                .1
+                .push((object_type, name));
+        }
+    }
+    groups
+        .into_values()
+        .filter_map(|(description, objects)| {
+            let mut distinct: Vec<String> = objects
+                .iter()
+                .map(|(_, name)| name.to_lowercase())
+                .collect();
+            distinct.sort_unstable();
+            distinct.dedup();
+            (distinct.len() > 1).then_some((description, objects))
+        })
+        .collect()
+}
+
+fn quoted(names: &[&str]) -> String {
+    names
+        .iter()
+        .map(|name| format!("'{name}'"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// `Models 'a', 'b'` when all objects are of one type, `Model 'a', Source 'b'` otherwise
+fn listed(objects: &[Object]) -> String {
+    let (object_type, _) = objects[0];
+    if objects.iter().all(|(other, _)| *other == object_type) {
+        let names: Vec<&str> = objects.iter().map(|(_, name)| *name).collect();
+        return format!("{object_type}s {}", quoted(&names));
+    }
+    objects
+        .iter()
+        .map(|(object_type, name)| format!("{object_type} '{name}'"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Descriptions of the seeds, snapshots, sources, macros and exposures of the project.
+/// Models are compared too, they are passed in separately.
+fn other_objects<'a>(
+    manifest: &'a Manifest,
+    rule: &ProjectRule,
+) -> Vec<(&'a str, &'a str, Option<&'a String>)> {
+    let in_project = |package_name: &String| {
+        manifest
+            .metadata
+            .project_name
+            .as_ref()
+            .is_none_or(|project| project == package_name)
+    };
+    let (includes, excludes) = (rule.includes.as_ref(), rule.excludes.as_ref());
+
+    let mut objects: Vec<(&str, &str, Option<&String>)> = manifest
+        .nodes
+        .values()
+        .filter(|node| matches!(node, Node::Seed(_) | Node::Snapshot(_)))
+        .filter(|node| in_project(node.get_package_name()))
+        .filter(|node| should_run_test(*node, includes, excludes))
+        .map(|node| {
+            (
+                node.get_object_type(),
+                node.get_name().as_str(),
+                node.get_base().description.as_ref(),
+            )
+        })
+        .collect();
+    objects.extend(
+        manifest
+            .sources
+            .values()
+            .filter(|source| in_project(source.get_package_name()))
+            .filter(|source| should_run_test(*source, includes, excludes))
+            .map(|source| {
+                (
+                    Source::get_object_type(),
+                    source.get_name().as_str(),
+                    source.description.as_ref(),
+                )
+            }),
+    );
+    objects.extend(
+        manifest
+            .macros
+            .values()
+            .filter(|macro_obj| in_project(macro_obj.get_package_name()))
+            .filter(|macro_obj| should_run_test(*macro_obj, includes, excludes))
+            .map(|macro_obj| {
+                (
+                    Macro::get_object_type(),
+                    macro_obj.get_name().as_str(),
+                    macro_obj.description.as_ref(),
+                )
+            }),
+    );
+    objects.extend(
+        manifest
+            .exposures
+            .values()
+            .filter(|exposure| in_project(exposure.get_package_name()))
+            .filter(|exposure| should_run_test(*exposure, includes, excludes))
+            .map(|exposure| {
+                (
+                    Exposure::get_object_type(),
+                    exposure.get_name().as_str(),
+                    exposure.description.as_ref(),
+                )
+            }),
+    );
+    objects
+}
+
+/// Report groups of models, seeds, snapshots, sources, macros and exposures with the same
+/// description, and groups of columns within a model with the same description.
+/// Each group is reported once.
+pub fn duplicate_descriptions(
+    models: &[&Node],
+    rule: &ProjectRule,
+    manifest: &Manifest,
+) -> Vec<RuleResult> {
+    let described = models
+        .iter()
+        .map(|model| {
+            (
+                model.get_object_type(),
+                model.get_name().as_str(),
+                model.get_base().description.as_ref(),
+            )
+        })
+        .chain(other_objects(manifest, rule));
+    let mut results: Vec<RuleResult> = duplicates(described)
+        .into_iter()
+        .map(|(description, objects)| {
+            RuleResult::new(
+                &rule.severity,
+                "Project",
+                rule.get_name(),
+                format!(
+                    "{} have the same description \"{}\"",
+                    listed(&objects),
+                    excerpt(&description)
+                ),
+                None,
+            )
+        })
+        .collect();
+
+    for model in models {
+        let columns = model
+            .get_base()
+            .columns
+            .iter()
+            .flat_map(|columns| columns.values())
+            .map(|column| ("Column", column.name.as_str(), column.description.as_ref()));
+        results.extend(
+            duplicates(columns)
+                .into_iter()
+                .map(|(description, columns)| {
+                    let names: Vec<&str> = columns.iter().map(|(_, name)| *name).collect();
+                    RuleResult::new(
+                        &rule.severity,
+                        model.get_object_type(),
+                        rule.get_name(),
+                        format!(
+                            "Columns {} of {} have the same description \"{}\"",
+                            quoted(&names),
+                            model.get_name(),
+                            excerpt(&description)
+                        ),
+                        Some(model.get_relative_path().clone()),
+                    )
+                }),
+        );
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::project_rule::ProjectSpecificRuleConfig;
+    use crate::core::config::severity::Severity;
+    use crate::core::manifest::Manifest;
+    use crate::core::rules::test_utils::{messages, ManifestBuilder};
+    use serde_json::json;
+
+    fn manifest() -> Manifest {
+        ManifestBuilder::default()
+            .model(
+                "customers",
+                json!({
+                    "description": "One row per customer.",
+                    "columns": {
+                        "customer_id": {"name": "customer_id", "description": "Unique identifier", "tags": []},
+                        "first_order_at": {"name": "first_order_at", "description": "Timestamp of the order", "tags": []},
+                        "last_order_at": {"name": "last_order_at", "description": "timestamp  of the ORDER", "tags": []}
+                    }
+                }),
+            )
+            .model(
+                "orders",
+                json!({
+                    "description": "One row per  customer.\n",
+                    "columns": {
+                        "customer_id": {"name": "customer_id", "description": "Unique identifier", "tags": []}
+                    }
+                }),
+            )
+            .model(
+                "payments",
+                json!({
+                    "description": "",
+                    "columns": {
+                        "amount": {"name": "amount", "tags": []},
+                        "fee": {"name": "fee", "tags": []}
+                    }
+                }),
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_duplicate_descriptions() {
+        let manifest = manifest();
+        let mut models: Vec<&Node> = manifest.nodes.values().collect();
+        models.sort_by_key(|node| node.get_unique_id());
+        let rule = ProjectRule::from_specific_rule(
+            ProjectSpecificRuleConfig::DuplicateDescriptions {},
+            Severity::Warning,
+        );
+
+        let results = duplicate_descriptions(&models, &rule, &manifest);
+        // Missing descriptions and columns sharing a name across models are not duplicates
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].object_type, "Project");
+        assert_eq!(
+            results[0].message,
+            "Models 'customers', 'orders' have the same description \"One row per customer.\""
+        );
+        assert_eq!(results[1].object_type, "Model");
+        assert_eq!(
+            results[1].message,
+            "Columns 'first_order_at', 'last_order_at' of customers have the same description \"Timestamp of the order\""
+        );
+    }
+
+    #[test]
+    fn test_duplicate_descriptions_across_object_types() {
+        let manifest = ManifestBuilder::default()
+            .model("customers", json!({"description": "One row per customer"}))
+            .node(
+                "seed",
+                "customer_types",
+                json!({"description": "Cents to dollars"}),
+            )
+            .source(
+                "raw",
+                "customers",
+                json!({"description": "one row per customer"}),
+            )
+            .source(
+                "raw",
+                "raw_customers",
+                json!({"description": "One row per customer"}),
+            )
+            .macro_(
+                "cents_to_dollars",
+                json!({"description": "Cents to dollars"}),
+            )
+            .macro_("cents_to_euros", json!({"description": "Cents to dollars"}))
+            .build();
+        let models: Vec<&Node> = manifest
+            .nodes
+            .values()
+            .filter(|node| matches!(node, Node::Model(_)))
+            .collect();
+        let rule = ProjectRule::from_specific_rule(
+            ProjectSpecificRuleConfig::DuplicateDescriptions {},
+            Severity::Warning,
+        );
+
+        assert_eq!(
+            messages(duplicate_descriptions(&models, &rule, &manifest)),
+            vec![
+                "Macro 'cents_to_dollars', Macro 'cents_to_euros', Seed 'customer_types' have the same description \"Cents to dollars\"",
+                "Model 'customers', Source 'customers', Source 'raw_customers' have the same description \"One row per customer\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_excerpt() {
+        assert_eq!(excerpt("Short"), "Short");
+        assert_eq!(
+            excerpt(&"word ".repeat(20)),
+            "word word word word word word word word word word..."
+        );
+    }
+}
@@ -0,0 +1,10 @@
+pub mod apply_project_rules;
+pub mod consistent_column_descriptions;
+pub mod coverage;
+pub mod duplicate_descriptions;
+pub mod unused_doc_blocks;
+
+pub use consistent_column_descriptions::consistent_column_descriptions;
+pub use coverage::{documentation_coverage, test_coverage};
+pub use duplicate_descriptions::duplicate_descriptions;
+pub use unused_doc_blocks::unused_doc_blocks;
@@ -0,0 +1,300 @@
+use std::collections::HashSet;
+
+use regex::Regex;
+
+use crate::{
+    cli::table::RuleResult,
+    core::{
+        config::{includes_excludes::should_run_test, project_rule::ProjectRule},
+        manifest::{DocBlock, Manifest},
+        rules::project::duplicate_descriptions::normalize_description,
+    },
+};
+
+// dbt's own doc block for the front page of the docs site
+const OVERVIEW: &str = "__overview__";
+
+/// Unique ids of all doc blocks used in descriptions of nodes, sources and their columns.
+fn referenced_doc_blocks(manifest: &Manifest) -> HashSet<&str> {
+    let nodes = manifest.nodes.values().map(|node| {
+        let base = node.get_base();
+        (&base.doc_blocks, &base.columns)
+    });
+    let sources = manifest
+        .sources
+        .values()
+        .map(|source| (&source.doc_blocks, &source.columns));
+
+    let mut referenced = HashSet::new();
+    for (doc_blocks, columns) in nodes.chain(sources) {
+        referenced.extend(doc_blocks.iter().flatten().map(String::as_str));
+        for column in columns.iter().flat_map(|columns| columns.values()) {
+            referenced.extend(column.doc_blocks.iter().flatten().map(String::as_str));
+        }
+    }
+    referenced
+}
+
+/// Unique ids of the doc blocks that `block` includes with `{{ doc('name') }}`.
+/// Without a package the block of the same package is meant, or else any block of that name.
+fn doc_calls(block: &DocBlock, doc_call: &Regex, manifest: &Manifest) -> Vec<String> {
+    let mut called = Vec::new();
+    for call in doc_call.captures_iter(&block.block_contents) {
+        if let Some(name) = call.get(2) {
+            called.push(format!("doc.{}.{}", &call[1], name.as_str()));
+            continue;
+        }
+        let same_package = format!("doc.{}.{}", block.package_name, &call[1]);
+        if manifest.docs.contains_key(&same_package) {
+            called.push(same_package);
+        } else {
+            called.extend(
+                manifest
+                    .docs
+                    .values()
+                    .filter(|other| other.name == call[1])
+                    .map(|other| other.unique_id.clone()),
+            );
+        }
+    }
+    called
+}
+
+/// Normalized, lower-cased descriptions of objects the manifest has no `doc_blocks` for.
+/// Manifests before dbt 1.10 have no `doc_blocks` at all, only the rendered descriptions.
+fn descriptions_without_doc_blocks(manifest: &Manifest) -> HashSet<String> {
+    let mut descriptions: Vec<Option<&String>> = Vec::new();
+    let nodes = manifest.nodes.values().map(|node| {
+        let base = node.get_base();
+        (&base.description, &base.doc_blocks, &base.columns)
+    });
+    let sources = manifest
+        .sources
+        .values()
+        .map(|source| (&source.description, &source.doc_blocks, &source.columns));
+    for (description, doc_blocks, columns) in nodes.chain(sources) {
+        if doc_blocks.is_none() {
+            descriptions.push(description.as_ref());
+        }
+        descriptions.extend(
+            columns
+                .iter()
+                .flat_map(|columns| columns.values())
+                .filter(|column| column.doc_blocks.is_none())
+                .map(|column| column.description.as_ref()),
+        );
+    }
+
+    descriptions.extend(manifest.macros.values().map(|m| m.description.as_ref()));
+    descriptions.extend(manifest.exposures.values().map(|e| e.description.as_ref()));
+    descriptions.extend(
+        manifest
+            .semantic_models
+            .values()
+            .map(|sm| sm.description.as_ref()),
+    );
+    descriptions.extend(manifest.metrics.values().map(|m| m.description.as_ref()));
+    descriptions.extend(
+        manifest
+            .saved_queries
+            .values()
+            .map(|sq| sq.description.as_ref()),
+    );
+
+    descriptions
+        .into_iter()
+        .filter_map(normalize_description)
+        .map(|description| description.to_lowercase())
+        .collect()
+}
+
+/// Unique ids of the doc blocks that descriptions use, and of the blocks those include, transitively.
+/// A block that is only included by unused blocks is unused as well.
+fn used_doc_blocks(manifest: &Manifest) -> HashSet<String> {
+    let doc_call = Regex::new(r#"\bdoc\(\s*['"]([^'"]+)['"]\s*(?:,\s*['"]([^'"]+)['"]\s*)?\)"#)
+        .expect("valid regex");
+    let other_descriptions = descriptions_without_doc_blocks(manifest);
+
+    let mut pending: Vec<String> = referenced_doc_blocks(manifest)
+        .into_iter()
+        .map(ToString::to_string)
+        .collect();
+    pending.extend(
+        manifest
+            .docs
+            .values()
+            .filter(|block| {
+                block.name == OVERVIEW
+                    || normalize_description(Some(&block.block_contents)).is_some_and(|contents| {
+                        other_descriptions.contains(&contents.to_lowercase())
+                    })
+            })
+            .map(|block| block.unique_id.clone()),
+    );
+
+    let mut used = HashSet::new();
+    while let Some(unique_id) = pending.pop() {
+        if let Some(block) = manifest.docs.get(&unique_id) {
+            if !used.contains(&unique_id) {
+                pending.extend(doc_calls(block, &doc_call, manifest));
+            }
+        }
+        used.insert(unique_id);
+    }
+    used
+}
+
+/// Report doc blocks of the project that no description uses.
+pub fn unused_doc_blocks(manifest: &Manifest, rule: &ProjectRule) -> Vec<RuleResult> {
+    let used = used_doc_blocks(manifest);
+
+    let mut unused: Vec<&DocBlock> = manifest
+        .docs
+        .values()
+        .filter(|block| block.name != OVERVIEW)
+        .filter(|block| {
+            manifest
+                .metadata
+                .project_name
+                .as_ref()
+                .is_none_or(|project| project == block.get_package_name())
+        })
+        .filter(|block| should_run_test(*block, rule.includes.as_ref(), rule.excludes.as_ref()))
+        .filter(|block| !used.contains(&block.unique_id))
+        .collect();
+    unused.sort_by_key(|block| &block.unique_id);
+
+    unused
+        .into_iter()
+        .map(|block| {
+            RuleResult::new(
+                &rule.severity,
+                "Doc block",
+                rule.get_name(),
+                format!("Doc block '{}' is never used", block.get_name()),
+                Some(block.get_relative_path().clone()),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::project_rule::ProjectSpecificRuleConfig;
+    use crate::core::config::severity::Severity;
+    use crate::core::rules::test_utils::{messages, ManifestBuilder};
+    use serde_json::json;
+
+    fn rule() -> ProjectRule {
+        ProjectRule::from_specific_rule(
+            ProjectSpecificRuleConfig::UnusedDocBlocks {},
+            Severity::Warning,
+        )
+    }
+
+    #[test]
+    fn test_unused_doc_blocks() {
+        let manifest: Manifest = serde_json::from_str(
+            r#"{
+  "metadata": {"dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json", "project_name": "p"},
+  "nodes": {
+    "model.p.orders": {
+      "resource_type": "model", "name": "orders", "unique_id": "model.p.orders",
+      "original_file_path": "models/orders.sql", "doc_blocks": ["doc.p.orders"],
+      "columns": {
+        "status": {"name": "status", "tags": [], "doc_blocks": ["doc.p.order_status"]}
+      }
+    }
+  },
+  "macros": {
+    "macro.p.cents_to_dollars": {
+      "name": "cents_to_dollars", "package_name": "p", "original_file_path": "macros/cents.sql",
+      "macro_sql": "", "description": "Converts cents to dollars.\n"
+    }
+  },
+  "docs": {
+    "doc.p.orders": {"name": "orders", "resource_type": "doc", "package_name": "p", "path": "docs.md",
+      "original_file_path": "models/docs.md", "unique_id": "doc.p.orders", "block_contents": "Orders"},
+    "doc.p.order_status": {"name": "order_status", "resource_type": "doc", "package_name": "p", "path": "docs.md",
+      "original_file_path": "models/docs.md", "unique_id": "doc.p.order_status", "block_contents": "Status"},
+    "doc.p.cents": {"name": "cents", "resource_type": "doc", "package_name": "p", "path": "docs.md",
+      "original_file_path": "macros/docs.md", "unique_id": "doc.p.cents", "block_contents": "Converts cents to dollars."},
+    "doc.p.customer_id": {"name": "customer_id", "resource_type": "doc", "package_name": "p", "path": "docs.md",
+      "original_file_path": "models/docs.md", "unique_id": "doc.p.customer_id", "block_contents": "Id of a customer"},
+    "doc.p.__overview__": {"name": "__overview__", "resource_type": "doc", "package_name": "p", "path": "overview.md",
+      "original_file_path": "models/overview.md", "unique_id": "doc.p.__overview__", "block_contents": "Welcome"},
+    "doc.dbt_utils.unused": {"name": "unused", "resource_type": "doc", "package_name": "dbt_utils", "path": "docs.md",
+      "original_file_path": "docs.md", "unique_id": "doc.dbt_utils.unused", "block_contents": "Unused"}
+  }
+}"#,
+        )
+        .unwrap();
+        let results = unused_doc_blocks(&manifest, &rule());
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].object_type, "Doc block");
+        assert_eq!(results[0].message, "Doc block 'customer_id' is never used");
+        assert_eq!(results[0].relative_path, Some("models/docs.md".to_string()));
+    }
+
+    #[test]
+    fn test_unused_doc_blocks_without_doc_blocks_field() {
+        // Manifests before dbt 1.10 only have the rendered descriptions
+        let manifest = ManifestBuilder::default()
+            .model(
+                "orders",
+                json!({
+                    "description": "Orders\n",
+                    "columns": {"status": {"name": "status", "description": "Status", "tags": []}}
+                }),
+            )
+            .with(
+                "semantic_models",
+                json!({"semantic_model.p.orders": {
+                    "name": "orders", "package_name": "p", "original_file_path": "models/orders.yml",
+                    "description": "Orders by day", "depends_on": {}
+                }}),
+            )
+            .with(
+                "metrics",
+                json!({"metric.p.revenue": {"name": "revenue", "description": "Revenue"}}),
+            )
+            .with(
+                "saved_queries",
+                json!({"saved_query.p.daily": {"name": "daily", "description": "Daily orders"}}),
+            )
+            .doc("orders", "Orders")
+            .doc("order_status", "Status")
+            .doc("orders_by_day", "Orders by day")
+            .doc("revenue", "Revenue")
+            .doc("daily", "Daily orders")
+            .doc("unused", "Unused")
+            .build();
+
+        assert_eq!(
+            messages(unused_doc_blocks(&manifest, &rule())),
+            vec!["Doc block 'unused' is never used"]
+        );
+    }
+
+    #[test]
+    fn test_unused_doc_blocks_included_by_other_doc_blocks() {
+        // Blocks included by a used block are used, blocks only included by unused blocks are not
+        let manifest = ManifestBuilder::default()
+            .model("revenue", json!({"doc_blocks": ["doc.p.net"]}))
+            .doc("net", "Revenue {{ doc('net_of_refunds') }}")
+            .doc("net_of_refunds", "net of {{ doc(\"p\", 'refunds') }}")
+            .doc("refunds", "refunds")
+            .doc("gross", "Revenue {{ doc('gross_of_refunds') }}")
+            .doc("gross_of_refunds", "gross of refunds")
+            .build();
+
+        assert_eq!(
+            messages(unused_doc_blocks(&manifest, &rule())),
+            vec![
+                "Doc block 'gross' is never used",
+                "Doc block 'gross_of_refunds' is never used",
+            ]
+        );
+    }
+}
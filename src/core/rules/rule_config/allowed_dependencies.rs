@@ -0,0 +1,249 @@
+use std::{collections::HashMap, hash::BuildHasher};
+
+use crate::{
+    cli::table::RuleResult,
+    core::{
+        config::{check_config_options::Layer, manifest_rule::ManifestRule},
+        manifest::Manifest,
+    },
+};
+
+// Objects that can be assigned to a layer
+pub trait Layered {
+    fn get_unique_id(&self) -> &str;
+    fn get_object_type(&self) -> &str;
+    fn get_object_string(&self) -> &str;
+    fn get_path(&self) -> &str;
+    fn get_tags(&self) -> &[String];
+    fn get_relative_path(&self) -> Option<&String> {
+        None
+    }
+    // Unique ids of the objects this object selects from
+    fn get_parents<'a>(&'a self, _manifest: &'a Manifest) -> Vec<&'a str> {
+        Vec::new()
+    }
+}
+
+/// Name of an object in the DAG from its unique id.
+/// `model.package.name` -> `name`, `source.package.source_name.name` -> `source_name.name`
+pub fn short_name(unique_id: &str) -> &str {
+    unique_id.splitn(3, '.').nth(2).unwrap_or(unique_id)
+}
+
+/// Unique ids of the direct parents of any object in the DAG
+pub fn parents_of<'a>(unique_id: &str, manifest: &'a Manifest) -> Vec<&'a str> {
+    manifest.get_node(unique_id).map_or_else(
+        || {
+            manifest
+                .parent_map
+                .get(unique_id)
+                .into_iter()
+                .flatten()
+                .map(String::as_str)
+                .collect()
+        },
+        |node| node.get_parents(manifest),
+    )
+}
+
+/// Whether the object belongs to the layer
+pub fn in_layer<T: Layered + ?Sized>(object: &T, layer: &Layer) -> bool {
+    let resource_type = object.get_unique_id().split('.').next().unwrap_or_default();
+    layer.matches(
+        resource_type,
+        object.get_object_string(),
+        object.get_path(),
+        object.get_tags(),
+    )
+}
+
+/// The first layer the object belongs to
+pub fn layer_of<'a, T: Layered + ?Sized>(object: &T, layers: &'a [Layer]) -> Option<&'a Layer> {
+    layers.iter().find(|layer| in_layer(object, layer))
+}
+
+/// Report every dependency of the object on a layer its own layer may not depend on.
+/// Objects outside of the layers, and layers without an `allowed` entry, are not checked.
+pub fn allowed_dependencies<T: Layered, S: BuildHasher>(
+    object: &T,
+    rule: &ManifestRule,
+    layers: &[Layer],
+    allowed: &HashMap<String, Vec<String>, S>,
+    manifest: &Manifest,
+) -> Vec<RuleResult> {
+    let Some(layer) = layer_of(object, layers) else {
+        return Vec::new();
+    };
+    let Some(allowed_layers) = allowed.get(&layer.name) else {
+        return Vec::new();
+    };
+
+    let mut parents = object.get_parents(manifest);
+    parents.sort_unstable();
+    parents.dedup();
+
+    parents
+        .into_iter()
+        .filter_map(|parent_id| {
+            let parent: &dyn Layered = match manifest.get_node(parent_id) {
+                Some(node) => node,
+                None => manifest.get_source(parent_id)?,
+            };
+            let parent_layer = layer_of(parent, layers)?;
+            if allowed_layers.contains(&parent_layer.name) {
+                return None;
+            }
+
+            let allowed_message = if allowed_layers.is_empty() {
+                format!("'{}' may not depend on any layer", layer.name)
+            } else {
+                format!(
+                    "'{}' may only depend on: {}",
+                    layer.name,
+                    allowed_layers.join(", ")
+                )
+            };
+            Some(RuleResult::new(
+                &rule.severity,
+                object.get_object_type(),
+                rule.get_name(),
+                format!(
+                    "{} '{}' ({}) depends on {} '{}' ({}), but {allowed_message}",
+                    object.get_object_type(),
+                    object.get_object_string(),
+                    layer.name,
+                    parent.get_object_type(),
+                    parent.get_object_string(),
+                    parent_layer.name,
+                ),
+                object.get_relative_path().cloned(),
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::manifest_rule::ManifestSpecificRuleConfig;
+    use crate::core::config::severity::Severity;
+    use crate::core::rules::test_utils::ManifestBuilder;
+    use dbt_artifact_parser::manifest::Manifest;
+    use serde_json::json;
+
+    fn layer(name: &str, prefixes: &[&str], resource_types: &[&str]) -> Layer {
+        Layer {
+            name: name.to_string(),
+            paths: vec![],
+            prefixes: prefixes.iter().map(ToString::to_string).collect(),
+            tags: vec![],
+            resource_types: resource_types.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    fn layers() -> Vec<Layer> {
+        vec![
+            layer("sources", &[], &["source"]),
+            layer("staging", &["stg_"], &[]),
+            layer("marts", &["fct_", "dim_"], &[]),
+        ]
+    }
+
+    fn allowed() -> HashMap<String, Vec<String>> {
+        HashMap::from([
+            ("staging".to_string(), vec!["sources".to_string()]),
+            ("marts".to_string(), vec!["staging".to_string()]),
+        ])
+    }
+
+    fn rule(layers: Vec<Layer>, allowed: HashMap<String, Vec<String>>) -> ManifestRule {
+        ManifestRule::from_specific_rule(
+            ManifestSpecificRuleConfig::AllowedDependencies { layers, allowed },
+            Severity::Error,
+        )
+    }
+
+    fn manifest() -> Manifest {
+        ManifestBuilder::default()
+            .model(
+                "stg_orders",
+                json!({
+                    "original_file_path": "models/staging/stg_orders.sql",
+                    "depends_on": {"nodes": ["source.p.raw.orders"]}
+                }),
+            )
+            .model(
+                "fct_orders",
+                json!({
+                    "original_file_path": "models/marts/fct_orders.sql",
+                    "depends_on": {"nodes": ["model.p.stg_orders", "source.p.raw.orders"]}
+                }),
+            )
+            .source(
+                "raw",
+                "orders",
+                json!({"original_file_path": "models/staging/_sources.yml"}),
+            )
+            .with(
+                "parent_map",
+                json!({"model.p.fct_orders": ["model.p.stg_orders", "source.p.raw.orders"]}),
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_allowed_dependencies_reports_illegal_edges() {
+        let manifest = manifest();
+        let rule = rule(layers(), allowed());
+        let (layers, allowed) = (layers(), allowed());
+
+        let staging = manifest.get_node("model.p.stg_orders").unwrap();
+        assert!(allowed_dependencies(staging, &rule, &layers, &allowed, &manifest).is_empty());
+
+        let marts = manifest.get_node("model.p.fct_orders").unwrap();
+        let results = allowed_dependencies(marts, &rule, &layers, &allowed, &manifest);
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].message,
+            "Model 'fct_orders' (marts) depends on Source 'orders' (sources), but 'marts' may only depend on: staging"
+        );
+    }
+
+    #[test]
+    fn test_allowed_dependencies_skips_unconstrained_layers() {
+        let manifest = manifest();
+        let rule = rule(layers(), HashMap::new());
+        let layers = layers();
+        let allowed = HashMap::from([("staging".to_string(), vec![])]);
+
+        // `marts` has no `allowed` entry
+        let marts = manifest.get_node("model.p.fct_orders").unwrap();
+        assert!(allowed_dependencies(marts, &rule, &layers, &allowed, &manifest).is_empty());
+
+        let staging = manifest.get_node("model.p.stg_orders").unwrap();
+        let results = allowed_dependencies(staging, &rule, &layers, &allowed, &manifest);
+        assert_eq!(results.len(), 1);
+        assert!(results[0]
+            .message
+            .ends_with("but 'staging' may not depend on any layer"));
+    }
+
+    #[test]
+    fn test_layer_of_uses_first_matching_layer() {
+        let manifest = manifest();
+        let layers = vec![
+            Layer {
+                name: "staging".to_string(),
+                paths: vec!["models/staging/*".to_string()],
+                prefixes: vec![],
+                tags: vec![],
+                resource_types: vec![],
+            },
+            layer("everything", &[""], &[]),
+        ];
+        let staging = manifest.get_node("model.p.stg_orders").unwrap();
+        let marts = manifest.get_node("model.p.fct_orders").unwrap();
+        assert_eq!(layer_of(staging, &layers).unwrap().name, "staging");
+        assert_eq!(layer_of(marts, &layers).unwrap().name, "everything");
+    }
+}
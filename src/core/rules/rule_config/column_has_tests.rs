@@ -0,0 +1,174 @@
+use crate::{
+    cli::table::RuleResult,
+    core::{
+        config::{check_config_options::ColumnSelector, manifest_rule::ManifestRule},
+        manifest::{Manifest, Node},
+        properties_file::{Property, PropertyRef},
+        rules::rule_config::{has_primary_key_test::tested_columns, has_unique_test::TestAble},
+    },
+};
+
+// Check that every selected column has all required tests
+pub fn column_has_tests(
+    node: &Node,
+    rule: &ManifestRule,
+    manifest: &Manifest,
+    selector: &ColumnSelector,
+    required_tests: &[String],
+) -> Vec<RuleResult> {
+    let tests = node.get_tests(manifest);
+
+    let mut columns: Vec<_> = node
+        .get_base()
+        .columns
+        .iter()
+        .flat_map(|columns| columns.values())
+        .filter(|column| selector.matches(column))
+        .collect();
+    columns.sort_by(|a, b| a.name.cmp(&b.name));
+
+    columns
+        .into_iter()
+        .filter_map(|column| {
+            let name = column.name.to_lowercase();
+            let missing: Vec<&str> = required_tests
+                .iter()
+                .filter(|required| {
+                    !tests.iter().any(|test| {
+                        test.matches_name(required) && tested_columns(test).contains(&name)
+                    })
+                })
+                .map(String::as_str)
+                .collect();
+            if missing.is_empty() {
+                return None;
+            }
+            Some(
+                RuleResult::new(
+                    &rule.severity,
+                    node.get_object_type(),
+                    rule.get_name(),
+                    format!(
+                        "Column '{}' of {} is missing required tests: {}",
+                        column.name,
+                        node.get_name(),
+                        missing.join(", ")
+                    ),
+                    Some(node.get_relative_path().clone()),
+                )
+                .with_property(PropertyRef::of_node(
+                    node,
+                    Property::Column(column.name.clone()),
+                )),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::{
+        check_config_options::ColumnNamePattern, manifest_rule::ManifestSpecificRuleConfig,
+        severity::Severity,
+    };
+    use crate::core::rules::test_utils::{messages, ManifestBuilder};
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    fn manifest() -> Manifest {
+        ManifestBuilder::default()
+            .model(
+                "customers",
+                json!({
+                    "columns": {
+                        "order_status": {"name": "order_status", "data_type": "varchar(20)", "tags": []},
+                        "payment_status": {"name": "payment_status", "data_type": "VARCHAR", "tags": ["finance"]},
+                        "email": {"name": "email", "data_type": "varchar", "tags": [], "meta": {"pii": true}},
+                        "phone": {"name": "phone", "tags": [], "meta": {"pii": false}}
+                    }
+                }),
+            )
+            .node(
+                "test",
+                "accepted_values_customers_order_status",
+                json!({
+                    "attached_node": "model.p.customers", "column_name": "order_status",
+                    "test_metadata": {"name": "accepted_values", "kwargs": {"values": ["open", "closed"]}}
+                }),
+            )
+            .node(
+                "test",
+                "not_null_customers_email",
+                json!({
+                    "attached_node": "model.p.customers", "column_name": "email",
+                    "test_metadata": {"name": "not_null"}
+                }),
+            )
+            .build()
+    }
+
+    fn missing_tests(selector: &ColumnSelector, required_tests: &[&str]) -> Vec<String> {
+        let manifest = manifest();
+        let required_tests: Vec<String> = required_tests.iter().map(ToString::to_string).collect();
+        let rule = ManifestRule::from_specific_rule(
+            ManifestSpecificRuleConfig::ColumnHasTests {
+                selector: ColumnSelector::default(),
+                required_tests: required_tests.clone(),
+            },
+            Severity::Error,
+        );
+        messages(column_has_tests(
+            &manifest.nodes["model.p.customers"],
+            &rule,
+            &manifest,
+            selector,
+            &required_tests,
+        ))
+    }
+
+    #[test]
+    fn test_column_has_tests_by_name() {
+        let selector = ColumnSelector {
+            columns: vec![ColumnNamePattern::Regex(
+                regex::Regex::new(".*_status$").unwrap(),
+            )],
+            ..ColumnSelector::default()
+        };
+        assert_eq!(
+            missing_tests(&selector, &["accepted_values"]),
+            vec!["Column 'payment_status' of customers is missing required tests: accepted_values"]
+        );
+    }
+
+    #[test]
+    fn test_column_has_tests_by_meta() {
+        let selector = ColumnSelector {
+            meta: HashMap::from([("pii".to_string(), serde_json::Value::Bool(true))]),
+            ..ColumnSelector::default()
+        };
+        assert_eq!(
+            missing_tests(&selector, &["not_null", "is_hashed"]),
+            vec!["Column 'email' of customers is missing required tests: is_hashed"]
+        );
+    }
+
+    #[test]
+    fn test_column_has_tests_by_data_type_and_tags() {
+        let selector = ColumnSelector {
+            data_types: vec!["varchar".to_string()],
+            tags: vec!["finance".to_string()],
+            ..ColumnSelector::default()
+        };
+        assert_eq!(
+            missing_tests(&selector, &["not_null"]),
+            vec!["Column 'payment_status' of customers is missing required tests: not_null"]
+        );
+
+        let selector = ColumnSelector {
+            data_types: vec!["varchar".to_string()],
+            ..ColumnSelector::default()
+        };
+        assert_eq!(missing_tests(&selector, &["not_null"]).len(), 2);
+    }
+}
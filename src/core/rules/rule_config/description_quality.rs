@@ -0,0 +1,199 @@
+use crate::{
+    cli::table::RuleResult,
+    core::{
+        config::{check_config_options::DescriptionCriteria, manifest_rule::ManifestRule},
+        properties_file::Property,
+        rules::rule_config::has_description::Descriptable,
+    },
+};
+
+// Words that don't add anything to a name, "Table of orders" says no more than `orders`
+const FILLER_WORDS: [&str; 16] = [
+    "a", "an", "the", "this", "that", "of", "for", "and", "in", "with", "table", "model", "view",
+    "source", "column", "contains",
+];
+
+fn words(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// Whether the description only consists of the words of the name (and filler words).
+fn restates_name(name: &str, description: &str) -> bool {
+    let name_words: Vec<String> = words(name).collect();
+    let mut description_words = words(description).peekable();
+    // Punctuation only is a placeholder, not a restated name
+    description_words.peek().is_some()
+        && description_words
+            .filter(|word| !FILLER_WORDS.contains(&word.as_str()))
+            .all(|word| name_words.contains(&word))
+}
+
+/// Everything wrong with a description, empty when it is fine or missing (that is up to `has_description`).
+pub fn description_problems(
+    name: &str,
+    description: &str,
+    criteria: &DescriptionCriteria,
+) -> Vec<String> {
+    let description = description.trim();
+    if description.is_empty() {
+        return Vec::new();
+    }
+    let mut problems = Vec::new();
+
+    let length = description.chars().count();
+    if length < criteria.min_length {
+        problems.push(format!(
+            "has {length} characters, minimum is {}",
+            criteria.min_length
+        ));
+    }
+    let word_count = description.split_whitespace().count();
+    if word_count < criteria.min_words {
+        problems.push(format!(
+            "has {word_count} words, minimum is {}",
+            criteria.min_words
+        ));
+    }
+    if let Some(pattern) = criteria
+        .forbidden_patterns
+        .iter()
+        .find(|pattern| pattern.0.is_match(description))
+    {
+        problems.push(format!(
+            "looks like a placeholder (matches '{}')",
+            pattern.0.as_str()
+        ));
+    }
+    if !criteria.allow_restating_name && restates_name(name, description) {
+        problems.push("does not say more than the name".to_string());
+    }
+    if criteria.require_punctuation && !description.ends_with(['.', '!', '?']) {
+        problems.push("does not end with '.', '!' or '?'".to_string());
+    }
+    problems
+}
+
+// Check the description of an object, and of its columns (name, description) when given
+pub fn description_quality<T: Descriptable>(
+    object: &T,
+    columns: &[(&String, &String)],
+    rule: &ManifestRule,
+    criteria: &DescriptionCriteria,
+) -> Vec<(RuleResult, Property)> {
+    let name = object.get_object_string();
+    let result = |subject: String, problems: Vec<String>| {
+        RuleResult::new(
+            &rule.severity,
+            object.get_object_type(),
+            rule.get_name(),
+            format!("Description of {subject} {}", problems.join("; ")),
+            object.get_relative_path().cloned(),
+        )
+    };
+
+    let mut results = Vec::new();
+    let problems = description_problems(
+        name,
+        object.description().map_or("", String::as_str),
+        criteria,
+    );
+    if !problems.is_empty() {
+        results.push((result(name.to_string(), problems), Property::Description));
+    }
+
+    if criteria.include_columns {
+        let mut columns = columns.to_vec();
+        columns.sort();
+        for (column, description) in columns {
+            let problems = description_problems(column, description, criteria);
+            if !problems.is_empty() {
+                results.push((
+                    result(format!("column '{column}' of {name}"), problems),
+                    Property::Column(column.clone()),
+                ));
+            }
+        }
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::check_config_options::default_forbidden_patterns;
+
+    fn criteria() -> DescriptionCriteria {
+        DescriptionCriteria {
+            forbidden_patterns: default_forbidden_patterns(),
+            ..DescriptionCriteria::default()
+        }
+    }
+
+    #[test]
+    fn test_description_problems_placeholders() {
+        for placeholder in [
+            "TODO",
+            "todo: add a description",
+            "N/A",
+            "...",
+            "Description",
+        ] {
+            assert_eq!(
+                description_problems("orders", placeholder, &criteria()).len(),
+                1,
+                "{placeholder}"
+            );
+        }
+        assert!(description_problems(
+            "orders",
+            "One row per order, including todo items.",
+            &criteria()
+        )
+        .is_empty());
+        // Missing descriptions are left to has_description
+        assert!(description_problems("orders", "  ", &criteria()).is_empty());
+    }
+
+    #[test]
+    fn test_description_problems_restating_name() {
+        assert_eq!(
+            description_problems("customer_orders", "The customer orders table", &criteria()),
+            vec!["does not say more than the name"]
+        );
+        assert_eq!(
+            description_problems("orders", "Model", &criteria()),
+            vec!["does not say more than the name"]
+        );
+        let criteria = DescriptionCriteria {
+            allow_restating_name: true,
+            ..criteria()
+        };
+        assert!(description_problems("orders", "Orders", &criteria).is_empty());
+    }
+
+    #[test]
+    fn test_description_problems_length_and_punctuation() {
+        let criteria = DescriptionCriteria {
+            min_length: 30,
+            min_words: 5,
+            require_punctuation: true,
+            ..criteria()
+        };
+        assert_eq!(
+            description_problems("orders", "Orders placed online", &criteria),
+            vec![
+                "has 20 characters, minimum is 30",
+                "has 3 words, minimum is 5",
+                "does not end with '.', '!' or '?'",
+            ]
+        );
+        assert!(description_problems(
+            "orders",
+            "One row per order placed in the web shop.",
+            &criteria
+        )
+        .is_empty());
+    }
+}
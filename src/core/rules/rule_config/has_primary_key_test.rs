@@ -0,0 +1,321 @@
+use crate::{
+    cli::table::RuleResult,
+    core::{
+        config::manifest_rule::ManifestRule,
+        manifest::{Manifest, Node},
+        rules::rule_config::{has_contract_enforced::ContractAble, has_unique_test::TestAble},
+    },
+};
+use dbt_artifact_parser::manifest::nodes::{Model, Test};
+
+// Kwargs of generic tests that test several columns together
+const COLUMN_LIST_KWARGS: [&str; 3] = ["combination_of_columns", "column_list", "column_names"];
+
+/// Columns a generic test is about, lower-cased.
+/// Tests on a single column use `column_name`, combined tests list their columns in the kwargs.
+pub fn tested_columns(test: &Test) -> Vec<String> {
+    if let Some(column_name) = &test.column_name {
+        return vec![column_name.to_lowercase()];
+    }
+    let Some(kwargs) = test
+        .test_metadata
+        .as_ref()
+        .and_then(|metadata| metadata.kwargs.as_ref())
+    else {
+        return Vec::new();
+    };
+    COLUMN_LIST_KWARGS
+        .iter()
+        .find_map(|kwarg| kwargs.get(kwarg).and_then(|value| value.as_array()))
+        .map(|columns| {
+            columns
+                .iter()
+                .filter_map(|column| column.as_str())
+                .map(str::to_lowercase)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Model and column level constraints of a model as (type, lower-cased columns).
+fn constraints(model: &Model) -> Vec<(&str, Vec<String>)> {
+    let model_constraints = model.constraints.iter().flatten().map(|constraint| {
+        let columns = constraint
+            .columns
+            .iter()
+            .map(|c| c.to_lowercase())
+            .collect();
+        (constraint.type_.as_str(), columns)
+    });
+    let column_constraints = model
+        .base
+        .columns
+        .iter()
+        .flat_map(|columns| columns.values())
+        .flat_map(|column| {
+            column
+                .constraints
+                .iter()
+                .flatten()
+                .map(|constraint| (constraint.type_.as_str(), vec![column.name.to_lowercase()]))
+        });
+    model_constraints.chain(column_constraints).collect()
+}
+
+/// The declared primary key, or the one inferred from a `primary_key` constraint or a combined uniqueness test.
+pub fn primary_key(model: &Model, unique_tests: &[Vec<String>]) -> Vec<String> {
+    if let Some(primary_key) = model.primary_key.as_ref().filter(|key| !key.is_empty()) {
+        return primary_key.iter().map(|c| c.to_lowercase()).collect();
+    }
+    // A column can be constrained at model and at column level
+    let mut constrained: Vec<String> = Vec::new();
+    for column in constraints(model)
+        .into_iter()
+        .filter(|(type_, _)| *type_ == "primary_key")
+        .flat_map(|(_, columns)| columns)
+    {
+        if !constrained.contains(&column) {
+            constrained.push(column);
+        }
+    }
+    if !constrained.is_empty() {
+        return constrained;
+    }
+    unique_tests
+        .iter()
+        .find(|columns| columns.len() > 1)
+        .cloned()
+        .unwrap_or_default()
+}
+
+fn same_columns(a: &[String], b: &[String]) -> bool {
+    a.len() == b.len() && a.iter().all(|column| b.contains(column))
+}
+
+// Check that the primary key of a model is covered by a uniqueness test and not_null tests (or enforced constraints)
+pub fn has_primary_key_test(
+    node: &Node,
+    rule: &ManifestRule,
+    manifest: &Manifest,
+    unique_test_names: &[String],
+    not_null_test_names: &[String],
+) -> Option<RuleResult> {
+    let Node::Model(model) = node else {
+        return None;
+    };
+
+    let tests = node.get_tests(manifest);
+    let columns_of = |names: &[String]| -> Vec<Vec<String>> {
+        tests
+            .iter()
+            .filter(|test| names.iter().any(|name| test.matches_name(name)))
+            .map(|test| tested_columns(test))
+            .filter(|columns| !columns.is_empty())
+            .collect()
+    };
+    let unique_tests = columns_of(unique_test_names);
+    let not_null_tests = columns_of(not_null_test_names);
+
+    let primary_key = primary_key(model, &unique_tests);
+    let message = if primary_key.is_empty() {
+        format!(
+            "{} has no primary key, declare a primary_key constraint or add a unique_combination_of_columns test",
+            node.get_name()
+        )
+    } else {
+        // Constraints only guarantee anything when the contract is enforced
+        let enforced = if node.get_contract_enforced() == Some(true) {
+            constraints(model)
+        } else {
+            Vec::new()
+        };
+
+        let unique = unique_tests
+            .iter()
+            .any(|columns| same_columns(columns, &primary_key))
+            || enforced.iter().any(|(type_, columns)| {
+                matches!(*type_, "primary_key" | "unique") && same_columns(columns, &primary_key)
+            });
+        let nullable: Vec<&String> = primary_key
+            .iter()
+            .filter(|column| {
+                !not_null_tests
+                    .iter()
+                    .any(|columns| columns.contains(column))
+                    && !enforced.iter().any(|(type_, columns)| {
+                        matches!(*type_, "primary_key" | "not_null") && columns.contains(column)
+                    })
+            })
+            .collect();
+
+        let mut missing = Vec::new();
+        if !unique {
+            missing.push("a uniqueness test".to_string());
+        }
+        if !nullable.is_empty() {
+            let columns: Vec<String> = nullable.iter().map(|c| format!("'{c}'")).collect();
+            missing.push(format!("a not_null test on {}", columns.join(", ")));
+        }
+        if missing.is_empty() {
+            return None;
+        }
+        format!(
+            "Primary key ({}) of {} is missing {}",
+            primary_key.join(", "),
+            node.get_name(),
+            missing.join(" and ")
+        )
+    };
+
+    Some(RuleResult::new(
+        &rule.severity,
+        node.get_object_type(),
+        rule.get_name(),
+        message,
+        Some(node.get_relative_path().clone()),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::{
+        check_config_options::{default_not_null_test_names, default_unique_test_names},
+        manifest_rule::ManifestSpecificRuleConfig,
+        severity::Severity,
+    };
+    use crate::core::rules::test_utils::ManifestBuilder;
+    use serde_json::{json, Value};
+
+    fn rule() -> ManifestRule {
+        ManifestRule::from_specific_rule(
+            ManifestSpecificRuleConfig::HasPrimaryKeyTest {
+                unique_test_names: default_unique_test_names(),
+                not_null_test_names: default_not_null_test_names(),
+            },
+            Severity::Error,
+        )
+    }
+
+    fn message(builder: ManifestBuilder) -> Option<String> {
+        let manifest = builder.build();
+        has_primary_key_test(
+            &manifest.nodes["model.p.orders"],
+            &rule(),
+            &manifest,
+            &default_unique_test_names(),
+            &default_not_null_test_names(),
+        )
+        .map(|result| result.message)
+    }
+
+    fn test(
+        builder: ManifestBuilder,
+        name: &str,
+        namespace: Option<&str>,
+        column: Option<&str>,
+        kwargs: &Value,
+    ) -> ManifestBuilder {
+        builder.node(
+            "test",
+            &format!("{name}_{}", column.unwrap_or("combined")),
+            json!({
+                "attached_node": "model.p.orders",
+                "column_name": column,
+                "test_metadata": {"name": name, "namespace": namespace, "kwargs": kwargs}
+            }),
+        )
+    }
+
+    fn orders() -> ManifestBuilder {
+        ManifestBuilder::default().model("orders", json!({"primary_key": ["order_id"]}))
+    }
+
+    #[test]
+    fn test_declared_primary_key() {
+        assert_eq!(
+            message(orders()).unwrap(),
+            "Primary key (order_id) of orders is missing a uniqueness test and a not_null test on 'order_id'"
+        );
+
+        let unique = test(orders(), "unique", None, Some("order_id"), &json!({}));
+        assert_eq!(
+            message(unique).unwrap(),
+            "Primary key (order_id) of orders is missing a not_null test on 'order_id'"
+        );
+
+        let unique = test(orders(), "unique", None, Some("order_id"), &json!({}));
+        let not_null = test(unique, "not_null", None, Some("ORDER_ID"), &json!({}));
+        assert!(message(not_null).is_none());
+    }
+
+    #[test]
+    fn test_unique_test_on_other_column() {
+        let unique = test(orders(), "unique", None, Some("customer_id"), &json!({}));
+        let not_null = test(unique, "not_null", None, Some("order_id"), &json!({}));
+        assert_eq!(
+            message(not_null).unwrap(),
+            "Primary key (order_id) of orders is missing a uniqueness test"
+        );
+    }
+
+    #[test]
+    fn test_primary_key_from_combination_test() {
+        let orders = || ManifestBuilder::default().model("orders", json!({}));
+        assert_eq!(
+            message(orders()).unwrap(),
+            "orders has no primary key, declare a primary_key constraint or add a unique_combination_of_columns test"
+        );
+
+        let combination = test(
+            orders(),
+            "unique_combination_of_columns",
+            Some("dbt_utils"),
+            None,
+            &json!({"combination_of_columns": ["order_id", "line"]}),
+        );
+        let not_null = test(combination, "not_null", None, Some("order_id"), &json!({}));
+        assert_eq!(
+            message(not_null).unwrap(),
+            "Primary key (order_id, line) of orders is missing a not_null test on 'line'"
+        );
+    }
+
+    #[test]
+    fn test_enforced_constraints() {
+        let orders = |enforced: bool| {
+            ManifestBuilder::default().model(
+                "orders",
+                json!({
+                    "config": {"contract": {"enforced": enforced, "alias_types": true}},
+                    "constraints": [{"type": "primary_key", "columns": ["order_id", "line"]}],
+                    "columns": {
+                        "line": {"name": "line", "tags": [], "constraints": [{"type": "not_null"}]}
+                    }
+                }),
+            )
+        };
+        assert!(message(orders(true)).is_none());
+        assert_eq!(
+            message(orders(false)).unwrap(),
+            "Primary key (order_id, line) of orders is missing a uniqueness test and a not_null test on 'order_id', 'line'"
+        );
+    }
+
+    #[test]
+    fn test_primary_key_constrained_twice() {
+        let orders = ManifestBuilder::default().model(
+            "orders",
+            json!({
+                "constraints": [{"type": "primary_key", "columns": ["order_id"]}],
+                "columns": {
+                    "order_id": {"name": "order_id", "tags": [], "constraints": [{"type": "primary_key"}]}
+                }
+            }),
+        );
+        assert_eq!(
+            message(orders).unwrap(),
+            "Primary key (order_id) of orders is missing a uniqueness test and a not_null test on 'order_id'"
+        );
+    }
+}
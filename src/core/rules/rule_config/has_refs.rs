@@ -4,6 +4,9 @@ use crate::{cli::table::RuleResult, core::config::manifest_rule::ManifestRule};
 // Indicated by {{ ref(model_name) }} statements in dbt models or {{ source(...) }} statements.
 pub trait CanReference {
     fn get_depends_on_nodes(&self) -> &[String];
+    fn get_depends_on_macros(&self) -> &[String] {
+        &[]
+    }
     fn get_object_type(&self) -> &str;
     fn get_object_string(&self) -> &str;
     fn get_relative_path(&self) -> Option<&String> {
@@ -0,0 +1,192 @@
+use crate::{
+    cli::table::RuleResult,
+    core::{
+        config::{check_config_options::ColumnNamePattern, manifest_rule::ManifestRule},
+        manifest::{Manifest, Node},
+        properties_file::{Property, PropertyRef},
+        rules::rule_config::{
+            has_primary_key_test::{primary_key, tested_columns},
+            has_unique_test::TestAble,
+        },
+    },
+};
+
+/// Lower-cased columns of a node with a foreign key constraint, on the column or on the model.
+fn foreign_key_constraint_columns(node: &Node) -> Vec<String> {
+    let model_constraints = match node {
+        Node::Model(model) => model
+            .constraints
+            .iter()
+            .flatten()
+            .filter(|constraint| constraint.type_ == "foreign_key")
+            .flat_map(|constraint| constraint.columns.iter().map(|c| c.to_lowercase()))
+            .collect(),
+        _ => Vec::new(),
+    };
+    let column_constraints = node
+        .get_base()
+        .columns
+        .iter()
+        .flat_map(|columns| columns.values())
+        .filter(|column| {
+            column
+                .constraints
+                .iter()
+                .flatten()
+                .any(|constraint| constraint.type_ == "foreign_key")
+        })
+        .map(|column| column.name.to_lowercase());
+    model_constraints
+        .into_iter()
+        .chain(column_constraints)
+        .collect()
+}
+
+// Check that every foreign-key-looking column has a relationships test or a foreign key constraint
+pub fn has_relationship_tests(
+    node: &Node,
+    rule: &ManifestRule,
+    manifest: &Manifest,
+    columns: &[ColumnNamePattern],
+    allowed_test_names: &[String],
+    exemptions: &[ColumnNamePattern],
+) -> Vec<RuleResult> {
+    let primary_key = match node {
+        Node::Model(model) => primary_key(model, &[]),
+        _ => Vec::new(),
+    };
+
+    let mut related: Vec<String> = node
+        .get_tests(manifest)
+        .into_iter()
+        .filter(|test| {
+            allowed_test_names
+                .iter()
+                .any(|name| test.matches_name(name))
+        })
+        .flat_map(tested_columns)
+        .collect();
+    related.extend(foreign_key_constraint_columns(node));
+
+    let mut unrelated: Vec<&String> = node
+        .get_base()
+        .columns
+        .iter()
+        .flat_map(|columns| columns.values())
+        .map(|column| &column.name)
+        .filter(|name| columns.iter().any(|pattern| pattern.matches(name)))
+        .filter(|name| !exemptions.iter().any(|pattern| pattern.matches(name)))
+        .filter(|name| {
+            let name = name.to_lowercase();
+            !primary_key.contains(&name) && !related.contains(&name)
+        })
+        .collect();
+    unrelated.sort();
+
+    unrelated
+        .into_iter()
+        .map(|name| {
+            RuleResult::new(
+                &rule.severity,
+                node.get_object_type(),
+                rule.get_name(),
+                format!(
+                    "Column '{name}' of {} looks like a foreign key but has no relationships test or foreign key constraint",
+                    node.get_name()
+                ),
+                Some(node.get_relative_path().clone()),
+            )
+            .with_property(PropertyRef::of_node(node, Property::Column(name.clone())))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::{
+        check_config_options::{default_foreign_key_columns, default_relationship_test_names},
+        manifest_rule::ManifestSpecificRuleConfig,
+        severity::Severity,
+    };
+    use crate::core::rules::test_utils::{messages, ManifestBuilder};
+    use serde_json::json;
+
+    fn manifest() -> Manifest {
+        ManifestBuilder::default()
+            .model(
+                "orders",
+                json!({
+                    "primary_key": ["order_id"],
+                    "columns": {
+                        "order_id": {"name": "order_id", "tags": []},
+                        "customer_id": {"name": "customer_id", "tags": []},
+                        "store_id": {
+                            "name": "store_id", "tags": [],
+                            "constraints": [{"type": "foreign_key", "to": "ref('stores')"}]
+                        },
+                        "employee_id": {"name": "employee_id", "tags": []},
+                        "external_id": {"name": "external_id", "tags": []},
+                        "status": {"name": "status", "tags": []}
+                    }
+                }),
+            )
+            .node(
+                "test",
+                "relationships_orders_customer_id",
+                json!({
+                    "attached_node": "model.p.orders", "column_name": "CUSTOMER_ID",
+                    "test_metadata": {"name": "relationships", "kwargs": {"to": "ref('customers')", "field": "id"}}
+                }),
+            )
+            .build()
+    }
+
+    fn relationship_messages(
+        exemptions: &[ColumnNamePattern],
+        test_names: &[String],
+    ) -> Vec<String> {
+        let manifest = manifest();
+        let rule = ManifestRule::from_specific_rule(
+            ManifestSpecificRuleConfig::HasRelationshipTests {
+                columns: default_foreign_key_columns(),
+                allowed_test_names: default_relationship_test_names(),
+                exemptions: Vec::new(),
+            },
+            Severity::Error,
+        );
+        messages(has_relationship_tests(
+            &manifest.nodes["model.p.orders"],
+            &rule,
+            &manifest,
+            &default_foreign_key_columns(),
+            test_names,
+            exemptions,
+        ))
+    }
+
+    #[test]
+    fn test_has_relationship_tests() {
+        assert_eq!(
+            relationship_messages(&[], &default_relationship_test_names()),
+            vec![
+                "Column 'employee_id' of orders looks like a foreign key but has no relationships test or foreign key constraint",
+                "Column 'external_id' of orders looks like a foreign key but has no relationships test or foreign key constraint",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_has_relationship_tests_exemptions_and_test_names() {
+        let exemptions = vec![
+            ColumnNamePattern::Literal("external_id".to_string()),
+            ColumnNamePattern::Regex(regex::Regex::new("^emp.*_id$").unwrap()),
+        ];
+        assert_eq!(
+            relationship_messages(&exemptions, &["dbt_constraints.foreign_key".to_string()]),
+            vec![
+                "Column 'customer_id' of orders looks like a foreign key but has no relationships test or foreign key constraint",
+            ]
+        );
+    }
+}
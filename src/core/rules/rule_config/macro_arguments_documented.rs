@@ -0,0 +1,164 @@
+use regex::Regex;
+use std::sync::LazyLock;
+
+use crate::{
+    cli::table::RuleResult,
+    core::{config::manifest_rule::ManifestRule, manifest::Macro},
+};
+
+// `{% macro name(`, compiled once for all macros
+static MACRO_SIGNATURE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{%-?\s*macro\s+(\w+)\s*\(").expect("valid regex"));
+
+/// Names of the parameters in the `{% macro name(...) %}` signature, `None` if there is no signature.
+/// Defaults may contain commas and parentheses (e.g. `columns=['a', 'b']`), so only top-level commas split.
+fn signature_arguments(macro_sql: &str, name: &str) -> Option<Vec<String>> {
+    let start = MACRO_SIGNATURE
+        .captures_iter(macro_sql)
+        .find(|signature| &signature[1] == name)?
+        .get(0)?
+        .end();
+
+    let mut arguments = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0usize;
+    let mut quote = None;
+    for c in macro_sql[start..].chars() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '(' | '[' | '{') => depth += 1,
+            (None, ')') if depth == 0 => {
+                arguments.push(current);
+                break;
+            }
+            (None, ')' | ']' | '}') => depth = depth.saturating_sub(1),
+            (None, ',') if depth == 0 => {
+                arguments.push(std::mem::take(&mut current));
+                continue;
+            }
+            _ => {}
+        }
+        if depth == 0 && quote.is_none() {
+            current.push(c);
+        }
+    }
+
+    Some(
+        arguments
+            .iter()
+            .filter_map(|argument| {
+                let name = argument.split('=').next().unwrap_or_default().trim();
+                (!name.is_empty()).then(|| name.to_string())
+            })
+            .collect(),
+    )
+}
+
+fn is_blank(value: Option<&String>) -> bool {
+    value.is_none_or(|value| value.trim().is_empty())
+}
+
+// Check that the arguments of a macro are documented in its properties file
+pub fn macro_arguments_documented(
+    macro_obj: &Macro,
+    rule: &ManifestRule,
+    require_types: bool,
+) -> Vec<RuleResult> {
+    let result = |argument: &str, problem: &str| {
+        RuleResult::new(
+            &rule.severity,
+            Macro::get_object_type(),
+            rule.get_name(),
+            format!(
+                "Argument '{argument}' of macro {} {problem}",
+                macro_obj.get_name()
+            ),
+            Some(macro_obj.get_relative_path().clone()),
+        )
+    };
+
+    let mut results = Vec::new();
+    for argument in &macro_obj.arguments {
+        if is_blank(argument.description.as_ref()) {
+            results.push(result(&argument.name, "has no description"));
+        }
+        if require_types && is_blank(argument.type_.as_ref()) {
+            results.push(result(&argument.name, "has no type"));
+        }
+    }
+    for name in signature_arguments(&macro_obj.macro_sql, macro_obj.get_name())
+        .unwrap_or_default()
+        .iter()
+        .filter(|name| !macro_obj.arguments.iter().any(|a| &a.name == *name))
+    {
+        results.push(result(name, "is not documented"));
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::{manifest_rule::ManifestSpecificRuleConfig, severity::Severity};
+
+    #[test]
+    fn test_signature_arguments() {
+        let sql = "{%- macro pivot(column, values=['a', 'b,c'], alias=true, agg=\"sum(\") -%}\n{{ column }}\n{% endmacro %}";
+        assert_eq!(
+            signature_arguments(sql, "pivot"),
+            Some(vec![
+                "column".to_string(),
+                "values".to_string(),
+                "alias".to_string(),
+                "agg".to_string(),
+            ])
+        );
+        assert_eq!(
+            signature_arguments("{% macro now() %}{% endmacro %}", "now"),
+            Some(vec![])
+        );
+        assert_eq!(signature_arguments("select 1", "now"), None);
+    }
+
+    #[test]
+    fn test_macro_arguments_documented() {
+        let macro_obj: Macro = serde_json::from_str(
+            r#"{
+  "name": "cents_to_dollars", "package_name": "p", "original_file_path": "macros/cents_to_dollars.sql",
+  "macro_sql": "{% macro cents_to_dollars(column, scale=2, rounding='half_up') %}{% endmacro %}",
+  "arguments": [
+    {"name": "column", "type": "string", "description": "Column in cents"},
+    {"name": "scale", "type": null, "description": ""}
+  ]
+}"#,
+        )
+        .unwrap();
+        let messages = |require_types| {
+            let rule = ManifestRule::from_specific_rule(
+                ManifestSpecificRuleConfig::MacroArgumentsDocumented { require_types },
+                Severity::Warning,
+            );
+            macro_arguments_documented(&macro_obj, &rule, require_types)
+                .into_iter()
+                .map(|result| result.message)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            messages(false),
+            vec![
+                "Argument 'scale' of macro cents_to_dollars has no description",
+                "Argument 'rounding' of macro cents_to_dollars is not documented",
+            ]
+        );
+        assert_eq!(
+            messages(true),
+            vec![
+                "Argument 'scale' of macro cents_to_dollars has no description",
+                "Argument 'scale' of macro cents_to_dollars has no type",
+                "Argument 'rounding' of macro cents_to_dollars is not documented",
+            ]
+        );
+    }
+}
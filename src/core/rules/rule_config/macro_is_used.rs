@@ -0,0 +1,128 @@
+use crate::{
+    cli::table::RuleResult,
+    core::{
+        config::{check_config_options::ColumnNamePattern, manifest_rule::ManifestRule},
+        manifest::{Macro, Manifest},
+    },
+};
+use std::{collections::HashSet, hash::BuildHasher};
+
+/// Unique ids of the macros called by a node (including tests and hooks), another macro or an exposure.
+/// Built once per run, instead of scanning the manifest for every macro.
+pub fn called_macros(manifest: &Manifest) -> HashSet<&str> {
+    manifest
+        .nodes
+        .values()
+        .filter_map(|node| node.get_base().depends_on.macros.as_deref())
+        .chain(
+            manifest
+                .macros
+                .values()
+                .map(|macro_obj| macro_obj.depends_on.macros.as_slice()),
+        )
+        .chain(
+            manifest
+                .exposures
+                .values()
+                .filter_map(|exposure| exposure.depends_on.macros.as_deref()),
+        )
+        .flatten()
+        .map(String::as_str)
+        .collect()
+}
+
+// Check that a macro of the project is called somewhere
+pub fn macro_is_used<S: BuildHasher>(
+    macro_obj: &Macro,
+    rule: &ManifestRule,
+    exemptions: &[ColumnNamePattern],
+    called_macros: &HashSet<&str, S>,
+) -> Option<RuleResult> {
+    if exemptions
+        .iter()
+        .any(|pattern| pattern.matches(macro_obj.get_name()))
+        || called_macros.contains(macro_obj.get_unique_id().as_str())
+    {
+        return None;
+    }
+
+    Some(RuleResult::new(
+        &rule.severity,
+        Macro::get_object_type(),
+        rule.get_name(),
+        format!(
+            "Macro '{}' is not used by any node, macro or exposure",
+            macro_obj.get_name()
+        ),
+        Some(macro_obj.get_relative_path().clone()),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::{
+        check_config_options::default_macro_exemptions, manifest_rule::ManifestSpecificRuleConfig,
+        severity::Severity,
+    };
+    use crate::core::rules::test_utils::ManifestBuilder;
+    use serde_json::json;
+
+    fn manifest() -> Manifest {
+        ManifestBuilder::default()
+            .model(
+                "orders",
+                json!({"depends_on": {"macros": ["macro.p.cents_to_dollars"], "nodes": []}}),
+            )
+            .node(
+                "operation",
+                "p-on-run-end-0",
+                json!({
+                    "original_file_path": "./dbt_project.yml",
+                    "depends_on": {"macros": ["macro.p.grant_select"], "nodes": []}
+                }),
+            )
+            .macro_(
+                "cents_to_dollars",
+                json!({"depends_on": {"macros": ["macro.p.round_money"]}}),
+            )
+            .macro_("round_money", json!({}))
+            .macro_("grant_select", json!({}))
+            .macro_("old_helper", json!({}))
+            .macro_("generate_schema_name", json!({}))
+            .macro_(
+                "snowflake__round_money",
+                json!({"original_file_path": "macros/round_money.sql"}),
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_macro_is_used() {
+        let manifest = manifest();
+        let called_macros = called_macros(&manifest);
+        let exemptions = default_macro_exemptions();
+        let rule = ManifestRule::from_specific_rule(
+            ManifestSpecificRuleConfig::MacroIsUsed {
+                exemptions: default_macro_exemptions(),
+            },
+            Severity::Warning,
+        );
+
+        let mut unused: Vec<String> = manifest
+            .macros
+            .values()
+            .filter_map(|macro_obj| macro_is_used(macro_obj, &rule, &exemptions, &called_macros))
+            .map(|result| result.message)
+            .collect();
+        unused.sort();
+        assert_eq!(
+            unused,
+            vec!["Macro 'old_helper' is not used by any node, macro or exposure"]
+        );
+
+        let old_helper = &manifest.macros["macro.p.old_helper"];
+        let exemptions = vec![ColumnNamePattern::Literal("old_helper".to_string())];
+        assert!(macro_is_used(old_helper, &rule, &exemptions, &called_macros).is_none());
+    }
+}
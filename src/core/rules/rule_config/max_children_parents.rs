@@ -0,0 +1,221 @@
+use crate::{
+    cli::table::RuleResult,
+    core::{
+        config::{
+            check_config_options::{DagResourceType, LayerThreshold},
+            manifest_rule::ManifestRule,
+        },
+        manifest::Manifest,
+        rules::rule_config::allowed_dependencies::{in_layer, short_name, Layered},
+    },
+};
+
+// Number of neighbours listed in the message
+const LISTED: usize = 5;
+
+/// Report objects with more children than allowed for their layer.
+pub fn max_children<T: Layered>(
+    object: &T,
+    rule: &ManifestRule,
+    max: usize,
+    resource_types: &[DagResourceType],
+    layers: &[LayerThreshold],
+    manifest: &Manifest,
+) -> Option<RuleResult> {
+    let children: Vec<&str> = manifest
+        .child_map
+        .get(object.get_unique_id())
+        .into_iter()
+        .flatten()
+        .map(String::as_str)
+        .collect();
+    too_many(
+        object,
+        rule,
+        "children",
+        children,
+        max,
+        resource_types,
+        layers,
+    )
+}
+
+/// Report objects with more parents than allowed for their layer.
+pub fn max_parents<T: Layered>(
+    object: &T,
+    rule: &ManifestRule,
+    max: usize,
+    resource_types: &[DagResourceType],
+    layers: &[LayerThreshold],
+    manifest: &Manifest,
+) -> Option<RuleResult> {
+    let parents = object.get_parents(manifest);
+    too_many(
+        object,
+        rule,
+        "parents",
+        parents,
+        max,
+        resource_types,
+        layers,
+    )
+}
+
+fn too_many<T: Layered>(
+    object: &T,
+    rule: &ManifestRule,
+    relation: &str,
+    mut neighbours: Vec<&str>,
+    max: usize,
+    resource_types: &[DagResourceType],
+    layers: &[LayerThreshold],
+) -> Option<RuleResult> {
+    neighbours.retain(|unique_id| {
+        let resource_type = unique_id.split('.').next().unwrap_or_default();
+        resource_types.iter().any(|t| t.matches(resource_type))
+    });
+    neighbours.sort_unstable();
+    neighbours.dedup();
+
+    let threshold = layers
+        .iter()
+        .find(|threshold| in_layer(object, &threshold.layer));
+    let max = threshold.map_or(max, |threshold| threshold.max);
+    if neighbours.len() <= max {
+        return None;
+    }
+
+    let mut names: Vec<&str> = neighbours.iter().map(|id| short_name(id)).collect();
+    names.sort_unstable();
+    names.truncate(LISTED);
+    let more = if neighbours.len() > LISTED {
+        format!(" and {} more", neighbours.len() - LISTED)
+    } else {
+        String::new()
+    };
+    let layer = threshold.map_or_else(String::new, |threshold| {
+        format!(" for layer '{}'", threshold.layer.name)
+    });
+
+    Some(RuleResult::new(
+        &rule.severity,
+        object.get_object_type(),
+        rule.get_name(),
+        format!(
+            "{} '{}' has {} {relation}, more than the maximum of {max}{layer}: {}{more}",
+            object.get_object_type(),
+            object.get_object_string(),
+            neighbours.len(),
+            names.join(", "),
+        ),
+        object.get_relative_path().cloned(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::check_config_options::{
+        default_children_resource_types, default_parents_resource_types, Layer,
+    };
+    use crate::core::config::manifest_rule::ManifestSpecificRuleConfig;
+    use crate::core::config::severity::Severity;
+    use crate::core::rules::test_utils::ManifestBuilder;
+    use serde_json::json;
+
+    fn rule() -> ManifestRule {
+        ManifestRule::from_specific_rule(
+            ManifestSpecificRuleConfig::MaxChildren {
+                max: 2,
+                resource_types: default_children_resource_types(),
+                layers: vec![],
+            },
+            Severity::Error,
+        )
+    }
+
+    fn manifest() -> Manifest {
+        ManifestBuilder::default()
+            .model(
+                "stg_orders",
+                json!({
+                    "original_file_path": "models/staging/stg_orders.sql",
+                    "depends_on": {"nodes": ["source.p.raw.orders", "seed.p.countries"]}
+                }),
+            )
+            .with(
+                "child_map",
+                json!({
+                    "model.p.stg_orders": [
+                        "model.p.a", "model.p.b", "model.p.c", "model.p.d", "model.p.e", "model.p.f",
+                        "test.p.unique_stg_orders_id", "exposure.p.dashboard"
+                    ]
+                }),
+            )
+            .with(
+                "parent_map",
+                json!({"model.p.stg_orders": ["source.p.raw.orders"]}),
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_max_children() {
+        let manifest = manifest();
+        let node = manifest.get_node("model.p.stg_orders").unwrap();
+        let resource_types = default_children_resource_types();
+
+        // Tests are not counted by default
+        let result = max_children(node, &rule(), 6, &resource_types, &[], &manifest).unwrap();
+        assert_eq!(
+            result.message,
+            "Model 'stg_orders' has 7 children, more than the maximum of 6: a, b, c, d, dashboard and 2 more"
+        );
+        assert!(max_children(node, &rule(), 7, &resource_types, &[], &manifest).is_none());
+
+        // Only models
+        let models = vec![DagResourceType::Models];
+        assert!(max_children(node, &rule(), 6, &models, &[], &manifest).is_none());
+    }
+
+    #[test]
+    fn test_max_children_layer_threshold() {
+        let manifest = manifest();
+        let node = manifest.get_node("model.p.stg_orders").unwrap();
+        let layers = vec![LayerThreshold {
+            layer: Layer {
+                name: "staging".to_string(),
+                paths: vec![],
+                prefixes: vec!["stg_".to_string()],
+                tags: vec![],
+                resource_types: vec![],
+            },
+            max: 20,
+        }];
+        let resource_types = default_children_resource_types();
+        assert!(max_children(node, &rule(), 2, &resource_types, &layers, &manifest).is_none());
+
+        let layers = vec![LayerThreshold {
+            max: 3,
+            ..layers.into_iter().next().unwrap()
+        }];
+        let result = max_children(node, &rule(), 20, &resource_types, &layers, &manifest).unwrap();
+        assert!(result.message.starts_with(
+            "Model 'stg_orders' has 7 children, more than the maximum of 3 for layer 'staging': "
+        ));
+    }
+
+    #[test]
+    fn test_max_parents() {
+        let manifest = manifest();
+        let node = manifest.get_node("model.p.stg_orders").unwrap();
+        let resource_types = default_parents_resource_types();
+
+        let result = max_parents(node, &rule(), 1, &resource_types, &[], &manifest).unwrap();
+        assert_eq!(
+            result.message,
+            "Model 'stg_orders' has 2 parents, more than the maximum of 1: countries, raw.orders"
+        );
+        assert!(max_parents(node, &rule(), 2, &resource_types, &[], &manifest).is_none());
+    }
+}
@@ -0,0 +1,299 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    cli::table::RuleResult,
+    core::{
+        config::{manifest_rule::ManifestRule, Materialization},
+        manifest::Manifest,
+        rules::rule_config::allowed_dependencies::{parents_of, short_name, Layered},
+    },
+};
+
+// Materializations that are computed again every time they are queried
+fn is_view(unique_id: &str, manifest: &Manifest) -> bool {
+    manifest
+        .get_node(unique_id)
+        .and_then(|node| node.get_materialization())
+        .is_some_and(|materialized| {
+            matches!(
+                materialized,
+                Materialization::View | Materialization::Ephemeral
+            )
+        })
+}
+
+/// The end of the longest chain ending at an object: its length and the object before it.
+#[derive(Clone, Copy)]
+struct ChainLink<'a> {
+    len: usize,
+    previous: Option<&'a str>,
+}
+
+/// Longest chains of objects for which `keep` holds, ending at each node (length 0 if it doesn't hold).
+/// The DAG is walked with an explicit stack, so deep DAGs can't overflow the call stack.
+fn longest_chains<'a>(
+    manifest: &'a Manifest,
+    keep: impl Fn(&str) -> bool,
+) -> HashMap<&'a str, ChainLink<'a>> {
+    let mut links: HashMap<&'a str, ChainLink<'a>> = HashMap::new();
+    let mut in_progress = HashSet::new();
+
+    for start in manifest.nodes.keys() {
+        // (object, whether its parents have been visited)
+        let mut stack = vec![(start.as_str(), false)];
+        while let Some((unique_id, parents_visited)) = stack.pop() {
+            if links.contains_key(unique_id) {
+                continue;
+            }
+            if !keep(unique_id) {
+                links.insert(
+                    unique_id,
+                    ChainLink {
+                        len: 0,
+                        previous: None,
+                    },
+                );
+                continue;
+            }
+
+            let mut parents = parents_of(unique_id, manifest);
+            if !parents_visited {
+                // An object that is already being visited is part of a cycle
+                if !in_progress.insert(unique_id) {
+                    continue;
+                }
+                stack.push((unique_id, true));
+                stack.extend(
+                    parents
+                        .into_iter()
+                        .filter(|parent| !links.contains_key(parent))
+                        .map(|parent| (parent, false)),
+                );
+                continue;
+            }
+
+            // On a tie the parent that sorts first wins, so the reported chain is stable
+            parents.sort_unstable();
+            let mut link = ChainLink {
+                len: 1,
+                previous: None,
+            };
+            for parent in parents {
+                if let Some(parent_link) = links.get(parent) {
+                    if parent_link.len + 1 > link.len {
+                        link = ChainLink {
+                            len: parent_link.len + 1,
+                            previous: Some(parent),
+                        };
+                    }
+                }
+            }
+            in_progress.remove(unique_id);
+            links.insert(unique_id, link);
+        }
+    }
+    links
+}
+
+fn chain<'a>(links: &HashMap<&'a str, ChainLink<'a>>, unique_id: &str) -> Vec<&'a str> {
+    let mut chain = Vec::new();
+    let mut current = links.get_key_value(unique_id);
+    while let Some((unique_id, link)) = current {
+        if link.len == 0 {
+            break;
+        }
+        chain.push(*unique_id);
+        current = link
+            .previous
+            .and_then(|previous| links.get_key_value(previous));
+    }
+    chain.reverse();
+    chain
+}
+
+/// The longest paths and chains of views in the DAG, computed once per run and shared by all nodes.
+pub struct DagChains<'a> {
+    paths: HashMap<&'a str, ChainLink<'a>>,
+    view_chains: HashMap<&'a str, ChainLink<'a>>,
+}
+
+impl<'a> DagChains<'a> {
+    pub fn new(manifest: &'a Manifest) -> Self {
+        Self {
+            paths: longest_chains(manifest, |_| true),
+            view_chains: longest_chains(manifest, |unique_id| is_view(unique_id, manifest)),
+        }
+    }
+}
+
+fn chain_string(chain: &[&str]) -> String {
+    chain
+        .iter()
+        .map(|unique_id| short_name(unique_id))
+        .collect::<Vec<_>>()
+        .join(" -> ")
+}
+
+/// Report objects that are too far from the sources, or at the end of a too long chain of views.
+pub fn max_dag_depth<T: Layered>(
+    object: &T,
+    rule: &ManifestRule,
+    max_depth: Option<usize>,
+    max_chained_views: Option<usize>,
+    dag_chains: &DagChains,
+) -> Vec<RuleResult> {
+    let mut results = Vec::new();
+    let mut result = |message: String| {
+        results.push(RuleResult::new(
+            &rule.severity,
+            object.get_object_type(),
+            rule.get_name(),
+            message,
+            object.get_relative_path().cloned(),
+        ));
+    };
+
+    if let Some(max_depth) = max_depth {
+        let path = chain(&dag_chains.paths, object.get_unique_id());
+        // The depth is the number of edges from the first object on the path
+        let depth = path.len().saturating_sub(1);
+        if depth > max_depth {
+            result(format!(
+                "{} '{}' is at depth {depth} of the DAG, more than the maximum of {max_depth}: {}",
+                object.get_object_type(),
+                object.get_object_string(),
+                chain_string(&path),
+            ));
+        }
+    }
+
+    if let Some(max_chained_views) = max_chained_views {
+        let chain = chain(&dag_chains.view_chains, object.get_unique_id());
+        if chain.len() > max_chained_views {
+            result(format!(
+                "{} '{}' ends a chain of {} views/ephemeral models, more than the maximum of {max_chained_views}: {}",
+                object.get_object_type(),
+                object.get_object_string(),
+                chain.len(),
+                chain_string(&chain),
+            ));
+        }
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::manifest_rule::ManifestSpecificRuleConfig;
+    use crate::core::config::severity::Severity;
+    use crate::core::rules::test_utils::ManifestBuilder;
+    use serde_json::json;
+
+    fn rule() -> ManifestRule {
+        ManifestRule::from_specific_rule(
+            ManifestSpecificRuleConfig::MaxDagDepth {
+                max_depth: None,
+                max_chained_views: None,
+            },
+            Severity::Error,
+        )
+    }
+
+    // raw.orders -> stg_orders (view) -> int_orders (ephemeral) -> int_orders_enriched (view) -> fct_orders (table)
+    // raw.orders -> fct_orders
+    fn manifest() -> Manifest {
+        ManifestBuilder::default()
+            .model(
+                "stg_orders",
+                json!({
+                    "config": {"materialized": "view"},
+                    "depends_on": {"nodes": ["source.p.raw.orders"]}
+                }),
+            )
+            .model(
+                "int_orders",
+                json!({
+                    "config": {"materialized": "ephemeral"},
+                    "depends_on": {"nodes": ["model.p.stg_orders"]}
+                }),
+            )
+            .model(
+                "int_orders_enriched",
+                json!({
+                    "config": {"materialized": "view"},
+                    "depends_on": {"nodes": ["model.p.int_orders"]}
+                }),
+            )
+            .model(
+                "fct_orders",
+                json!({
+                    "config": {"materialized": "table"},
+                    "depends_on": {"nodes": ["model.p.int_orders_enriched", "source.p.raw.orders"]}
+                }),
+            )
+            .source("raw", "orders", json!({}))
+            .build()
+    }
+
+    #[test]
+    fn test_max_depth() {
+        let manifest = manifest();
+        let node = manifest.get_node("model.p.fct_orders").unwrap();
+
+        let dag_chains = DagChains::new(&manifest);
+
+        let results = max_dag_depth(node, &rule(), Some(3), None, &dag_chains);
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].message,
+            "Model 'fct_orders' is at depth 4 of the DAG, more than the maximum of 3: raw.orders -> stg_orders -> int_orders -> int_orders_enriched -> fct_orders"
+        );
+        assert!(max_dag_depth(node, &rule(), Some(4), None, &dag_chains).is_empty());
+    }
+
+    #[test]
+    fn test_max_chained_views() {
+        let manifest = manifest();
+
+        let dag_chains = DagChains::new(&manifest);
+
+        let view = manifest.get_node("model.p.int_orders_enriched").unwrap();
+        let results = max_dag_depth(view, &rule(), None, Some(2), &dag_chains);
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].message,
+            "Model 'int_orders_enriched' ends a chain of 3 views/ephemeral models, more than the maximum of 2: stg_orders -> int_orders -> int_orders_enriched"
+        );
+        assert!(max_dag_depth(view, &rule(), None, Some(3), &dag_chains).is_empty());
+
+        // Tables end a chain
+        let table = manifest.get_node("model.p.fct_orders").unwrap();
+        assert!(max_dag_depth(table, &rule(), None, Some(1), &dag_chains).is_empty());
+    }
+
+    #[test]
+    fn test_deep_dag() {
+        // A chain this long would overflow the stack when walked recursively
+        let manifest = (1..20_000)
+            .fold(
+                ManifestBuilder::default().model("m0", json!({})),
+                |builder, i| {
+                    builder.model(
+                        &format!("m{i}"),
+                        json!({"depends_on": {"nodes": [format!("model.p.m{}", i - 1)]}}),
+                    )
+                },
+            )
+            .build();
+        let dag_chains = DagChains::new(&manifest);
+
+        let node = manifest.get_node("model.p.m19999").unwrap();
+        let results = max_dag_depth(node, &rule(), Some(100), None, &dag_chains);
+        assert_eq!(results.len(), 1);
+        assert!(results[0]
+            .message
+            .starts_with("Model 'm19999' is at depth 19999 of the DAG"));
+    }
+}
@@ -1,19 +1,49 @@
+pub mod allowed_dependencies;
 pub mod child_map;
+pub mod column_has_tests;
+pub mod description_quality;
 pub mod has_contract_enforced;
 pub mod has_description;
 pub mod has_metadata_keys;
+pub mod has_primary_key_test;
 pub mod has_refs;
+pub mod has_relationship_tests;
 pub mod has_tags;
 pub mod has_unique_test;
+pub mod macro_arguments_documented;
+pub mod macro_is_used;
+pub mod max_children_parents;
 pub mod max_code_lines;
+pub mod max_dag_depth;
+pub mod model_versions;
 pub mod name_convention;
+pub mod no_deprecated_references;
+pub mod no_hardcoded_references;
+pub mod redundant_dependencies;
+pub mod sources_only_in_staging;
+pub mod uses_doc_blocks;
 
+pub use allowed_dependencies::allowed_dependencies;
 pub use child_map::is_not_orphaned;
+pub use column_has_tests::column_has_tests;
+pub use description_quality::description_quality;
 pub use has_contract_enforced::has_contract_enforced;
 pub use has_description::has_description;
 pub use has_metadata_keys::has_metadata_keys;
+pub use has_primary_key_test::has_primary_key_test;
 pub use has_refs::has_refs;
+pub use has_relationship_tests::has_relationship_tests;
 pub use has_tags::has_tags;
 pub use has_unique_test::has_unique_test;
+pub use macro_arguments_documented::macro_arguments_documented;
+pub use macro_is_used::macro_is_used;
+pub use max_children_parents::{max_children, max_parents};
 pub use max_code_lines::max_code_lines;
+pub use max_dag_depth::max_dag_depth;
+pub use model_versions::model_versions;
 pub use name_convention::check_name_convention;
+pub use no_deprecated_references::no_deprecated_references;
+pub use no_hardcoded_references::no_hardcoded_references;
+pub use redundant_dependencies::redundant_dependencies;
+pub use sources_only_in_staging::{duplicate_staging_models, source_outside_staging};
+pub use uses_doc_blocks::uses_doc_blocks;
@@ -0,0 +1,345 @@
+use std::cmp::Ordering;
+
+use crate::{
+    cli::table::RuleResult,
+    core::{
+        config::{
+            check_config_options::{VersionChecks, VersionIssue},
+            manifest_rule::ManifestRule,
+        },
+        manifest::{dbt_objects::Column, Manifest, Node},
+        rules::rule_config::no_deprecated_references::{is_past, version_string},
+    },
+};
+use dbt_artifact_parser::manifest::nodes::Model;
+
+/// Numeric versions compare as numbers (`2` < `10`), others as text.
+fn compare_versions(a: &serde_json::Value, b: &serde_json::Value) -> Ordering {
+    let (a, b) = (version_string(a), version_string(b));
+    match (a.parse::<f64>(), b.parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        _ => a.cmp(&b),
+    }
+}
+
+/// All versions of the model `model` is a version of, oldest first.
+fn versions_of<'a>(model: &Model, manifest: &'a Manifest) -> Vec<&'a Model> {
+    let mut versions: Vec<&Model> = manifest
+        .nodes
+        .values()
+        .filter_map(|node| match node {
+            Node::Model(other)
+                if other.version.is_some()
+                    && other.base.name == model.base.name
+                    && other.base.package_name == model.base.package_name =>
+            {
+                Some(other)
+            }
+            _ => None,
+        })
+        .collect();
+    versions.sort_by(|a, b| {
+        compare_versions(
+            a.version.as_ref().unwrap_or_default(),
+            b.version.as_ref().unwrap_or_default(),
+        )
+    });
+    versions
+}
+
+fn is_contract_enforced(model: &Model) -> bool {
+    model
+        .base
+        .config
+        .as_ref()
+        .and_then(|config| config.contract.as_ref())
+        .is_some_and(|contract| contract.enforced)
+}
+
+fn sorted_columns(model: &Model) -> Vec<&Column> {
+    let mut columns: Vec<&Column> = model
+        .base
+        .columns
+        .iter()
+        .flat_map(|columns| columns.values())
+        .collect();
+    columns.sort_by(|a, b| a.name.cmp(&b.name));
+    columns
+}
+
+/// Contract columns of `previous` that `model` removes or gives another data type.
+fn breaking_changes(model: &Model, previous: &Model) -> Vec<String> {
+    let current = sorted_columns(model);
+    sorted_columns(previous)
+        .into_iter()
+        .filter_map(|old| {
+            let Some(new) = current
+                .iter()
+                .find(|new| new.name.eq_ignore_ascii_case(&old.name))
+            else {
+                return Some(format!("removes column '{}'", old.name));
+            };
+            match (&old.data_type, &new.data_type) {
+                (Some(old_type), Some(new_type))
+                    if !old_type.trim().eq_ignore_ascii_case(new_type.trim()) =>
+                {
+                    Some(format!(
+                        "changes the data type of column '{}' from {old_type} to {new_type}",
+                        old.name
+                    ))
+                }
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Findings about the versions of a versioned model, reported on the version they are about.
+fn version_messages(
+    model: &Model,
+    checks: &VersionChecks,
+    manifest: &Manifest,
+    today: &str,
+) -> Vec<String> {
+    let Some(version) = model.version.as_ref() else {
+        return Vec::new();
+    };
+    let versions = versions_of(model, manifest);
+    let name = &model.base.name;
+    let v = version_string(version);
+    // Without `latest_version` dbt takes the highest version
+    let latest = model
+        .latest_version
+        .as_ref()
+        .or_else(|| versions.last().and_then(|latest| latest.version.as_ref()))
+        .unwrap_or(version);
+    let is_latest = compare_versions(version, latest).is_eq();
+
+    let mut messages = Vec::new();
+    match model.deprecation_date.as_deref() {
+        None if !is_latest && !checks.allows(&VersionIssue::MissingDeprecationDate) => messages
+            .push(format!(
+                "Version {v} of {name} has no deprecation_date, but the latest version is {}",
+                version_string(latest)
+            )),
+        Some(date)
+            if is_past(date, today) && !checks.allows(&VersionIssue::PastDeprecationDate) =>
+        {
+            messages.push(format!(
+                "Version {v} of {name} was deprecated on {}, remove it",
+                &date[..10]
+            ));
+        }
+        _ => {}
+    }
+
+    if let Some(max) = checks.max_live_versions.filter(|_| is_latest) {
+        let live = versions
+            .iter()
+            .filter(|version| {
+                version
+                    .deprecation_date
+                    .as_deref()
+                    .is_none_or(|date| !is_past(date, today))
+            })
+            .count();
+        if live > max {
+            messages.push(format!("{name} has {live} live versions, more than {max}"));
+        }
+    }
+
+    if !checks.allows(&VersionIssue::BreakingChanges) && is_contract_enforced(model) {
+        let previous = versions.iter().rev().find(|other| {
+            other
+                .version
+                .as_ref()
+                .is_some_and(|other| compare_versions(other, version).is_lt())
+        });
+        if let Some(previous) = previous {
+            let previous_v = version_string(previous.version.as_ref().unwrap_or_default());
+            messages.extend(
+                breaking_changes(model, previous).into_iter().map(|change| {
+                    format!("Version {v} of {name} {change} of version {previous_v}")
+                }),
+            );
+        }
+    }
+    messages
+}
+
+// Check the versions of versioned models, and refs to deprecated versions
+pub fn model_versions(
+    node: &Node,
+    rule: &ManifestRule,
+    checks: &VersionChecks,
+    manifest: &Manifest,
+    today: &str,
+) -> Vec<RuleResult> {
+    let Node::Model(model) = node else {
+        return Vec::new();
+    };
+
+    let mut messages = version_messages(model, checks, manifest, today);
+    if !checks.allows(&VersionIssue::DeprecatedRefs) {
+        for parent in model.base.depends_on.nodes.iter().flatten() {
+            let Some(Node::Model(parent)) = manifest.nodes.get(parent) else {
+                continue;
+            };
+            if let (Some(version), Some(date)) = (&parent.version, &parent.deprecation_date) {
+                let status = if is_past(date, today) {
+                    "was deprecated"
+                } else {
+                    "is scheduled for deprecation"
+                };
+                messages.push(format!(
+                    "{} references version {} of {}, which {status} on {}",
+                    model.base.name,
+                    version_string(version),
+                    parent.base.name,
+                    date.get(..10).unwrap_or(date)
+                ));
+            }
+        }
+    }
+
+    messages
+        .into_iter()
+        .map(|message| {
+            RuleResult::new(
+                &rule.severity,
+                node.get_object_type(),
+                rule.get_name(),
+                message,
+                Some(node.get_relative_path().clone()),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::{manifest_rule::ManifestSpecificRuleConfig, severity::Severity};
+    use crate::core::rules::test_utils::{messages, ManifestBuilder};
+    use serde_json::json;
+
+    fn manifest() -> Manifest {
+        let columns = json!({
+            "customer_id": {"name": "customer_id", "data_type": "int", "tags": []},
+            "email": {"name": "email", "data_type": "varchar", "tags": []}
+        });
+        ManifestBuilder::default()
+            .model(
+                "dim_customers",
+                json!({
+                    "unique_id": "model.p.dim_customers.v1",
+                    "original_file_path": "models/dim_customers_v1.sql",
+                    "version": 1, "latest_version": 10, "deprecation_date": "2024-06-30T00:00:00",
+                    "columns": columns
+                }),
+            )
+            .model(
+                "dim_customers",
+                json!({
+                    "unique_id": "model.p.dim_customers.v2",
+                    "original_file_path": "models/dim_customers_v2.sql",
+                    "version": 2, "latest_version": 10,
+                    "columns": columns
+                }),
+            )
+            .model(
+                "dim_customers",
+                json!({
+                    "unique_id": "model.p.dim_customers.v10",
+                    "original_file_path": "models/dim_customers_v10.sql",
+                    "version": 10, "latest_version": 10,
+                    "config": {"contract": {"enforced": true, "alias_types": true}},
+                    "columns": {
+                        "customer_id": {"name": "customer_id", "data_type": "VARCHAR", "tags": []}
+                    }
+                }),
+            )
+            .model(
+                "orders",
+                json!({"depends_on": {"nodes": ["model.p.dim_customers.v1", "model.p.dim_customers.v10"]}}),
+            )
+            .build()
+    }
+
+    fn version_findings(unique_id: &str, checks: &VersionChecks) -> Vec<String> {
+        version_findings_on(unique_id, checks, "2025-01-01")
+    }
+
+    fn version_findings_on(unique_id: &str, checks: &VersionChecks, today: &str) -> Vec<String> {
+        let manifest = manifest();
+        let rule = ManifestRule::from_specific_rule(
+            ManifestSpecificRuleConfig::ModelVersions {
+                checks: VersionChecks::default(),
+            },
+            Severity::Warning,
+        );
+        messages(model_versions(
+            &manifest.nodes[unique_id],
+            &rule,
+            checks,
+            &manifest,
+            today,
+        ))
+    }
+
+    #[test]
+    fn test_model_versions_deprecation_dates() {
+        assert_eq!(
+            version_findings("model.p.dim_customers.v1", &VersionChecks::default()),
+            vec!["Version 1 of dim_customers was deprecated on 2024-06-30, remove it"]
+        );
+        assert_eq!(
+            version_findings("model.p.dim_customers.v2", &VersionChecks::default()),
+            vec![
+                "Version 2 of dim_customers has no deprecation_date, but the latest version is 10"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_model_versions_latest() {
+        let checks = VersionChecks {
+            max_live_versions: Some(1),
+            ..VersionChecks::default()
+        };
+        assert_eq!(
+            version_findings("model.p.dim_customers.v10", &checks),
+            vec![
+                "dim_customers has 2 live versions, more than 1",
+                "Version 10 of dim_customers changes the data type of column 'customer_id' from int to VARCHAR of version 2",
+                "Version 10 of dim_customers removes column 'email' of version 2",
+            ]
+        );
+        let checks = VersionChecks {
+            allow: vec![VersionIssue::BreakingChanges],
+            ..VersionChecks::default()
+        };
+        assert!(version_findings("model.p.dim_customers.v10", &checks).is_empty());
+    }
+
+    #[test]
+    fn test_model_versions_deprecated_refs() {
+        assert_eq!(
+            version_findings_on("model.p.orders", &VersionChecks::default(), "2024-01-01"),
+            vec![
+                "orders references version 1 of dim_customers, which is scheduled for deprecation on 2024-06-30"
+            ]
+        );
+        assert_eq!(
+            version_findings("model.p.orders", &VersionChecks::default()),
+            vec![
+                "orders references version 1 of dim_customers, which was deprecated on 2024-06-30"
+            ]
+        );
+        let checks = VersionChecks {
+            allow: vec![VersionIssue::DeprecatedRefs],
+            ..VersionChecks::default()
+        };
+        assert!(version_findings("model.p.orders", &checks).is_empty());
+    }
+}
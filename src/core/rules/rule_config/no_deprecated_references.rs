@@ -0,0 +1,209 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{
+    cli::table::RuleResult,
+    core::{
+        config::{check_config_options::DeprecatedReference, manifest_rule::ManifestRule},
+        manifest::{Manifest, Node},
+        rules::rule_config::has_refs::CanReference,
+    },
+};
+
+/// Today's date (UTC) as `YYYY-MM-DD`, the format `deprecation_date` starts with.
+pub fn today() -> String {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() / 86_400);
+    civil_date(days)
+}
+
+/// The `YYYY-MM-DD` date of a number of days since 1970-01-01 (H. Hinnant's algorithm).
+fn civil_date(days_since_epoch: u64) -> String {
+    let z = days_since_epoch + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Whether a `deprecation_date` (e.g. `2024-06-30T00:00:00`) is on or before `today`.
+pub fn is_past(deprecation_date: &str, today: &str) -> bool {
+    deprecation_date.get(..10).is_some_and(|date| date <= today)
+}
+
+/// A model version as written in the properties file, `1` rather than `1.0` or `"1"`.
+pub fn version_string(version: &serde_json::Value) -> String {
+    version
+        .as_str()
+        .map_or_else(|| version.to_string(), str::to_string)
+}
+
+/// Why a dependency is deprecated, with a hint on what to use instead.
+fn deprecation(
+    unique_id: &str,
+    deprecated: &[DeprecatedReference],
+    manifest: &Manifest,
+    today: &str,
+) -> Option<String> {
+    if let Some(reference) = deprecated
+        .iter()
+        .find(|reference| reference.unique_id.matches(unique_id))
+    {
+        return Some(
+            reference
+                .replacement
+                .as_ref()
+                .map_or_else(String::new, |replacement| {
+                    format!(", use {replacement} instead")
+                }),
+        );
+    }
+
+    let Some(Node::Model(model)) = manifest.nodes.get(unique_id) else {
+        return None;
+    };
+    let date = model.deprecation_date.as_deref()?;
+    if !is_past(date, today) {
+        return None;
+    }
+    let latest = model
+        .latest_version
+        .as_ref()
+        .filter(|latest| model.version.as_ref() != Some(*latest))
+        .map_or_else(String::new, |latest| {
+            format!(", use v{} instead", version_string(latest))
+        });
+    Some(format!(", deprecated since {}{latest}", &date[..10]))
+}
+
+// Check that an object does not depend on deprecated macros or nodes
+pub fn no_deprecated_references<T: CanReference>(
+    item: &T,
+    rule: &ManifestRule,
+    deprecated: &[DeprecatedReference],
+    manifest: &Manifest,
+    today: &str,
+) -> Vec<RuleResult> {
+    item.get_depends_on_nodes()
+        .iter()
+        .chain(item.get_depends_on_macros())
+        .filter_map(|unique_id| {
+            let reason = deprecation(unique_id, deprecated, manifest, today)?;
+            Some(RuleResult::new(
+                &rule.severity,
+                item.get_object_type(),
+                rule.get_name(),
+                format!(
+                    "{} depends on deprecated '{unique_id}'{reason}",
+                    item.get_object_string()
+                ),
+                item.get_relative_path().cloned(),
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::{
+        check_config_options::ColumnNamePattern, manifest_rule::ManifestSpecificRuleConfig,
+        severity::Severity,
+    };
+    use crate::core::rules::test_utils::{messages, ManifestBuilder};
+    use regex::Regex;
+    use serde_json::json;
+
+    fn manifest() -> Manifest {
+        ManifestBuilder::default()
+            .model(
+                "dim_customers",
+                json!({
+                    "unique_id": "model.p.dim_customers.v1",
+                    "original_file_path": "models/dim_customers_v1.sql",
+                    "version": 1, "latest_version": 2, "deprecation_date": "2024-06-30T00:00:00"
+                }),
+            )
+            .model(
+                "dim_customers",
+                json!({
+                    "unique_id": "model.p.dim_customers.v2",
+                    "original_file_path": "models/dim_customers_v2.sql",
+                    "version": 2, "latest_version": 2, "deprecation_date": "2099-01-01T00:00:00"
+                }),
+            )
+            .model(
+                "orders",
+                json!({
+                    "depends_on": {
+                        "nodes": ["model.p.dim_customers.v1", "model.p.dim_customers.v2", "model.p.legacy_payments"],
+                        "macros": ["macro.p.old_cents"]
+                    }
+                }),
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_today() {
+        let today = today();
+        assert_eq!(today.len(), 10);
+        assert!(today.as_str() > "2024-01-01");
+        assert!(is_past("2024-06-30T00:00:00", "2024-06-30"));
+        assert!(!is_past("2024-07-01 00:00:00", "2024-06-30"));
+    }
+
+    #[test]
+    fn test_civil_date() {
+        assert_eq!(civil_date(0), "1970-01-01");
+        // Leap days, 2000 is a leap year as it's divisible by 400
+        assert_eq!(civil_date(11_016), "2000-02-29");
+        assert_eq!(civil_date(19_782), "2024-02-29");
+        assert_eq!(civil_date(19_783), "2024-03-01");
+        // Year boundary
+        assert_eq!(civil_date(19_722), "2023-12-31");
+        assert_eq!(civil_date(19_723), "2024-01-01");
+    }
+
+    #[test]
+    fn test_no_deprecated_references() {
+        let manifest = manifest();
+        let deprecated = vec![
+            DeprecatedReference {
+                unique_id: ColumnNamePattern::Literal("macro.p.old_cents".to_string()),
+                replacement: Some("cents_to_dollars".to_string()),
+            },
+            DeprecatedReference {
+                unique_id: ColumnNamePattern::Regex(Regex::new(r"^model\.p\.legacy_.*").unwrap()),
+                replacement: None,
+            },
+        ];
+        let rule = ManifestRule::from_specific_rule(
+            ManifestSpecificRuleConfig::NoDeprecatedReferences {
+                deprecated: Vec::new(),
+            },
+            Severity::Warning,
+        );
+
+        assert_eq!(
+            messages(no_deprecated_references(
+                &manifest.nodes["model.p.orders"],
+                &rule,
+                &deprecated,
+                &manifest,
+                "2025-01-01",
+            )),
+            vec![
+                "orders depends on deprecated 'model.p.dim_customers.v1', deprecated since 2024-06-30, use v2 instead",
+                "orders depends on deprecated 'model.p.legacy_payments'",
+                "orders depends on deprecated 'macro.p.old_cents', use cents_to_dollars instead",
+            ]
+        );
+    }
+}
@@ -0,0 +1,293 @@
+use crate::{
+    cli::table::RuleResult,
+    core::{config::manifest_rule::ManifestRule, rules::rule_config::max_code_lines::HasCode},
+};
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'a> {
+    // Identifier or keyword, quoted identifiers keep their quotes
+    Word(&'a str),
+    Dot,
+    Comma,
+    Open,
+    Close,
+    Other,
+}
+
+/// A token with its line and column (both 1-based, the column counts chars).
+type Located<'a> = (Token<'a>, usize, usize);
+
+// Comments and Jinja are skipped until their closing delimiter
+const SKIPPED: [(&str, &str); 5] = [
+    ("--", "\n"),
+    ("/*", "*/"),
+    ("{{", "}}"),
+    ("{%", "%}"),
+    ("{#", "#}"),
+];
+
+// Keywords that end the relation list of a FROM clause
+const CLAUSE_KEYWORDS: [&str; 14] = [
+    "where",
+    "group",
+    "having",
+    "qualify",
+    "window",
+    "order",
+    "limit",
+    "union",
+    "except",
+    "intersect",
+    "select",
+    "on",
+    "using",
+    "set",
+];
+
+// Non-ASCII bytes are part of identifiers, so tokens always end on a char boundary
+const fn is_word_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'$' || !byte.is_ascii()
+}
+
+// The bytes after the first of a multi-byte char, they don't start a column
+const fn is_utf8_continuation(byte: u8) -> bool {
+    byte & 0b1100_0000 == 0b1000_0000
+}
+
+/// End (exclusive) of the quoted text starting at `start`.
+/// A doubled quote (`'it''s'`) is part of the text, backslashes are not escapes as in standard SQL.
+fn closing_quote(bytes: &[u8], start: usize) -> usize {
+    let quote = bytes[start];
+    let mut i = start + 1;
+    while i < bytes.len() {
+        if bytes[i] == quote {
+            if bytes.get(i + 1) != Some(&quote) {
+                return i + 1;
+            }
+            i += 1;
+        }
+        i += 1;
+    }
+    bytes.len()
+}
+
+/// Split SQL into the tokens relevant for finding relation names.
+/// Comments, string literals and Jinja are dropped.
+fn tokenize(sql: &str) -> Vec<Located<'_>> {
+    let bytes = sql.as_bytes();
+    let mut tokens = Vec::new();
+    let (mut line, mut column) = (1, 1);
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let rest = &sql[i..];
+        let end =
+            if let Some((open, close)) = SKIPPED.iter().find(|(open, _)| rest.starts_with(open)) {
+                // A line comment keeps its newline, so lines are still counted
+                let close_len = if *close == "\n" { 0 } else { close.len() };
+                rest[open.len()..]
+                    .find(close)
+                    .map_or(bytes.len(), |pos| i + open.len() + pos + close_len)
+            } else {
+                match bytes[i] {
+                    b'\'' => closing_quote(bytes, i),
+                    b'"' | b'`' => {
+                        let end = closing_quote(bytes, i);
+                        tokens.push((Token::Word(&sql[i..end]), line, column));
+                        end
+                    }
+                    byte if is_word_byte(byte) => {
+                        let end = bytes[i..]
+                            .iter()
+                            .position(|&byte| !is_word_byte(byte))
+                            .map_or(bytes.len(), |pos| i + pos);
+                        tokens.push((Token::Word(&sql[i..end]), line, column));
+                        end
+                    }
+                    byte => {
+                        if !byte.is_ascii_whitespace() {
+                            let token = match byte {
+                                b'.' => Token::Dot,
+                                b',' => Token::Comma,
+                                b'(' => Token::Open,
+                                b')' => Token::Close,
+                                _ => Token::Other,
+                            };
+                            tokens.push((token, line, column));
+                        }
+                        i + 1
+                    }
+                }
+            };
+
+        for byte in &bytes[i..end] {
+            if *byte == b'\n' {
+                line += 1;
+                column = 1;
+            } else if !is_utf8_continuation(*byte) {
+                column += 1;
+            }
+        }
+        i = end;
+    }
+    tokens
+}
+
+/// The `database.schema.table` name the tokens start with.
+/// Exactly three parts, more parts are not a relation.
+fn three_part_name(tokens: &[Located<'_>]) -> Option<(String, usize, usize)> {
+    match tokens {
+        [(Token::Word(database), line, column), (Token::Dot, ..), (Token::Word(schema), ..), (Token::Dot, ..), (Token::Word(table), ..), rest @ ..]
+            if !matches!(rest.first(), Some((Token::Dot, ..))) =>
+        {
+            Some((format!("{database}.{schema}.{table}"), *line, *column))
+        }
+        _ => None,
+    }
+}
+
+/// Fully qualified `database.schema.table` names selected from, with their line and column.
+/// Both the relation after FROM or JOIN and the ones after a comma in the FROM list count.
+fn hardcoded_relations(sql: &str) -> Vec<(String, usize, usize)> {
+    let tokens = tokenize(sql);
+    let mut relations = Vec::new();
+    // Per level of parentheses, whether its tokens are in a FROM list
+    let mut in_from = vec![false];
+
+    for (i, (token, _, _)) in tokens.iter().enumerate() {
+        let starts_relation = match token {
+            Token::Open => {
+                in_from.push(false);
+                false
+            }
+            Token::Close => {
+                if in_from.len() > 1 {
+                    in_from.pop();
+                }
+                false
+            }
+            Token::Comma => in_from.last() == Some(&true),
+            Token::Word(word) => {
+                let from = word.eq_ignore_ascii_case("from") || word.eq_ignore_ascii_case("join");
+                if from
+                    || CLAUSE_KEYWORDS
+                        .iter()
+                        .any(|keyword| word.eq_ignore_ascii_case(keyword))
+                {
+                    if let Some(level) = in_from.last_mut() {
+                        *level = from;
+                    }
+                }
+                from
+            }
+            Token::Dot | Token::Other => false,
+        };
+        if starts_relation {
+            relations.extend(three_part_name(&tokens[i + 1..]));
+        }
+    }
+    relations
+}
+
+/// Report every relation that is selected from by name instead of through `ref()` or `source()`.
+pub fn no_hardcoded_references<T: HasCode>(object: &T, rule: &ManifestRule) -> Vec<RuleResult> {
+    hardcoded_relations(object.get_code().unwrap_or_default())
+        .into_iter()
+        .map(|(relation, line, column)| {
+            RuleResult::new(
+                &rule.severity,
+                object.get_object_type(),
+                rule.get_name(),
+                format!(
+                    "{} selects from hard-coded relation '{relation}' on line {line}, use ref() or source() instead",
+                    object.get_name(),
+                ),
+                object.get_relative_path().cloned(),
+            )
+            .with_location(line, column)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hardcoded_relations() {
+        let sql = "select *\nfrom analytics.raw.orders o\n  left join \"DB\".\"Raw\".\"Customers\" c on o.id = c.id";
+        assert_eq!(
+            hardcoded_relations(sql),
+            vec![
+                ("analytics.raw.orders".to_string(), 2, 6),
+                ("\"DB\".\"Raw\".\"Customers\"".to_string(), 3, 13),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hardcoded_relations_skips_comments_strings_and_jinja() {
+        let sql = r"-- select * from db.schema.commented
+/* from db.schema.block
+   comment */
+select 'from db.schema.string' as s, 'it''s from db.schema.escaped'
+from {{ ref('orders') }}
+join {{ source('raw', 'customers') }} using (id)
+{% if is_incremental() %} where x > (select max(x) from {{ this }}) {% endif %}
+{# from db.schema.jinja_comment #}";
+        assert!(hardcoded_relations(sql).is_empty());
+    }
+
+    #[test]
+    fn test_hardcoded_relations_ignores_other_names() {
+        // Two part names, column references and struct fields
+        let sql = "select o.customer.address.city, extract(year from o.created_at)\nfrom raw.orders o\njoin a.b.c.d";
+        assert!(hardcoded_relations(sql).is_empty());
+    }
+
+    #[test]
+    fn test_hardcoded_relations_lines_after_multiline_skips() {
+        let sql = "select '\n'\n{{\n config()\n}}\n-- comment\nFROM db.s.t";
+        assert_eq!(hardcoded_relations(sql), vec![("db.s.t".to_string(), 7, 6)]);
+    }
+
+    #[test]
+    fn test_hardcoded_relations_quotes() {
+        // A backslash doesn't escape the quote, the string ends after it
+        let sql = r"select 'C:\' as dir from db.s.t";
+        assert_eq!(
+            hardcoded_relations(sql),
+            vec![("db.s.t".to_string(), 1, 26)]
+        );
+        // Doubled quotes in quoted identifiers
+        let sql = r#"select * from "my""db".s.t"#;
+        assert_eq!(
+            hardcoded_relations(sql),
+            vec![(r#""my""db".s.t"#.to_string(), 1, 15)]
+        );
+    }
+
+    #[test]
+    fn test_hardcoded_relations_column_counts_chars() {
+        let sql = "select 'café', 'Ünïcödé' from db.s.t";
+        assert_eq!(
+            hardcoded_relations(sql),
+            vec![("db.s.t".to_string(), 1, 31)]
+        );
+    }
+
+    #[test]
+    fn test_hardcoded_relations_from_list() {
+        let sql = "select a.x, b.y\nfrom db.s.a, db.s.b\njoin (select 1 as x, 2 as y from c) c on a.x = c.x, d\nwhere a.x in (1, db.s.z)";
+        assert_eq!(
+            hardcoded_relations(sql),
+            vec![("db.s.a".to_string(), 2, 6), ("db.s.b".to_string(), 2, 14)]
+        );
+        // A comma after a subquery in the FROM list
+        let sql = "select * from (select * from db.s.a) a, db.s.b";
+        assert_eq!(
+            hardcoded_relations(sql),
+            vec![("db.s.a".to_string(), 1, 30), ("db.s.b".to_string(), 1, 41)]
+        );
+    }
+}
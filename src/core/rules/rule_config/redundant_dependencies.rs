@@ -0,0 +1,189 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::{
+    cli::table::RuleResult,
+    core::{
+        config::manifest_rule::ManifestRule,
+        manifest::Manifest,
+        rules::rule_config::allowed_dependencies::{parents_of, short_name, Layered},
+    },
+};
+
+/// For every ancestor of the object reachable through one of its parents, the next object on
+/// the (shortest) path from that ancestor towards the object.
+fn ancestors_through_parents<'a>(
+    parents: &[&'a str],
+    manifest: &'a Manifest,
+) -> HashMap<&'a str, &'a str> {
+    let mut next_towards_object: HashMap<&str, &str> = HashMap::new();
+    let mut queue: VecDeque<&str> = parents.iter().copied().collect();
+    let mut expanded: HashSet<&str> = HashSet::new();
+
+    while let Some(current) = queue.pop_front() {
+        if !expanded.insert(current) {
+            continue;
+        }
+        for ancestor in parents_of(current, manifest) {
+            if !next_towards_object.contains_key(ancestor) {
+                next_towards_object.insert(ancestor, current);
+                queue.push_back(ancestor);
+            }
+        }
+    }
+    next_towards_object
+}
+
+/// Report direct dependencies that are redundant (A -> C while A -> B -> C exists).
+///
+/// When the object in between is used by nothing else, the upstream concept is rejoined and the
+/// two models could be merged.
+pub fn redundant_dependencies<T: Layered>(
+    object: &T,
+    rule: &ManifestRule,
+    exemptions: &[String],
+    manifest: &Manifest,
+) -> Vec<RuleResult> {
+    let mut parents = object.get_parents(manifest);
+    parents.sort_unstable();
+    parents.dedup();
+
+    let next_towards_object = ancestors_through_parents(&parents, manifest);
+
+    parents
+        .iter()
+        .filter(|parent| {
+            !exemptions
+                .iter()
+                .any(|exempt| exempt == *parent || exempt == short_name(parent))
+        })
+        .filter_map(|&parent| {
+            // Parents are never their own ancestor in a DAG, so any path is through another parent
+            let mut path = vec![parent];
+            let mut current = next_towards_object.get(parent)?;
+            path.push(current);
+            while let Some(next) = next_towards_object.get(current) {
+                path.push(next);
+                current = next;
+            }
+
+            let names: Vec<&str> = path.iter().map(|id| short_name(id)).collect();
+            let description = format!(
+                "{} '{}' depends on '{}' both directly and through {} -> {}",
+                object.get_object_type(),
+                object.get_object_string(),
+                names[0],
+                names.join(" -> "),
+                object.get_object_string(),
+            );
+
+            let in_between = path[1];
+            let only_child = manifest.child_map.get(in_between).is_some_and(|children| {
+                children
+                    .iter()
+                    .filter(|child| !child.starts_with("test."))
+                    .all(|child| child == object.get_unique_id())
+            });
+            let message = if path.len() == 2 && only_child {
+                format!(
+                    "{description}. '{}' is only used here, consider merging it into '{}'",
+                    names[1],
+                    object.get_object_string(),
+                )
+            } else {
+                format!("{description}. Remove the direct dependency")
+            };
+
+            Some(RuleResult::new(
+                &rule.severity,
+                object.get_object_type(),
+                rule.get_name(),
+                message,
+                object.get_relative_path().cloned(),
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::manifest_rule::ManifestSpecificRuleConfig;
+    use crate::core::config::severity::Severity;
+    use crate::core::rules::test_utils::{messages, ManifestBuilder};
+    use serde_json::json;
+
+    fn rule() -> ManifestRule {
+        ManifestRule::from_specific_rule(
+            ManifestSpecificRuleConfig::RedundantDependencies { exemptions: vec![] },
+            Severity::Error,
+        )
+    }
+
+    // stg_orders -> int_orders -> int_order_items -> fct_orders
+    // stg_orders -> fct_orders, stg_customers -> int_customers -> fct_orders, stg_customers -> fct_orders
+    fn manifest() -> Manifest {
+        ManifestBuilder::default()
+            .model("stg_orders", json!({}))
+            .model("stg_customers", json!({}))
+            .model(
+                "int_orders",
+                json!({"depends_on": {"nodes": ["model.p.stg_orders"]}}),
+            )
+            .model(
+                "int_order_items",
+                json!({"depends_on": {"nodes": ["model.p.int_orders"]}}),
+            )
+            .model(
+                "int_customers",
+                json!({"depends_on": {"nodes": ["model.p.stg_customers"]}}),
+            )
+            .model(
+                "fct_orders",
+                json!({
+                    "depends_on": {"nodes": [
+                        "model.p.int_order_items", "model.p.stg_orders",
+                        "model.p.int_customers", "model.p.stg_customers"
+                    ]}
+                }),
+            )
+            .with(
+                "child_map",
+                json!({
+                    "model.p.int_customers": ["model.p.fct_orders", "test.p.unique_int_customers_id"],
+                    "model.p.int_orders": ["model.p.int_order_items", "model.p.dim_orders"]
+                }),
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_redundant_dependencies() {
+        let manifest = manifest();
+        let node = manifest.get_node("model.p.fct_orders").unwrap();
+        let mut messages = messages(redundant_dependencies(node, &rule(), &[], &manifest));
+        messages.sort();
+
+        assert_eq!(
+            messages,
+            vec![
+                "Model 'fct_orders' depends on 'stg_customers' both directly and through stg_customers -> int_customers -> fct_orders. 'int_customers' is only used here, consider merging it into 'fct_orders'",
+                "Model 'fct_orders' depends on 'stg_orders' both directly and through stg_orders -> int_orders -> int_order_items -> fct_orders. Remove the direct dependency",
+            ]
+        );
+
+        // Upstream objects without other paths are fine
+        let node = manifest.get_node("model.p.int_order_items").unwrap();
+        assert!(redundant_dependencies(node, &rule(), &[], &manifest).is_empty());
+    }
+
+    #[test]
+    fn test_redundant_dependencies_exemptions() {
+        let manifest = manifest();
+        let node = manifest.get_node("model.p.fct_orders").unwrap();
+        let exemptions = vec![
+            "stg_customers".to_string(),
+            "model.p.stg_orders".to_string(),
+        ];
+        assert!(redundant_dependencies(node, &rule(), &exemptions, &manifest).is_empty());
+    }
+}
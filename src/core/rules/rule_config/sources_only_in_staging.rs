@@ -0,0 +1,186 @@
+use crate::{
+    cli::table::RuleResult,
+    core::{
+        config::{includes_excludes::glob_match, manifest_rule::ManifestRule},
+        manifest::{Manifest, Node},
+        rules::rule_config::allowed_dependencies::Layered,
+    },
+};
+
+fn is_staging<T: Layered + ?Sized>(object: &T, paths: &[String], prefixes: &[String]) -> bool {
+    let path = object.get_path().replace('\\', "/");
+    paths.iter().any(|pattern| glob_match(pattern, &path))
+        || prefixes
+            .iter()
+            .any(|prefix| object.get_object_string().starts_with(prefix))
+}
+
+/// Report every source a node outside of the staging layer selects from directly.
+pub fn source_outside_staging<T: Layered>(
+    node: &T,
+    rule: &ManifestRule,
+    staging_paths: &[String],
+    staging_prefixes: &[String],
+    manifest: &Manifest,
+) -> Vec<RuleResult> {
+    if is_staging(node, staging_paths, staging_prefixes) {
+        return Vec::new();
+    }
+
+    let mut parents = node.get_parents(manifest);
+    parents.sort_unstable();
+    parents.dedup();
+
+    parents
+        .into_iter()
+        .filter_map(|parent_id| manifest.get_source(parent_id))
+        .map(|source| {
+            RuleResult::new(
+                &rule.severity,
+                node.get_object_type(),
+                rule.get_name(),
+                format!(
+                    "{} '{}' selects directly from Source '{}.{}', sources should only be used in staging models",
+                    node.get_object_type(),
+                    node.get_object_string(),
+                    source.source_name,
+                    source.get_name(),
+                ),
+                node.get_relative_path().cloned(),
+            )
+        })
+        .collect()
+}
+
+/// Report a source that is consumed by more than one staging model.
+pub fn duplicate_staging_models<T: Layered>(
+    source: &T,
+    rule: &ManifestRule,
+    staging_paths: &[String],
+    staging_prefixes: &[String],
+    manifest: &Manifest,
+) -> Option<RuleResult> {
+    let mut staging_models: Vec<&str> = manifest
+        .child_map
+        .get(source.get_unique_id())
+        .into_iter()
+        .flatten()
+        .filter_map(|child_id| manifest.get_node(child_id))
+        .filter(|child| is_staging(*child, staging_paths, staging_prefixes))
+        .map(Node::get_object_string)
+        .collect();
+    staging_models.sort_unstable();
+    staging_models.dedup();
+
+    if staging_models.len() <= 1 {
+        return None;
+    }
+
+    Some(RuleResult::new(
+        &rule.severity,
+        source.get_object_type(),
+        rule.get_name(),
+        format!(
+            "{} '{}' is used by {} staging models: {}. Each source should have a single staging model",
+            source.get_object_type(),
+            source.get_object_string(),
+            staging_models.len(),
+            staging_models.join(", "),
+        ),
+        source.get_relative_path().cloned(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::check_config_options::default_staging_prefixes;
+    use crate::core::config::manifest_rule::ManifestSpecificRuleConfig;
+    use crate::core::config::severity::Severity;
+    use crate::core::rules::test_utils::ManifestBuilder;
+    use serde_json::json;
+
+    fn rule() -> ManifestRule {
+        ManifestRule::from_specific_rule(
+            ManifestSpecificRuleConfig::SourcesOnlyInStaging {
+                staging_paths: vec![],
+                staging_prefixes: default_staging_prefixes(),
+            },
+            Severity::Error,
+        )
+    }
+
+    fn manifest() -> Manifest {
+        ManifestBuilder::default()
+            .model(
+                "stg_orders",
+                json!({
+                    "original_file_path": "models/staging/stg_orders.sql",
+                    "depends_on": {"nodes": ["source.p.raw.orders"]}
+                }),
+            )
+            .model(
+                "base_orders",
+                json!({
+                    "original_file_path": "models/staging/base/base_orders.sql",
+                    "depends_on": {"nodes": ["source.p.raw.orders"]}
+                }),
+            )
+            .model(
+                "fct_orders",
+                json!({
+                    "original_file_path": "models/marts/fct_orders.sql",
+                    "depends_on": {"nodes": ["model.p.stg_orders", "source.p.raw.orders", "source.p.raw.orders"]}
+                }),
+            )
+            .source(
+                "raw",
+                "orders",
+                json!({"original_file_path": "models/staging/_sources.yml"}),
+            )
+            .with(
+                "child_map",
+                json!({
+                    "source.p.raw.orders": ["model.p.base_orders", "model.p.fct_orders", "model.p.stg_orders"]
+                }),
+            )
+            .build()
+    }
+
+    #[test]
+    fn test_source_outside_staging() {
+        let manifest = manifest();
+        let rule = rule();
+        let prefixes = default_staging_prefixes();
+
+        let staging = manifest.get_node("model.p.stg_orders").unwrap();
+        assert!(source_outside_staging(staging, &rule, &[], &prefixes, &manifest).is_empty());
+
+        let marts = manifest.get_node("model.p.fct_orders").unwrap();
+        let results = source_outside_staging(marts, &rule, &[], &prefixes, &manifest);
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].message,
+            "Model 'fct_orders' selects directly from Source 'raw.orders', sources should only be used in staging models"
+        );
+    }
+
+    #[test]
+    fn test_duplicate_staging_models() {
+        let manifest = manifest();
+        let rule = rule();
+        let source = manifest.get_source("source.p.raw.orders").unwrap();
+
+        // Only `stg_orders` is a staging model by prefix
+        let prefixes = default_staging_prefixes();
+        assert!(duplicate_staging_models(source, &rule, &[], &prefixes, &manifest).is_none());
+
+        // By path, `base_orders` is a staging model as well
+        let paths = vec!["models/staging/*".to_string()];
+        let result = duplicate_staging_models(source, &rule, &paths, &[], &manifest).unwrap();
+        assert_eq!(
+            result.message,
+            "Source 'orders' is used by 2 staging models: base_orders, stg_orders. Each source should have a single staging model"
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::BuildHasher;
+
+use crate::{
+    cli::table::RuleResult,
+    core::{
+        config::{check_config_options::ColumnNamePattern, manifest_rule::ManifestRule},
+        manifest::{Manifest, Node},
+        properties_file::{Property, PropertyRef},
+        rules::project::duplicate_descriptions::normalize_description,
+    },
+};
+
+/// Number of models with a column of each (lower-cased) name, counted once per run.
+pub fn models_per_column(manifest: &Manifest) -> HashMap<String, usize> {
+    let mut counts = HashMap::new();
+    for node in manifest.nodes.values() {
+        if !matches!(node, Node::Model(_)) {
+            continue;
+        }
+        let names: HashSet<String> = node
+            .get_base()
+            .columns
+            .iter()
+            .flat_map(|columns| columns.values())
+            .map(|column| column.name.to_lowercase())
+            .collect();
+        for name in names {
+            *counts.entry(name).or_default() += 1;
+        }
+    }
+    counts
+}
+
+/// The (normalized, lower-cased) contents of all doc blocks, counted once per run.
+pub fn doc_block_contents(manifest: &Manifest) -> HashSet<String> {
+    manifest
+        .docs
+        .values()
+        .filter_map(|block| normalize_description(Some(&block.block_contents)))
+        .map(|contents| contents.to_lowercase())
+        .collect()
+}
+
+// Check that selected columns take their description from a {% docs %} block
+pub fn uses_doc_blocks<S: BuildHasher>(
+    node: &Node,
+    rule: &ManifestRule,
+    models_per_column: &HashMap<String, usize, S>,
+    doc_block_contents: &HashSet<String, S>,
+    columns: &[ColumnNamePattern],
+    used_in_more_than: Option<usize>,
+) -> Vec<RuleResult> {
+    let mut without_doc_block: Vec<_> = node
+        .get_base()
+        .columns
+        .iter()
+        .flat_map(|columns| columns.values())
+        // Manifests before dbt 1.10 have no doc_blocks, then the rendered text is all we have
+        .filter(|column| {
+            column.doc_blocks.as_ref().map_or_else(
+                || {
+                    !normalize_description(column.description.as_ref()).is_some_and(|description| {
+                        doc_block_contents.contains(&description.to_lowercase())
+                    })
+                },
+                Vec::is_empty,
+            )
+        })
+        .collect();
+    without_doc_block.sort_by(|a, b| a.name.cmp(&b.name));
+
+    without_doc_block
+        .into_iter()
+        .filter_map(|column| {
+            let reason = if columns.iter().any(|pattern| pattern.matches(&column.name)) {
+                String::new()
+            } else {
+                let count = models_per_column
+                    .get(&column.name.to_lowercase())
+                    .copied()
+                    .unwrap_or_default();
+                if count <= used_in_more_than? {
+                    return None;
+                }
+                format!(", it appears in {count} models")
+            };
+            Some(
+                RuleResult::new(
+                    &rule.severity,
+                    node.get_object_type(),
+                    rule.get_name(),
+                    format!(
+                        "Column '{}' of {} does not use a doc block{reason}",
+                        column.name,
+                        node.get_name()
+                    ),
+                    Some(node.get_relative_path().clone()),
+                )
+                .with_property(PropertyRef::of_node(
+                    node,
+                    Property::Column(column.name.clone()),
+                )),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::{manifest_rule::ManifestSpecificRuleConfig, severity::Severity};
+    use crate::core::rules::test_utils::{messages, ManifestBuilder};
+    use serde_json::json;
+
+    fn manifest() -> Manifest {
+        ManifestBuilder::default()
+            .model(
+                "orders",
+                json!({
+                    "columns": {
+                        "order_id": {"name": "order_id", "description": "Id of the order", "tags": []},
+                        "customer_id": {"name": "customer_id", "description": "Id of the customer", "tags": []},
+                        "status": {
+                            "name": "status", "description": "Status", "tags": [],
+                            "doc_blocks": ["doc.p.order_status"]
+                        }
+                    }
+                }),
+            )
+            .model(
+                "customers",
+                json!({
+                    "columns": {
+                        "CUSTOMER_ID": {
+                            "name": "CUSTOMER_ID", "description": "Id of the customer", "tags": [],
+                            "doc_blocks": ["doc.p.customer_id"]
+                        }
+                    }
+                }),
+            )
+            .build()
+    }
+
+    fn missing_doc_blocks(
+        columns: &[ColumnNamePattern],
+        used_in_more_than: Option<usize>,
+    ) -> Vec<String> {
+        missing_doc_blocks_in(&manifest(), columns, used_in_more_than)
+    }
+
+    fn missing_doc_blocks_in(
+        manifest: &Manifest,
+        columns: &[ColumnNamePattern],
+        used_in_more_than: Option<usize>,
+    ) -> Vec<String> {
+        let rule = ManifestRule::from_specific_rule(
+            ManifestSpecificRuleConfig::UsesDocBlocks {
+                columns: Vec::new(),
+                used_in_more_than,
+            },
+            Severity::Error,
+        );
+        messages(uses_doc_blocks(
+            &manifest.nodes["model.p.orders"],
+            &rule,
+            &models_per_column(manifest),
+            &doc_block_contents(manifest),
+            columns,
+            used_in_more_than,
+        ))
+    }
+
+    #[test]
+    fn test_uses_doc_blocks_by_name() {
+        let columns = vec![
+            ColumnNamePattern::Literal("order_id".to_string()),
+            ColumnNamePattern::Literal("status".to_string()),
+        ];
+        assert_eq!(
+            missing_doc_blocks(&columns, None),
+            vec!["Column 'order_id' of orders does not use a doc block"]
+        );
+    }
+
+    #[test]
+    fn test_uses_doc_blocks_by_usage() {
+        assert_eq!(
+            missing_doc_blocks(&[], Some(1)),
+            vec!["Column 'customer_id' of orders does not use a doc block, it appears in 2 models"]
+        );
+        assert!(missing_doc_blocks(&[], Some(2)).is_empty());
+    }
+
+    #[test]
+    fn test_uses_doc_blocks_without_recorded_doc_blocks() {
+        // Only dbt 1.10 and later record doc_blocks, before that the text has to match a block
+        let column = |name: &str, doc_blocks: Option<Vec<&str>>| {
+            let mut column = json!({"name": name, "description": "Id of the order", "tags": []});
+            if let Some(doc_blocks) = doc_blocks {
+                column["doc_blocks"] = json!(doc_blocks);
+            }
+            column
+        };
+        let manifest = ManifestBuilder::default()
+            .model(
+                "orders",
+                json!({
+                    "columns": {
+                        "order_id": column("order_id", None),
+                        "order_key": column("order_key", Some(vec![]))
+                    }
+                }),
+            )
+            .doc("order_id", "Id of the\n order")
+            .build();
+        let columns = vec![
+            ColumnNamePattern::Literal("order_id".to_string()),
+            ColumnNamePattern::Literal("order_key".to_string()),
+        ];
+        assert_eq!(
+            missing_doc_blocks_in(&manifest, &columns, None),
+            vec!["Column 'order_key' of orders does not use a doc block"]
+        );
+    }
+}
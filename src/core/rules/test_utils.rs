@@ -0,0 +1,109 @@
+use crate::cli::table::RuleResult;
+use crate::core::manifest::Manifest;
+use serde_json::{json, Map, Value};
+
+/// Builds a manifest of package `p` for rule tests.
+/// Objects get the fields every object needs, the `fields` given are added or override them.
+#[must_use]
+pub struct ManifestBuilder {
+    manifest: Map<String, Value>,
+}
+
+impl Default for ManifestBuilder {
+    fn default() -> Self {
+        let mut manifest = Map::new();
+        manifest.insert(
+            "metadata".to_string(),
+            json!({"dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json"}),
+        );
+        Self { manifest }
+    }
+}
+
+impl ManifestBuilder {
+    /// Model `model.p.<name>` at `models/<name>.sql`
+    pub fn model(self, name: &str, fields: Value) -> Self {
+        self.node("model", name, fields)
+    }
+
+    /// Node `<resource_type>.p.<name>` at `models/<name>.sql`
+    pub fn node(self, resource_type: &str, name: &str, fields: Value) -> Self {
+        let defaults = json!({
+            "resource_type": resource_type,
+            "name": name,
+            "unique_id": format!("{resource_type}.p.{name}"),
+            "package_name": "p",
+            "original_file_path": format!("models/{name}.sql"),
+        });
+        self.object("nodes", defaults, fields)
+    }
+
+    /// Source `source.p.<source_name>.<name>` at `models/_sources.yml`
+    pub fn source(self, source_name: &str, name: &str, fields: Value) -> Self {
+        let defaults = json!({
+            "name": name,
+            "source_name": source_name,
+            "unique_id": format!("source.p.{source_name}.{name}"),
+            "package_name": "p",
+            "original_file_path": "models/_sources.yml",
+        });
+        self.object("sources", defaults, fields)
+    }
+
+    /// Macro `macro.p.<name>` at `macros/<name>.sql`
+    pub fn macro_(self, name: &str, fields: Value) -> Self {
+        let defaults = json!({
+            "name": name,
+            "unique_id": format!("macro.p.{name}"),
+            "package_name": "p",
+            "original_file_path": format!("macros/{name}.sql"),
+            "macro_sql": "",
+        });
+        self.object("macros", defaults, fields)
+    }
+
+    /// Doc block `doc.p.<name>` in `models/docs.md`
+    pub fn doc(self, name: &str, block_contents: &str) -> Self {
+        let defaults = json!({
+            "name": name,
+            "resource_type": "doc",
+            "unique_id": format!("doc.p.{name}"),
+            "package_name": "p",
+            "path": "docs.md",
+            "original_file_path": "models/docs.md",
+            "block_contents": block_contents,
+        });
+        self.object("docs", defaults, json!({}))
+    }
+
+    /// Set a top level field, e.g. `child_map`
+    pub fn with(mut self, key: &str, value: Value) -> Self {
+        self.manifest.insert(key.to_string(), value);
+        self
+    }
+
+    /// # Panics
+    /// Panics if the objects don't deserialize, e.g. when a required field is missing
+    pub fn build(self) -> Manifest {
+        serde_json::from_value(Value::Object(self.manifest)).unwrap()
+    }
+
+    fn object(mut self, collection: &str, defaults: Value, fields: Value) -> Self {
+        let (Value::Object(mut object), Value::Object(fields)) = (defaults, fields) else {
+            panic!("Objects and their fields must be JSON objects");
+        };
+        object.extend(fields);
+        let unique_id = object["unique_id"].as_str().unwrap().to_string();
+        self.manifest
+            .entry(collection)
+            .or_insert_with(|| json!({}))
+            .as_object_mut()
+            .unwrap()
+            .insert(unique_id, Value::Object(object));
+        self
+    }
+}
+
+pub fn messages(results: Vec<RuleResult>) -> Vec<String> {
+    results.into_iter().map(|result| result.message).collect()
+}
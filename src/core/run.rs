@@ -5,6 +5,7 @@ use crate::core::config::parse_config::resolve_config_path;
 use crate::core::config::severity::Severity;
 use crate::core::config::Config;
 use crate::core::manifest::Manifest;
+use crate::core::properties_file::PropertiesFiles;
 use crate::core::rules::catalog::{
     apply_catalog_node_rules::apply_catalog_node_rules,
     apply_catalog_source_rules::apply_catalog_source_rules,
@@ -13,35 +14,39 @@ use crate::core::rules::manifest::{
     apply_manifest_node_rules::apply_manifest_node_rules,
     apply_other_manifest_object_rules::apply_manifest_object_rules,
 };
+use crate::core::rules::project::apply_project_rules::apply_project_rules;
 use crate::core::utils::unwrap_or_exit;
+use crate::core::watch::watch_files;
 use log::debug;
+use owo_colors::OwoColorize;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
 use std::time::Instant;
 
 #[must_use]
 pub fn run(options: &RunOptions, verbose: bool) -> i32 {
-    let start = Instant::now();
-
     let config_path = resolve_config_path(&options.entry_point, options.config_file.as_ref());
-    let config = unwrap_or_exit(Config::from_file(config_path));
 
-    debug!("Loaded configuration: {config:#?}");
+    if options.watch {
+        return watch(options, &config_path, verbose);
+    }
+    unwrap_or_exit(analyze(options, &config_path, verbose))
+}
 
-    // Store all findings in a result vector
-    let mut findings: Vec<(RuleResult, &Severity)> = Vec::new();
+/// Run the analysis once and show the results table.
+///
+/// # Errors
+/// Returns an error if the config, manifest or catalog can't be loaded or a rule is misconfigured.
+fn analyze(options: &RunOptions, config_path: &str, verbose: bool) -> anyhow::Result<i32> {
+    let start = Instant::now();
 
-    // Manifest-based rules
-    let manifest_path =
-        std::path::PathBuf::from(format!("{}/{}", options.entry_point, options.manifest_file));
-    let manifest = unwrap_or_exit(Manifest::from_file(&manifest_path));
+    let config = Config::from_file(config_path)?;
 
-    // Manifest-node object rules
-    findings.extend(unwrap_or_exit(apply_manifest_node_rules(
-        &manifest, &config, verbose,
-    )));
-    // Manifest-non-node object rules (source macro exposures semantic_models unit_tests)
-    findings.extend(unwrap_or_exit(apply_manifest_object_rules(
-        &manifest, &config, verbose,
-    )));
+    debug!("Loaded configuration: {config:#?}");
+
+    let manifest_path = PathBuf::from(format!("{}/{}", options.entry_point, options.manifest_file));
+    let manifest = Manifest::from_file(&manifest_path)?;
 
     // Catalog-based rules (need both manifest and catalog)
     // This can error in the following case:
@@ -51,25 +56,106 @@ pub fn run(options: &RunOptions, verbose: bool) -> i32 {
         None
     } else {
         let catalog_path =
-            std::path::PathBuf::from(format!("{}/{}", options.entry_point, options.catalog_file));
-        Some(unwrap_or_exit(Catalog::from_file(&catalog_path)))
+            PathBuf::from(format!("{}/{}", options.entry_point, options.catalog_file));
+        Some(Catalog::from_file(&catalog_path)?)
     };
 
-    if let Some(ref catalog) = catalog {
-        findings.extend(unwrap_or_exit(apply_catalog_node_rules(
-            &config, catalog, &manifest, verbose,
-        )));
-        findings.extend(unwrap_or_exit(apply_catalog_source_rules(
-            &config, catalog, &manifest, verbose,
-        )));
-    }
+    let findings = collect_findings(
+        &config,
+        &manifest,
+        catalog.as_ref(),
+        Path::new(&options.entry_point),
+        verbose,
+    )?;
 
-    show_results_and_exit(
+    Ok(show_results_and_exit(
         &findings,
         verbose,
         options.entry_point.as_ref(),
         options.disable_hyperlinks,
         options.hide_warnings,
         Some(start.elapsed()),
-    )
+    ))
+}
+
+/// Apply all manifest and project rules, and the catalog rules when a catalog is given.
+/// Findings about properties are located in the properties files under `entry_point`.
+///
+/// # Errors
+/// Returns an error if a rule has invalid configuration (e.g., invalid regex pattern).
+pub fn collect_findings<'a>(
+    config: &'a Config,
+    manifest: &'a Manifest,
+    catalog: Option<&'a Catalog>,
+    entry_point: &Path,
+    verbose: bool,
+) -> anyhow::Result<Vec<(RuleResult, &'a Severity)>> {
+    // Store all findings in a result vector
+    let mut findings: Vec<(RuleResult, &Severity)> = Vec::new();
+
+    // Manifest-node object rules
+    findings.extend(apply_manifest_node_rules(manifest, config, verbose)?);
+    // Manifest-non-node object rules (source macro exposures semantic_models unit_tests)
+    findings.extend(apply_manifest_object_rules(manifest, config, verbose)?);
+    // Project rules about all models at once
+    findings.extend(apply_project_rules(manifest, config));
+
+    if let Some(catalog) = catalog {
+        findings.extend(apply_catalog_node_rules(
+            config, catalog, manifest, verbose,
+        )?);
+        findings.extend(apply_catalog_source_rules(
+            config, catalog, manifest, verbose,
+        )?);
+    }
+
+    let files = PropertiesFiles::read(
+        entry_point,
+        findings
+            .iter()
+            .filter_map(|(result, _)| result.property.as_ref())
+            .map(|property| property.file.as_str()),
+    );
+    for (result, _) in &mut findings {
+        result.resolve_location(&files);
+    }
+    Ok(findings)
+}
+
+/// Re-run the analysis whenever the manifest, catalog or config file changes.
+/// Runs until interrupted, errors (e.g. a manifest that is still being written) are shown and watching continues.
+fn watch(options: &RunOptions, config_path: &str, verbose: bool) -> i32 {
+    let entry_point = Path::new(&options.entry_point);
+    let mut files = vec![
+        PathBuf::from(config_path),
+        entry_point.join(&options.manifest_file),
+    ];
+    if !options.only_manifest {
+        files.push(entry_point.join(&options.catalog_file));
+    }
+
+    let (sender, receiver) = mpsc::channel();
+    let _watcher = unwrap_or_exit(watch_files(&files, move || {
+        sender.send(()).ok();
+    }));
+
+    redraw(options, config_path, verbose);
+    for () in receiver {
+        redraw(options, config_path, verbose);
+    }
+    0
+}
+
+fn redraw(options: &RunOptions, config_path: &str, verbose: bool) {
+    // Clear the screen and move the cursor to the top left
+    print!("\x1B[2J\x1B[1;1H");
+    std::io::stdout().flush().ok();
+    if let Err(err) = analyze(options, config_path, verbose) {
+        eprintln!("{}", err.to_string().red());
+    }
+    println!(
+        "\n{}",
+        "Watching for changes to the manifest, catalog and config... (press Ctrl+C to stop)"
+            .dimmed()
+    );
 }
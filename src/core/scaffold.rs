@@ -0,0 +1,171 @@
+use crate::cli::commands::ScaffoldOptions;
+use crate::core::catalog::Catalog;
+use crate::core::fix::{add_to_plan, apply_plan, write_change, FixPlan};
+use crate::core::manifest::dbt_objects::Column;
+use crate::core::manifest::{Manifest, Node};
+use crate::core::properties_file::{node_target, ColumnEdit, ObjectEdit, ObjectPath};
+use crate::core::utils::unwrap_or_exit;
+use dbt_artifact_parser::catalog::{CatalogColumn, CatalogResourceMetadata};
+use log::debug;
+use owo_colors::OwoColorize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+#[must_use]
+pub fn scaffold(options: &ScaffoldOptions, verbose: bool) -> i32 {
+    let manifest_path = PathBuf::from(format!("{}/{}", options.entry_point, options.manifest_file));
+    let manifest = unwrap_or_exit(Manifest::from_file(&manifest_path));
+
+    let catalog_path = PathBuf::from(format!("{}/{}", options.entry_point, options.catalog_file));
+    let catalog = unwrap_or_exit(Catalog::from_file(&catalog_path));
+
+    let plan = plan_scaffold(&manifest, &catalog);
+    if verbose {
+        debug!("Planned scaffolds: {plan:#?}");
+    }
+
+    let changes = unwrap_or_exit(apply_plan(Path::new(&options.entry_point), &plan));
+    if changes.is_empty() {
+        println!(
+            "{}",
+            "Nothing to scaffold, all objects are documented.".green()
+        );
+        return 0;
+    }
+
+    for change in &changes {
+        if options.dry_run {
+            print!("{}", change.unified_diff());
+        } else {
+            unwrap_or_exit(write_change(change));
+            println!("{} {}", "Scaffolded".green(), change.file);
+        }
+    }
+
+    if options.dry_run {
+        println!(
+            "\n{} file(s) would be changed. Run without --dry-run to write the files.",
+            changes.len()
+        );
+    } else {
+        println!("\n{} file(s) changed.", changes.len());
+    }
+    0
+}
+
+/// Plan properties entries for models, seeds, snapshots and sources without documented columns.
+///
+/// Columns come from the catalog, including their data type and, where present, their comment as description.
+pub fn plan_scaffold(manifest: &Manifest, catalog: &Catalog) -> FixPlan {
+    let mut plan = FixPlan::new();
+    // Snowflake reports upper case names, dbt projects use lower case
+    let lowercase_names = manifest
+        .metadata
+        .adapter_type
+        .as_ref()
+        .is_some_and(|adapter| adapter.eq_ignore_ascii_case("snowflake"));
+    let is_project_object = |package_name: &String| {
+        manifest
+            .metadata
+            .project_name
+            .as_ref()
+            .is_none_or(|project| project == package_name)
+    };
+
+    let mut nodes: Vec<&Node> = manifest
+        .nodes
+        .values()
+        .filter(|node| is_project_object(node.get_package_name()))
+        .collect();
+    nodes.sort_by_key(|node| node.get_unique_id());
+
+    for node in nodes {
+        let Some(catalog_node) = catalog.nodes.get(node.get_unique_id()) else {
+            continue;
+        };
+        if !is_undocumented(node.get_base().columns.as_ref()) {
+            continue;
+        }
+        let Some((file, path)) = node_target(node) else {
+            continue;
+        };
+
+        let description = node.get_base().description.as_deref();
+        let edit = scaffold_edit(
+            description,
+            &catalog_node.get_base().metadata,
+            &catalog_node.get_base().columns,
+            lowercase_names,
+        );
+        add_to_plan(&mut plan, file, path, edit);
+    }
+
+    let mut sources: Vec<_> = manifest
+        .sources
+        .values()
+        .filter(|source| is_project_object(source.get_package_name()))
+        .collect();
+    sources.sort_by_key(|source| source.get_unique_id());
+
+    for source in sources {
+        let Some(catalog_source) = catalog.sources.get(source.get_unique_id()) else {
+            continue;
+        };
+        if !is_undocumented(source.columns.as_ref()) {
+            continue;
+        }
+
+        let path = ObjectPath::SourceTable {
+            source_name: source.source_name.clone(),
+            table_name: source.name.clone(),
+        };
+        let edit = scaffold_edit(
+            source.description.as_deref(),
+            &catalog_source.metadata,
+            &catalog_source.columns,
+            lowercase_names,
+        );
+        add_to_plan(&mut plan, source.get_relative_path().clone(), path, edit);
+    }
+
+    plan
+}
+
+fn is_undocumented(columns: Option<&HashMap<String, Column>>) -> bool {
+    columns.is_none_or(HashMap::is_empty)
+}
+
+fn non_empty(value: Option<&str>) -> Option<String> {
+    value
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(ToString::to_string)
+}
+
+fn scaffold_edit(
+    description: Option<&str>,
+    metadata: &CatalogResourceMetadata,
+    catalog_columns: &HashMap<String, CatalogColumn>,
+    lowercase_names: bool,
+) -> ObjectEdit {
+    let mut ordered_columns: Vec<&CatalogColumn> = catalog_columns.values().collect();
+    ordered_columns.sort_by_key(|column| column.index);
+
+    ObjectEdit {
+        // The manifest description wins over the table comment
+        description: non_empty(description).or_else(|| non_empty(metadata.comment.as_deref())),
+        columns: ordered_columns
+            .into_iter()
+            .map(|column| ColumnEdit {
+                name: if lowercase_names {
+                    column.name.to_lowercase()
+                } else {
+                    column.name.clone()
+                },
+                data_type: Some(column.type_.clone()),
+                description: non_empty(column.comment.as_deref()),
+            })
+            .collect(),
+        ..ObjectEdit::default()
+    }
+}
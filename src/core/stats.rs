@@ -0,0 +1,436 @@
+use crate::cli::commands::StatsOptions;
+use crate::cli::table::RuleResult;
+use crate::core::catalog::Catalog;
+use crate::core::config::parse_config::resolve_config_path;
+use crate::core::config::severity::Severity;
+use crate::core::config::Config;
+use crate::core::manifest::Manifest;
+use crate::core::rules::project::apply_project_rules::project_models;
+use crate::core::rules::project::coverage::{is_documented, Coverage};
+use crate::core::run::collect_findings;
+use crate::core::utils::unwrap_or_exit;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use tabled::builder::Builder;
+use tabled::settings::Style;
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct CoverageStats {
+    pub covered: usize,
+    pub total: usize,
+    pub percent: f64,
+}
+
+impl From<Coverage> for CoverageStats {
+    fn from(coverage: Coverage) -> Self {
+        Self {
+            covered: coverage.covered,
+            total: coverage.total,
+            percent: coverage.percent(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct Ranked {
+    pub name: String,
+    pub count: usize,
+}
+
+/// Health report of a dbt project, models of installed packages are not counted.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct Stats {
+    pub models: usize,
+    pub models_per_materialization: BTreeMap<String, usize>,
+    pub models_per_layer: BTreeMap<String, usize>,
+    pub documented_models: CoverageStats,
+    pub documented_columns: CoverageStats,
+    pub tested_models: CoverageStats,
+    pub exposures: usize,
+    pub unit_tests: usize,
+    pub semantic_models: usize,
+    /// Models with the most lines of `raw_code`
+    pub largest_models: Vec<Ranked>,
+    /// Objects with the most children (tests not counted)
+    pub most_depended_on: Vec<Ranked>,
+    pub findings_per_rule: Vec<Ranked>,
+}
+
+#[must_use]
+pub fn stats(options: &StatsOptions, verbose: bool) -> i32 {
+    let config_path = resolve_config_path(&options.entry_point, options.config_file.as_ref());
+    let config = unwrap_or_exit(Config::from_file(config_path));
+
+    let manifest_path = PathBuf::from(format!("{}/{}", options.entry_point, options.manifest_file));
+    let manifest = unwrap_or_exit(Manifest::from_file(&manifest_path));
+
+    let catalog = if options.only_manifest {
+        None
+    } else {
+        let catalog_path =
+            PathBuf::from(format!("{}/{}", options.entry_point, options.catalog_file));
+        Some(unwrap_or_exit(Catalog::from_file(&catalog_path)))
+    };
+
+    let findings = unwrap_or_exit(collect_findings(
+        &config,
+        &manifest,
+        catalog.as_ref(),
+        Path::new(&options.entry_point),
+        verbose,
+    ));
+
+    let stats = collect_stats(&manifest, &findings, options.top);
+    match options.format.as_str() {
+        "json" => println!(
+            "{}",
+            unwrap_or_exit(serde_json::to_string_pretty(&stats).map_err(anyhow::Error::from))
+        ),
+        "markdown" => print!("{}", render_markdown(&stats)),
+        _ => print!("{}", render_table(&stats)),
+    }
+    0
+}
+
+// The layer of a model is the first directory below the model path, e.g. `staging`
+fn layer_of(path: &str) -> &str {
+    let directories: Vec<&str> = path.split('/').collect();
+    if directories.len() > 2 {
+        directories[1]
+    } else {
+        "(root)"
+    }
+}
+
+fn top(counts: impl IntoIterator<Item = (String, usize)>, limit: usize) -> Vec<Ranked> {
+    let mut ranked: Vec<Ranked> = counts
+        .into_iter()
+        .filter(|(_, count)| *count > 0)
+        .map(|(name, count)| Ranked { name, count })
+        .collect();
+    ranked.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.name.cmp(&b.name)));
+    ranked.truncate(limit);
+    ranked
+}
+
+/// Summarize the manifest and the findings of a run, listing at most `limit` objects per ranking.
+pub fn collect_stats(
+    manifest: &Manifest,
+    findings: &[(RuleResult, &Severity)],
+    limit: usize,
+) -> Stats {
+    let models = project_models(manifest);
+    let tests_by_parent = manifest.get_tests_by_attached_node();
+
+    let mut models_per_materialization: BTreeMap<String, usize> = BTreeMap::new();
+    let mut models_per_layer: BTreeMap<String, usize> = BTreeMap::new();
+    let (mut documented_models, mut documented_columns, mut tested_models) = (
+        Coverage::default(),
+        Coverage::default(),
+        Coverage::default(),
+    );
+
+    for model in &models {
+        let materialization = model
+            .get_materialization()
+            .map_or_else(|| "unknown".to_string(), ToString::to_string);
+        *models_per_materialization
+            .entry(materialization)
+            .or_default() += 1;
+        *models_per_layer
+            .entry(layer_of(model.get_relative_path()).to_string())
+            .or_default() += 1;
+
+        documented_models.add(is_documented(model.get_base().description.as_ref()));
+        for column in model.get_base().columns.iter().flat_map(HashMap::values) {
+            documented_columns.add(is_documented(column.description.as_ref()));
+        }
+        tested_models.add(
+            tests_by_parent
+                .get(model.get_unique_id().as_str())
+                .is_some_and(|tests| !tests.is_empty()),
+        );
+    }
+
+    let largest_models = top(
+        models.iter().map(|model| {
+            let lines = model
+                .get_base()
+                .raw_code
+                .as_deref()
+                .map_or(0, |code| code.lines().count());
+            (model.get_unique_id().clone(), lines)
+        }),
+        limit,
+    );
+
+    let most_depended_on = top(
+        manifest
+            .child_map
+            .iter()
+            .filter(|(unique_id, _)| !unique_id.starts_with("test."))
+            .map(|(unique_id, children)| {
+                let children = children
+                    .iter()
+                    .filter(|child| !child.starts_with("test."))
+                    .count();
+                (unique_id.clone(), children)
+            }),
+        limit,
+    );
+
+    let mut findings_per_rule: HashMap<String, usize> = HashMap::new();
+    for (result, _) in findings {
+        *findings_per_rule
+            .entry(result.rule_name.clone())
+            .or_default() += 1;
+    }
+
+    Stats {
+        models: models.len(),
+        models_per_materialization,
+        models_per_layer,
+        documented_models: documented_models.into(),
+        documented_columns: documented_columns.into(),
+        tested_models: tested_models.into(),
+        exposures: manifest.exposures.len(),
+        unit_tests: manifest.unit_tests.len(),
+        semantic_models: manifest.semantic_models.len(),
+        largest_models,
+        most_depended_on,
+        findings_per_rule: top(findings_per_rule, limit),
+    }
+}
+
+fn coverage_cell(coverage: &CoverageStats) -> String {
+    format!(
+        "{:.1}% ({} of {})",
+        coverage.percent, coverage.covered, coverage.total
+    )
+}
+
+/// Title, header and rows of a section of the report.
+type Section = (&'static str, [&'static str; 2], Vec<[String; 2]>);
+
+fn sections(stats: &Stats) -> Vec<Section> {
+    let counts = |counts: &BTreeMap<String, usize>| {
+        counts
+            .iter()
+            .map(|(name, count)| [name.clone(), count.to_string()])
+            .collect()
+    };
+    let ranked = |ranked: &[Ranked]| {
+        ranked
+            .iter()
+            .map(|ranked| [ranked.name.clone(), ranked.count.to_string()])
+            .collect()
+    };
+
+    vec![
+        (
+            "Overview",
+            ["Metric", "Value"],
+            vec![
+                ["Models".to_string(), stats.models.to_string()],
+                [
+                    "Documented models".to_string(),
+                    coverage_cell(&stats.documented_models),
+                ],
+                [
+                    "Documented columns".to_string(),
+                    coverage_cell(&stats.documented_columns),
+                ],
+                [
+                    "Tested models".to_string(),
+                    coverage_cell(&stats.tested_models),
+                ],
+                ["Exposures".to_string(), stats.exposures.to_string()],
+                ["Unit tests".to_string(), stats.unit_tests.to_string()],
+                [
+                    "Semantic models".to_string(),
+                    stats.semantic_models.to_string(),
+                ],
+            ],
+        ),
+        (
+            "Models per materialization",
+            ["Materialization", "Models"],
+            counts(&stats.models_per_materialization),
+        ),
+        (
+            "Models per layer",
+            ["Layer", "Models"],
+            counts(&stats.models_per_layer),
+        ),
+        (
+            "Largest models",
+            ["Model", "Lines of code"],
+            ranked(&stats.largest_models),
+        ),
+        (
+            "Most depended-on objects",
+            ["Object", "Children"],
+            ranked(&stats.most_depended_on),
+        ),
+        (
+            "Findings per rule",
+            ["Rule", "Findings"],
+            ranked(&stats.findings_per_rule),
+        ),
+    ]
+}
+
+// Sections without rows are left out
+fn tables(stats: &Stats) -> impl Iterator<Item = (&'static str, tabled::Table)> {
+    sections(stats)
+        .into_iter()
+        .filter(|(_, _, rows)| !rows.is_empty())
+        .map(|(title, header, rows)| {
+            let mut builder = Builder::default();
+            builder.push_record(header);
+            for row in rows {
+                builder.push_record(row);
+            }
+            (title, builder.build())
+        })
+}
+
+/// The report as tables for the terminal.
+pub fn render_table(stats: &Stats) -> String {
+    tables(stats)
+        .map(|(title, mut table)| {
+            table.with(Style::modern());
+            format!("\n{}\n{table}\n", title.bold())
+        })
+        .collect::<Vec<_>>()
+        .concat()
+}
+
+/// The report as a Markdown document, e.g. for a pull request comment.
+pub fn render_markdown(stats: &Stats) -> String {
+    let sections = tables(stats)
+        .map(|(title, mut table)| {
+            table.with(Style::markdown());
+            format!("\n## {title}\n\n{table}\n")
+        })
+        .collect::<Vec<_>>()
+        .concat();
+    format!("# dbtective stats\n{sections}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest() -> Manifest {
+        serde_json::from_str(
+            r#"{
+  "metadata": {"dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json", "project_name": "p"},
+  "nodes": {
+    "model.p.stg_orders": {
+      "resource_type": "model", "name": "stg_orders", "unique_id": "model.p.stg_orders", "package_name": "p",
+      "original_file_path": "models/staging/stg_orders.sql", "description": "Orders",
+      "config": {"materialized": "view"}, "raw_code": "select 1\nfrom a",
+      "columns": {"id": {"name": "id", "description": "Id", "tags": []}}
+    },
+    "model.p.orders": {
+      "resource_type": "model", "name": "orders", "unique_id": "model.p.orders", "package_name": "p",
+      "original_file_path": "models/orders.sql", "config": {"materialized": "table"},
+      "raw_code": "select 1\nfrom a\nwhere b"
+    },
+    "model.dbt_utils.utils_model": {
+      "resource_type": "model", "name": "utils_model", "unique_id": "model.dbt_utils.utils_model",
+      "package_name": "dbt_utils", "original_file_path": "models/utils_model.sql"
+    },
+    "test.p.unique_stg_orders_id": {
+      "resource_type": "test", "name": "unique_stg_orders_id", "unique_id": "test.p.unique_stg_orders_id",
+      "package_name": "p", "attached_node": "model.p.stg_orders"
+    }
+  },
+  "child_map": {
+    "model.p.stg_orders": ["model.p.orders", "test.p.unique_stg_orders_id"],
+    "model.p.orders": []
+  }
+}"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_collect_stats() {
+        let manifest = manifest();
+        let findings = vec![
+            (
+                RuleResult::new(&Severity::Error, "Model", "has_description", "", None),
+                &Severity::Error,
+            ),
+            (
+                RuleResult::new(&Severity::Error, "Model", "has_description", "", None),
+                &Severity::Error,
+            ),
+            (
+                RuleResult::new(&Severity::Warning, "Model", "has_tags", "", None),
+                &Severity::Warning,
+            ),
+        ];
+
+        let stats = collect_stats(&manifest, &findings, 1);
+        assert_eq!(stats.models, 2);
+        assert_eq!(
+            stats.models_per_materialization,
+            BTreeMap::from([("table".to_string(), 1), ("view".to_string(), 1)])
+        );
+        assert_eq!(
+            stats.models_per_layer,
+            BTreeMap::from([("(root)".to_string(), 1), ("staging".to_string(), 1)])
+        );
+        assert_eq!(
+            stats.documented_models,
+            CoverageStats {
+                covered: 1,
+                total: 2,
+                percent: 50.0
+            }
+        );
+        assert_eq!(
+            stats.documented_columns.covered,
+            stats.documented_columns.total
+        );
+        assert_eq!(stats.tested_models.covered, 1);
+        assert_eq!(
+            stats.largest_models,
+            vec![Ranked {
+                name: "model.p.orders".to_string(),
+                count: 3
+            }]
+        );
+        assert_eq!(
+            stats.most_depended_on,
+            vec![Ranked {
+                name: "model.p.stg_orders".to_string(),
+                count: 1
+            }]
+        );
+        assert_eq!(
+            stats.findings_per_rule,
+            vec![Ranked {
+                name: "has_description".to_string(),
+                count: 2
+            }]
+        );
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let manifest = manifest();
+        let markdown = render_markdown(&collect_stats(&manifest, &[], 10));
+
+        assert!(markdown.starts_with("# dbtective stats\n"));
+        assert!(markdown.contains("## Models per layer"));
+        assert!(markdown.contains("| Documented models  | 50.0% (1 of 2)  |"));
+        // Sections without rows are left out
+        assert!(!markdown.contains("## Findings per rule"));
+    }
+}
@@ -0,0 +1,120 @@
+use anyhow::Context;
+use notify_debouncer_full::notify::{EventKind, RecommendedWatcher, RecursiveMode};
+use notify_debouncer_full::{new_debouncer, DebounceEventResult, Debouncer, RecommendedCache};
+use owo_colors::OwoColorize;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+// dbt writes the manifest in chunks, wait for writes to settle before re-running
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Watches files until dropped
+pub type FileWatcher = Debouncer<RecommendedWatcher, RecommendedCache>;
+
+/// Call `on_change` whenever one of `files` is created, modified or removed (debounced).
+///
+/// # Errors
+/// Returns an error if the watcher can't be started or a directory of the files doesn't exist.
+pub fn watch_files<F>(files: &[PathBuf], mut on_change: F) -> anyhow::Result<FileWatcher>
+where
+    F: FnMut() + Send + 'static,
+{
+    let watched_files: Vec<PathBuf> = files.iter().map(|file| watched_path(file)).collect();
+
+    // Watch the directories instead of the files: dbt replaces the artifacts instead of updating them
+    let mut watched_dirs: Vec<PathBuf> = watched_files
+        .iter()
+        .filter_map(|file| file.parent().map(Path::to_path_buf))
+        .collect();
+    watched_dirs.sort();
+    watched_dirs.dedup();
+
+    let mut debouncer =
+        new_debouncer(
+            WATCH_DEBOUNCE,
+            None,
+            move |result: DebounceEventResult| match result {
+                Ok(events) => {
+                    // Reading the artifacts also emits (access) events, only react to writes
+                    let changed = events.iter().any(|event| {
+                        matches!(
+                            event.kind,
+                            EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+                        ) && event.paths.iter().any(|path| watched_files.contains(path))
+                    });
+                    if changed {
+                        on_change();
+                    }
+                }
+                Err(errors) => {
+                    for err in errors {
+                        eprintln!("{}", format!("Watch error: {err}").red());
+                    }
+                }
+            },
+        )
+        .context("Unable to start watching for changes")?;
+
+    for dir in watched_dirs {
+        debouncer
+            .watch(&dir, RecursiveMode::NonRecursive)
+            .with_context(|| format!("Unable to watch '{}' for changes", dir.display()))?;
+    }
+    Ok(debouncer)
+}
+
+/// Absolute path of a watched file, matching the paths reported by file system events
+fn watched_path(path: &Path) -> PathBuf {
+    let file_name = path.file_name().unwrap_or_default();
+    let parent = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+    parent
+        .canonicalize()
+        .unwrap_or_else(|_| parent.to_path_buf())
+        .join(file_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+
+    #[test]
+    fn test_watched_path_is_absolute() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let manifest = temp_dir.path().join("target").join("manifest.json");
+        std::fs::create_dir_all(manifest.parent().unwrap()).unwrap();
+
+        let watched = watched_path(&manifest);
+        assert!(watched.is_absolute());
+        assert!(watched.ends_with("target/manifest.json"));
+
+        // Files in the working directory have an empty parent
+        let config = watched_path(Path::new("dbtective.yml"));
+        assert_eq!(
+            config,
+            std::env::current_dir().unwrap().join("dbtective.yml")
+        );
+    }
+
+    #[test]
+    fn test_watch_files_reports_writes_to_watched_files_only() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let manifest = temp_dir.path().join("manifest.json");
+        std::fs::write(&manifest, "{}").unwrap();
+
+        let (sender, receiver) = mpsc::channel();
+        let _watcher = watch_files(std::slice::from_ref(&manifest), move || {
+            sender.send(()).unwrap();
+        })
+        .unwrap();
+
+        std::fs::write(temp_dir.path().join("other.json"), "{}").unwrap();
+        assert!(receiver.recv_timeout(Duration::from_secs(2)).is_err());
+
+        std::fs::write(&manifest, "{\"nodes\": {}}").unwrap();
+        assert!(receiver.recv_timeout(Duration::from_secs(5)).is_ok());
+    }
+}
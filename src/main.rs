@@ -1,8 +1,12 @@
 mod cli;
 mod core;
 use crate::cli::commands::{Cli, Commands};
+use crate::core::fix::fix;
 use crate::core::init::init;
+use crate::core::lsp::lsp;
 use crate::core::run::run;
+use crate::core::scaffold::scaffold;
+use crate::core::stats::stats;
 use clap::{CommandFactory, Parser};
 use log::debug;
 use owo_colors::OwoColorize;
@@ -31,6 +35,34 @@ fn main() {
             }
             std::process::exit(init(options, args.verbose));
         }
+        Some(Commands::Fix { options }) => {
+            if args.verbose {
+                debug!("Fixing dbtective findings...");
+                debug!("{options:#?}");
+            }
+            std::process::exit(fix(options, args.verbose));
+        }
+        Some(Commands::Scaffold { options }) => {
+            if args.verbose {
+                debug!("Scaffolding properties files...");
+                debug!("{options:#?}");
+            }
+            std::process::exit(scaffold(options, args.verbose));
+        }
+        Some(Commands::Lsp { options }) => {
+            if args.verbose {
+                debug!("Starting dbtective language server...");
+                debug!("{options:#?}");
+            }
+            std::process::exit(lsp(options, args.verbose));
+        }
+        Some(Commands::Stats { options }) => {
+            if args.verbose {
+                debug!("Collecting project stats...");
+                debug!("{options:#?}");
+            }
+            std::process::exit(stats(options, args.verbose));
+        }
         None => {
             println!(
                 r"
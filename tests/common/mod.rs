@@ -9,6 +9,7 @@ use dbtective::core::rules::catalog::apply_catalog_node_rules::apply_catalog_nod
 use dbtective::core::rules::catalog::apply_catalog_source_rules::apply_catalog_source_rules;
 use dbtective::core::rules::manifest::apply_manifest_node_rules::apply_manifest_node_rules;
 use dbtective::core::rules::manifest::apply_other_manifest_object_rules::apply_manifest_object_rules;
+use dbtective::core::rules::project::apply_project_rules::apply_project_rules;
 use std::io::Write;
 use tempfile::TempDir;
 
@@ -102,6 +103,16 @@ impl TestEnvironment {
             .collect()
     }
 
+    pub fn run_project_rules(&self) -> Vec<(RuleResult, Severity)> {
+        let manifest = Manifest::from_file(&self.manifest_path).expect("Failed to load manifest");
+        let config = Config::from_file(&self.config_path).expect("Failed to load config");
+
+        apply_project_rules(&manifest, &config)
+            .into_iter()
+            .map(|(result, severity)| (result, severity.clone()))
+            .collect()
+    }
+
     pub fn run_catalog_rules(&self, verbose: bool) -> anyhow::Result<Vec<(RuleResult, Severity)>> {
         let manifest = Manifest::from_file(&self.manifest_path)?;
         let config = Config::from_file(&self.config_path)?;
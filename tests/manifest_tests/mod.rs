@@ -1,9 +1,24 @@
+mod test_allowed_dependencies;
+mod test_column_has_tests;
+mod test_description_quality;
 mod test_has_contract_enforced;
 mod test_has_description;
 mod test_has_metadata_keys;
+mod test_has_primary_key_test;
 mod test_has_refs;
+mod test_has_relationship_tests;
 mod test_has_tags;
 mod test_has_unique_tests;
 mod test_is_not_orphaned;
+mod test_macro_arguments_documented;
+mod test_macro_is_used;
+mod test_max_children_parents;
 mod test_max_code_lines;
+mod test_max_dag_depth;
+mod test_model_versions;
 mod test_naming_convention;
+mod test_no_deprecated_references;
+mod test_no_hardcoded_references;
+mod test_redundant_dependencies;
+mod test_sources_only_in_staging;
+mod test_uses_doc_blocks;
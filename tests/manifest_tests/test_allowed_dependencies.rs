@@ -0,0 +1,120 @@
+use crate::common::TestEnvironment;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "project_name": "test_project"
+  },
+  "nodes": {
+    "model.test.stg_orders": {
+      "name": "stg_orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/stg_orders.sql",
+      "unique_id": "model.test.stg_orders",
+      "tags": [],
+      "depends_on": {"nodes": ["source.test.raw.orders"]}
+    },
+    "model.test.int_orders": {
+      "name": "int_orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/intermediate/int_orders.sql",
+      "unique_id": "model.test.int_orders",
+      "tags": [],
+      "depends_on": {"nodes": ["model.test.stg_orders"]}
+    },
+    "model.test.orders": {
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/marts/orders.sql",
+      "unique_id": "model.test.orders",
+      "tags": ["mart"],
+      "depends_on": {"nodes": ["model.test.int_orders", "model.test.stg_orders", "source.test.raw.orders"]}
+    }
+  },
+  "sources": {
+    "source.test.raw.orders": {
+      "name": "orders",
+      "resource_type": "source",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/_sources.yml",
+      "unique_id": "source.test.raw.orders",
+      "source_name": "raw"
+    }
+  },
+  "parent_map": {
+    "model.test.stg_orders": ["source.test.raw.orders"],
+    "model.test.int_orders": ["model.test.stg_orders"],
+    "model.test.orders": ["model.test.int_orders", "model.test.stg_orders", "source.test.raw.orders"]
+  },
+  "child_map": {}
+}"#;
+
+const LAYERS: &str = r#"
+    layers:
+      - name: sources
+        resource_types: ["source"]
+      - name: staging
+        prefixes: ["stg_"]
+      - name: intermediate
+        paths: ["models/intermediate/*"]
+      - name: marts
+        tags: ["mart"]
+"#;
+
+#[test]
+fn test_allowed_dependencies() {
+    // Test case 1: Failure - marts may only select from intermediate models
+    let config = format!(
+        r#"
+manifest_tests:
+  - name: "layered_dag"
+    type: allowed_dependencies
+{LAYERS}
+    allowed:
+      staging: ["sources"]
+      intermediate: ["staging"]
+      marts: ["intermediate"]
+"#
+    );
+
+    let env = TestEnvironment::new(MANIFEST, &config);
+    let findings = env.run_maniest_rules(false);
+
+    let mut messages: Vec<&str> = findings
+        .iter()
+        .map(|(result, _)| result.message.as_str())
+        .collect();
+    messages.sort_unstable();
+    assert_eq!(
+        messages,
+        vec![
+            "Model 'orders' (marts) depends on Model 'stg_orders' (staging), but 'marts' may only depend on: intermediate",
+            "Model 'orders' (marts) depends on Source 'orders' (sources), but 'marts' may only depend on: intermediate",
+        ]
+    );
+    assert!(findings.iter().all(|(result, _)| {
+        result.rule_name == "layered_dag"
+            && result.object_type == "Model"
+            && result.relative_path.as_deref() == Some("models/marts/orders.sql")
+    }));
+
+    // Test case 2: Success - layers without an entry in `allowed` are not checked
+    let config = format!(
+        r#"
+manifest_tests:
+  - type: allowed_dependencies
+{LAYERS}
+    allowed:
+      staging: ["sources"]
+      intermediate: ["staging"]
+"#
+    );
+
+    let env = TestEnvironment::new(MANIFEST, &config);
+    let findings = env.run_maniest_rules(false);
+    assert_eq!(findings.len(), 0);
+}
@@ -0,0 +1,86 @@
+use crate::common::TestEnvironment;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "project_name": "test_project"
+  },
+  "nodes": {
+    "model.test.customers": {
+      "name": "customers",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/marts/customers.sql",
+      "unique_id": "model.test.customers",
+      "columns": {
+        "customer_status": {"name": "customer_status", "data_type": "varchar", "tags": []},
+        "email": {"name": "email", "data_type": "varchar", "tags": [], "meta": {"pii": true}},
+        "country": {"name": "country", "data_type": "varchar", "tags": []}
+      }
+    },
+    "test.test.accepted_values_customers_customer_status": {
+      "name": "accepted_values_customers_customer_status",
+      "resource_type": "test",
+      "package_name": "test_project",
+      "unique_id": "test.test.accepted_values_customers_customer_status",
+      "attached_node": "model.test.customers",
+      "column_name": "customer_status",
+      "test_metadata": {
+        "name": "accepted_values",
+        "kwargs": {"column_name": "customer_status", "values": ["active", "churned"]}
+      }
+    },
+    "test.test.is_hashed_customers_email": {
+      "name": "is_hashed_customers_email",
+      "resource_type": "test",
+      "package_name": "test_project",
+      "unique_id": "test.test.is_hashed_customers_email",
+      "attached_node": "model.test.customers",
+      "column_name": "email",
+      "test_metadata": {"name": "is_hashed", "namespace": "my_project", "kwargs": {"column_name": "email"}}
+    }
+  }
+}"#;
+
+#[test]
+fn test_column_has_tests() {
+    // Test case 1: Success - status columns have accepted_values and pii columns are hashed
+    let config = r#"
+manifest_tests:
+  - name: "status_columns_have_accepted_values"
+    type: "column_has_tests"
+    columns: [".*_status$"]
+    required_tests: ["accepted_values"]
+  - name: "pii_is_hashed"
+    type: "column_has_tests"
+    meta:
+      pii: true
+    required_tests: ["is_hashed"]
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+    assert_eq!(findings.len(), 0);
+
+    // Test case 2: Failure - every varchar column needs a not_null test
+    let config = r#"
+manifest_tests:
+  - name: "strings_not_null"
+    type: "column_has_tests"
+    data_types: ["varchar"]
+    required_tests: ["not_null"]
+    excludes: ["models/staging/*"]
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let mut findings = env.run_maniest_rules(false);
+    findings.sort_by(|a, b| a.0.message.cmp(&b.0.message));
+
+    assert_eq!(findings.len(), 3);
+    assert_eq!(findings[0].0.rule_name, "strings_not_null");
+    assert_eq!(
+        findings[0].0.message,
+        "Column 'country' of customers is missing required tests: not_null"
+    );
+}
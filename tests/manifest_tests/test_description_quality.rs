@@ -0,0 +1,121 @@
+use crate::common::TestEnvironment;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "project_name": "test_project"
+  },
+  "nodes": {
+    "model.test.customers": {
+      "name": "customers",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/marts/customers.sql",
+      "unique_id": "model.test.customers",
+      "description": "One row per customer, with their lifetime order totals.",
+      "columns": {
+        "customer_id": {"name": "customer_id", "description": "Customer id", "tags": []},
+        "lifetime_value": {"name": "lifetime_value", "description": "TBD", "tags": []}
+      }
+    },
+    "model.test.orders": {
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/marts/orders.sql",
+      "unique_id": "model.test.orders",
+      "description": "TODO: describe this model"
+    },
+    "model.test.payments": {
+      "name": "payments",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/marts/payments.sql",
+      "unique_id": "model.test.payments",
+      "description": "The payments table"
+    }
+  },
+  "sources": {
+    "source.test_project.raw.raw_customers": {
+      "name": "raw_customers",
+      "source_name": "raw",
+      "resource_type": "source",
+      "package_name": "test_project",
+      "path": "models/sources.yml",
+      "original_file_path": "models/sources.yml",
+      "unique_id": "source.test_project.raw.raw_customers",
+      "fqn": ["test_project", "raw", "raw_customers"],
+      "identifier": "raw_customers",
+      "loader": "",
+      "source_description": "",
+      "description": "n/a",
+      "columns": {}
+    }
+  }
+}"#;
+
+#[test]
+fn test_description_quality() {
+    // Test case 1: Success - only customers is checked and its columns are skipped
+    let config = r#"
+manifest_tests:
+  - name: "meaningful_descriptions"
+    type: "description_quality"
+    includes: ["models/marts/customers.sql"]
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+    assert_eq!(findings.len(), 0);
+
+    // Test case 2: Failure - placeholders and descriptions that restate the name
+    let config = r#"
+manifest_tests:
+  - name: "meaningful_descriptions"
+    type: "description_quality"
+    include_columns: true
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let mut findings = env.run_maniest_rules(false);
+    findings.sort_by(|a, b| a.0.message.cmp(&b.0.message));
+
+    let messages: Vec<&str> = findings.iter().map(|f| f.0.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Description of column 'customer_id' of customers does not say more than the name",
+            "Description of column 'lifetime_value' of customers looks like a placeholder (matches '^\\W*(todo|tbd|tba|fixme|wip|xxx)\\b')",
+            "Description of orders looks like a placeholder (matches '^\\W*(todo|tbd|tba|fixme|wip|xxx)\\b')",
+            "Description of payments does not say more than the name",
+            "Description of raw_customers looks like a placeholder (matches '^\\W*(n/?a|none|null|empty|placeholder|description|tmp|test|\\.+|-+|\\?+)\\W*$')",
+        ]
+    );
+
+    // Test case 3: Failure - stricter length requirement on models only
+    let config = r#"
+manifest_tests:
+  - name: "long_descriptions"
+    type: "description_quality"
+    min_words: 5
+    allow_restating_name: true
+    forbidden_patterns: []
+    applies_to: ["models"]
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let mut findings = env.run_maniest_rules(false);
+    findings.sort_by(|a, b| a.0.message.cmp(&b.0.message));
+
+    assert_eq!(findings.len(), 2);
+    assert_eq!(findings[0].0.rule_name, "long_descriptions");
+    assert_eq!(
+        findings[0].0.message,
+        "Description of orders has 4 words, minimum is 5"
+    );
+    assert_eq!(
+        findings[1].0.message,
+        "Description of payments has 3 words, minimum is 5"
+    );
+}
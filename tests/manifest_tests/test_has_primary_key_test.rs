@@ -0,0 +1,133 @@
+use crate::common::TestEnvironment;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "project_name": "test_project"
+  },
+  "nodes": {
+    "model.test.orders": {
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/marts/orders.sql",
+      "unique_id": "model.test.orders",
+      "primary_key": ["order_id"]
+    },
+    "model.test.order_lines": {
+      "name": "order_lines",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/marts/order_lines.sql",
+      "unique_id": "model.test.order_lines"
+    },
+    "model.test.stg_payments": {
+      "name": "stg_payments",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/stg_payments.sql",
+      "unique_id": "model.test.stg_payments"
+    },
+    "test.test.unique_orders_customer_id": {
+      "name": "unique_orders_customer_id",
+      "resource_type": "test",
+      "package_name": "test_project",
+      "unique_id": "test.test.unique_orders_customer_id",
+      "attached_node": "model.test.orders",
+      "column_name": "customer_id",
+      "test_metadata": {"name": "unique", "kwargs": {"column_name": "customer_id"}}
+    },
+    "test.test.not_null_orders_order_id": {
+      "name": "not_null_orders_order_id",
+      "resource_type": "test",
+      "package_name": "test_project",
+      "unique_id": "test.test.not_null_orders_order_id",
+      "attached_node": "model.test.orders",
+      "column_name": "order_id",
+      "test_metadata": {"name": "not_null", "kwargs": {"column_name": "order_id"}}
+    },
+    "test.test.unique_combination_order_lines": {
+      "name": "dbt_utils_unique_combination_of_columns_order_lines",
+      "resource_type": "test",
+      "package_name": "test_project",
+      "unique_id": "test.test.unique_combination_order_lines",
+      "attached_node": "model.test.order_lines",
+      "test_metadata": {
+        "name": "unique_combination_of_columns",
+        "namespace": "dbt_utils",
+        "kwargs": {"combination_of_columns": ["order_id", "line_number"]}
+      }
+    },
+    "test.test.not_null_order_lines_order_id": {
+      "name": "not_null_order_lines_order_id",
+      "resource_type": "test",
+      "package_name": "test_project",
+      "unique_id": "test.test.not_null_order_lines_order_id",
+      "attached_node": "model.test.order_lines",
+      "column_name": "order_id",
+      "test_metadata": {"name": "not_null", "kwargs": {"column_name": "order_id"}}
+    },
+    "test.test.not_null_order_lines_line_number": {
+      "name": "not_null_order_lines_line_number",
+      "resource_type": "test",
+      "package_name": "test_project",
+      "unique_id": "test.test.not_null_order_lines_line_number",
+      "attached_node": "model.test.order_lines",
+      "column_name": "line_number",
+      "test_metadata": {"name": "not_null", "kwargs": {"column_name": "line_number"}}
+    }
+  }
+}"#;
+
+#[test]
+fn test_has_primary_key_test() {
+    // Test case 1: Failure - orders tests the wrong column for uniqueness, stg_payments has no key
+    let config = r#"
+manifest_tests:
+  - name: "primary_keys_are_tested"
+    type: "has_primary_key_test"
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let mut findings = env.run_maniest_rules(false);
+    findings.sort_by(|a, b| a.0.message.cmp(&b.0.message));
+
+    assert_eq!(findings.len(), 2);
+    assert_eq!(findings[0].0.rule_name, "primary_keys_are_tested");
+    assert_eq!(
+        findings[0].0.message,
+        "Primary key (order_id) of orders is missing a uniqueness test"
+    );
+    assert_eq!(
+        findings[1].0.message,
+        "stg_payments has no primary key, declare a primary_key constraint or add a unique_combination_of_columns test"
+    );
+
+    // Test case 2: Success - the composite key of order_lines is fully tested
+    let config = r#"
+manifest_tests:
+  - type: "has_primary_key_test"
+    includes: ["models/marts/order_lines.sql"]
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+    assert_eq!(findings.len(), 0);
+
+    // Test case 3: Failure - the combined test is not accepted as a uniqueness test
+    let config = r#"
+manifest_tests:
+  - type: "has_primary_key_test"
+    includes: ["models/marts/order_lines.sql"]
+    unique_test_names: ["unique"]
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+    assert_eq!(findings.len(), 1);
+    assert_eq!(
+        findings[0].0.message,
+        "order_lines has no primary key, declare a primary_key constraint or add a unique_combination_of_columns test"
+    );
+}
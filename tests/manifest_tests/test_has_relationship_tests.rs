@@ -0,0 +1,84 @@
+use crate::common::TestEnvironment;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "project_name": "test_project"
+  },
+  "nodes": {
+    "model.test.orders": {
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/marts/orders.sql",
+      "unique_id": "model.test.orders",
+      "primary_key": ["order_id"],
+      "columns": {
+        "order_id": {"name": "order_id", "tags": []},
+        "customer_id": {"name": "customer_id", "tags": []},
+        "salesforce_id": {"name": "salesforce_id", "tags": []},
+        "customer_key": {"name": "customer_key", "tags": []}
+      }
+    },
+    "test.test.relationships_orders_customer_id": {
+      "name": "relationships_orders_customer_id",
+      "resource_type": "test",
+      "package_name": "test_project",
+      "unique_id": "test.test.relationships_orders_customer_id",
+      "attached_node": "model.test.orders",
+      "column_name": "customer_id",
+      "test_metadata": {
+        "name": "relationships",
+        "kwargs": {"column_name": "customer_id", "to": "ref('customers')", "field": "customer_id"}
+      }
+    }
+  }
+}"#;
+
+#[test]
+fn test_has_relationship_tests() {
+    // Test case 1: Failure - salesforce_id has no relationships test
+    let config = r#"
+manifest_tests:
+  - name: "foreign_keys_are_tested"
+    type: "has_relationship_tests"
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].0.rule_name, "foreign_keys_are_tested");
+    assert_eq!(
+        findings[0].0.message,
+        "Column 'salesforce_id' of orders looks like a foreign key but has no relationships test or foreign key constraint"
+    );
+
+    // Test case 2: Success - external ids are exempt
+    let config = r#"
+manifest_tests:
+  - type: "has_relationship_tests"
+    exemptions: ["salesforce_id"]
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+    assert_eq!(findings.len(), 0);
+
+    // Test case 3: Failure - custom column pattern
+    let config = r#"
+manifest_tests:
+  - type: "has_relationship_tests"
+    columns: [".*_(id|key)$"]
+    exemptions: ["salesforce_id"]
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+    assert_eq!(findings.len(), 1);
+    assert_eq!(
+        findings[0].0.message,
+        "Column 'customer_key' of orders looks like a foreign key but has no relationships test or foreign key constraint"
+    );
+}
@@ -0,0 +1,77 @@
+use crate::common::TestEnvironment;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "project_name": "test_project"
+  },
+  "nodes": {},
+  "macros": {
+    "macro.test_project.cents_to_dollars": {
+      "name": "cents_to_dollars",
+      "package_name": "test_project",
+      "original_file_path": "macros/cents_to_dollars.sql",
+      "unique_id": "macro.test_project.cents_to_dollars",
+      "macro_sql": "{% macro cents_to_dollars(column_name, scale=2) %}round({{ column_name }} / 100, {{ scale }}){% endmacro %}",
+      "description": "Converts cents to dollars",
+      "arguments": [
+        {"name": "column_name", "type": "string", "description": "Column holding an amount in cents"},
+        {"name": "scale", "type": "integer", "description": "Number of decimals"}
+      ]
+    },
+    "macro.test_project.date_spine": {
+      "name": "date_spine",
+      "package_name": "test_project",
+      "original_file_path": "macros/date_spine.sql",
+      "unique_id": "macro.test_project.date_spine",
+      "macro_sql": "{% macro date_spine(start_date, end_date, datepart='day') %}{% endmacro %}",
+      "description": "Generates a row per datepart",
+      "arguments": [
+        {"name": "start_date", "type": null, "description": "First date"},
+        {"name": "end_date", "type": "date", "description": ""}
+      ]
+    }
+  }
+}"#;
+
+#[test]
+fn test_macro_arguments_documented() {
+    // Test case 1: Failure - missing descriptions and undocumented parameters
+    let config = r#"
+manifest_tests:
+  - name: "macro_arguments_documented"
+    type: "macro_arguments_documented"
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let mut findings = env.run_maniest_rules(false);
+    findings.sort_by(|a, b| a.0.message.cmp(&b.0.message));
+
+    assert_eq!(findings.len(), 2);
+    assert_eq!(findings[0].0.rule_name, "macro_arguments_documented");
+    assert_eq!(
+        findings[0].0.message,
+        "Argument 'datepart' of macro date_spine is not documented"
+    );
+    assert_eq!(
+        findings[1].0.message,
+        "Argument 'end_date' of macro date_spine has no description"
+    );
+
+    // Test case 2: Failure - types are required as well
+    let config = r#"
+manifest_tests:
+  - name: "macro_arguments_typed"
+    type: "macro_arguments_documented"
+    require_types: true
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+
+    assert_eq!(findings.len(), 3);
+    assert!(findings
+        .iter()
+        .any(|f| f.0.message == "Argument 'start_date' of macro date_spine has no type"));
+}
@@ -0,0 +1,87 @@
+use crate::common::TestEnvironment;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "project_name": "test_project"
+  },
+  "nodes": {
+    "model.test_project.orders": {
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/orders.sql",
+      "unique_id": "model.test_project.orders",
+      "depends_on": {"macros": ["macro.test_project.cents_to_dollars"], "nodes": []}
+    }
+  },
+  "macros": {
+    "macro.test_project.cents_to_dollars": {
+      "name": "cents_to_dollars",
+      "package_name": "test_project",
+      "original_file_path": "macros/cents_to_dollars.sql",
+      "unique_id": "macro.test_project.cents_to_dollars",
+      "macro_sql": "{% macro cents_to_dollars(column) %}{{ round_money(column / 100) }}{% endmacro %}",
+      "depends_on": {"macros": ["macro.test_project.round_money"]}
+    },
+    "macro.test_project.round_money": {
+      "name": "round_money",
+      "package_name": "test_project",
+      "original_file_path": "macros/round_money.sql",
+      "unique_id": "macro.test_project.round_money",
+      "macro_sql": "{% macro round_money(value) %}round({{ value }}, 2){% endmacro %}"
+    },
+    "macro.test_project.generate_schema_name": {
+      "name": "generate_schema_name",
+      "package_name": "test_project",
+      "original_file_path": "macros/generate_schema_name.sql",
+      "unique_id": "macro.test_project.generate_schema_name",
+      "macro_sql": "{% macro generate_schema_name(custom_schema_name, node) %}{% endmacro %}"
+    },
+    "macro.test_project.legacy_pivot": {
+      "name": "legacy_pivot",
+      "package_name": "test_project",
+      "original_file_path": "macros/legacy_pivot.sql",
+      "unique_id": "macro.test_project.legacy_pivot",
+      "macro_sql": "{% macro legacy_pivot() %}{% endmacro %}"
+    }
+  }
+}"#;
+
+#[test]
+fn test_macro_is_used() {
+    // Test case 1: Failure - legacy_pivot is never called, generate_schema_name is exempt
+    let config = r#"
+manifest_tests:
+  - name: "macros_are_used"
+    type: "macro_is_used"
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].0.rule_name, "macros_are_used");
+    assert_eq!(
+        findings[0].0.message,
+        "Macro 'legacy_pivot' is not used by any node, macro or exposure"
+    );
+
+    // Test case 2: Failure - custom exemptions replace the defaults
+    let config = r#"
+manifest_tests:
+  - name: "macros_are_used"
+    type: "macro_is_used"
+    exemptions: ["^legacy_.*"]
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+
+    assert_eq!(findings.len(), 1);
+    assert_eq!(
+        findings[0].0.message,
+        "Macro 'generate_schema_name' is not used by any node, macro or exposure"
+    );
+}
@@ -0,0 +1,133 @@
+use crate::common::TestEnvironment;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "project_name": "test_project"
+  },
+  "nodes": {
+    "model.test.stg_orders": {
+      "name": "stg_orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/stg_orders.sql",
+      "unique_id": "model.test.stg_orders",
+      "depends_on": {"nodes": ["source.test.raw.orders"]}
+    },
+    "model.test.orders": {
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/marts/orders.sql",
+      "unique_id": "model.test.orders",
+      "depends_on": {"nodes": ["model.test.stg_orders", "source.test.raw.orders", "seed.test.countries"]}
+    },
+    "model.test.customers": {
+      "name": "customers",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/marts/customers.sql",
+      "unique_id": "model.test.customers",
+      "depends_on": {"nodes": ["model.test.stg_orders"]}
+    }
+  },
+  "sources": {
+    "source.test.raw.orders": {
+      "name": "orders",
+      "resource_type": "source",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/_sources.yml",
+      "unique_id": "source.test.raw.orders",
+      "source_name": "raw"
+    }
+  },
+  "parent_map": {
+    "model.test.stg_orders": ["source.test.raw.orders"],
+    "model.test.orders": ["model.test.stg_orders", "seed.test.countries", "source.test.raw.orders"],
+    "model.test.customers": ["model.test.stg_orders"]
+  },
+  "child_map": {
+    "source.test.raw.orders": ["model.test.orders", "model.test.stg_orders"],
+    "model.test.stg_orders": ["model.test.customers", "model.test.orders", "test.test.not_null_stg_orders_id"],
+    "model.test.orders": [],
+    "model.test.customers": []
+  }
+}"#;
+
+#[test]
+fn test_max_children() {
+    // Test case 1: Failure - staging model and source with two model children
+    let config = r#"
+manifest_tests:
+  - name: "fan_out"
+    type: max_children
+    max: 1
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let mut findings = env.run_maniest_rules(false);
+    findings.sort_by(|a, b| a.0.message.cmp(&b.0.message));
+
+    assert_eq!(findings.len(), 2);
+    assert_eq!(
+        findings[0].0.message,
+        "Model 'stg_orders' has 2 children, more than the maximum of 1: customers, orders"
+    );
+    assert_eq!(findings[1].0.object_type, "Source");
+    assert_eq!(
+        findings[1].0.message,
+        "Source 'orders' has 2 children, more than the maximum of 1: orders, stg_orders"
+    );
+
+    // Test case 2: Counting tests, with a higher threshold for the staging layer
+    let config = r#"
+manifest_tests:
+  - type: "max_children"
+    max: 1
+    resource_types: ["models", "tests"]
+    applies_to: ["models"]
+    layers:
+      - name: staging
+        prefixes: ["stg_"]
+        max: 2
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+    assert_eq!(findings.len(), 1);
+    assert_eq!(
+        findings[0].0.message,
+        "Model 'stg_orders' has 3 children, more than the maximum of 2 for layer 'staging': customers, not_null_stg_orders_id, orders"
+    );
+}
+
+#[test]
+fn test_max_parents() {
+    let config = r#"
+manifest_tests:
+  - type: "max_parents"
+    max: 2
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].0.rule_name, "max_parents");
+    assert_eq!(
+        findings[0].0.message,
+        "Model 'orders' has 3 parents, more than the maximum of 2: countries, raw.orders, stg_orders"
+    );
+
+    // Only count models
+    let config = r#"
+manifest_tests:
+  - type: "max_parents"
+    max: 2
+    resource_types: ["models"]
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+    assert_eq!(findings.len(), 0);
+}
@@ -0,0 +1,133 @@
+use crate::common::TestEnvironment;
+
+// raw.orders -> stg_orders (view) -> int_orders (ephemeral) -> orders (view)
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "project_name": "test_project"
+  },
+  "nodes": {
+    "model.test.stg_orders": {
+      "name": "stg_orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/stg_orders.sql",
+      "unique_id": "model.test.stg_orders",
+      "config": {"materialized": "view"},
+      "depends_on": {"nodes": ["source.test.raw.orders"]}
+    },
+    "model.test.int_orders": {
+      "name": "int_orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/intermediate/int_orders.sql",
+      "unique_id": "model.test.int_orders",
+      "config": {"materialized": "ephemeral"},
+      "depends_on": {"nodes": ["model.test.stg_orders"]}
+    },
+    "model.test.orders": {
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/marts/orders.sql",
+      "unique_id": "model.test.orders",
+      "config": {"materialized": "view"},
+      "depends_on": {"nodes": ["model.test.int_orders"]}
+    }
+  },
+  "sources": {
+    "source.test.raw.orders": {
+      "name": "orders",
+      "resource_type": "source",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/_sources.yml",
+      "unique_id": "source.test.raw.orders",
+      "source_name": "raw"
+    }
+  },
+  "parent_map": {
+    "model.test.stg_orders": ["source.test.raw.orders"],
+    "model.test.int_orders": ["model.test.stg_orders"],
+    "model.test.orders": ["model.test.int_orders"]
+  },
+  "child_map": {
+    "source.test.raw.orders": ["model.test.stg_orders"],
+    "model.test.stg_orders": ["model.test.int_orders"],
+    "model.test.int_orders": ["model.test.orders"],
+    "model.test.orders": []
+  }
+}"#;
+
+#[test]
+fn test_max_dag_depth() {
+    // Test case 1: Failure - orders is 3 edges away from the source
+    let config = r#"
+manifest_tests:
+  - name: "shallow_dag"
+    type: "max_dag_depth"
+    max_depth: 2
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].0.rule_name, "shallow_dag");
+    assert_eq!(
+        findings[0].0.relative_path.as_deref(),
+        Some("models/marts/orders.sql")
+    );
+    assert_eq!(
+        findings[0].0.message,
+        "Model 'orders' is at depth 3 of the DAG, more than the maximum of 2: raw.orders -> stg_orders -> int_orders -> orders"
+    );
+
+    // Test case 2: Success - within the maximum
+    let config = r#"
+manifest_tests:
+  - type: "max_dag_depth"
+    max_depth: 3
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+    assert_eq!(findings.len(), 0);
+}
+
+#[test]
+fn test_max_chained_views() {
+    // Test case 1: Failure - int_orders and orders end chains of 2 and 3 views
+    let config = r#"
+manifest_tests:
+  - type: "max_dag_depth"
+    max_chained_views: 1
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let mut messages: Vec<String> = env
+        .run_maniest_rules(false)
+        .into_iter()
+        .map(|finding| finding.0.message)
+        .collect();
+    messages.sort();
+
+    assert_eq!(
+        messages,
+        vec![
+            "Model 'int_orders' ends a chain of 2 views/ephemeral models, more than the maximum of 1: stg_orders -> int_orders",
+            "Model 'orders' ends a chain of 3 views/ephemeral models, more than the maximum of 1: stg_orders -> int_orders -> orders",
+        ]
+    );
+
+    // Test case 2: Success - within the maximum
+    let config = r#"
+manifest_tests:
+  - type: "max_dag_depth"
+    max_chained_views: 3
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+    assert_eq!(findings.len(), 0);
+}
@@ -0,0 +1,93 @@
+use crate::common::TestEnvironment;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "project_name": "test_project"
+  },
+  "nodes": {
+    "model.test_project.dim_customers.v1": {
+      "name": "dim_customers",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/dim_customers_v1.sql",
+      "unique_id": "model.test_project.dim_customers.v1",
+      "version": 1,
+      "latest_version": 3,
+      "deprecation_date": "2020-01-01T00:00:00"
+    },
+    "model.test_project.dim_customers.v2": {
+      "name": "dim_customers",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/dim_customers_v2.sql",
+      "unique_id": "model.test_project.dim_customers.v2",
+      "version": 2,
+      "latest_version": 3,
+      "columns": {
+        "customer_id": {"name": "customer_id", "data_type": "int", "tags": []},
+        "email": {"name": "email", "data_type": "varchar", "tags": []}
+      }
+    },
+    "model.test_project.dim_customers.v3": {
+      "name": "dim_customers",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/dim_customers_v3.sql",
+      "unique_id": "model.test_project.dim_customers.v3",
+      "version": 3,
+      "latest_version": 3,
+      "config": {"contract": {"enforced": true, "alias_types": true}},
+      "columns": {
+        "customer_id": {"name": "customer_id", "data_type": "int", "tags": []}
+      }
+    },
+    "model.test_project.orders": {
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/orders.sql",
+      "unique_id": "model.test_project.orders",
+      "depends_on": {"nodes": ["model.test_project.dim_customers.v3"], "macros": []}
+    }
+  }
+}"#;
+
+#[test]
+fn test_model_versions() {
+    // Test case 1: Failure - every check
+    let config = r#"
+manifest_tests:
+  - name: "model_versions"
+    type: "model_versions"
+    max_live_versions: 1
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let mut findings = env.run_maniest_rules(false);
+    findings.sort_by(|a, b| a.0.message.cmp(&b.0.message));
+
+    let messages: Vec<&str> = findings.iter().map(|f| f.0.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        vec![
+            "Version 1 of dim_customers was deprecated on 2020-01-01, remove it",
+            "Version 2 of dim_customers has no deprecation_date, but the latest version is 3",
+            "Version 3 of dim_customers removes column 'email' of version 2",
+            "dim_customers has 2 live versions, more than 1",
+        ]
+    );
+
+    // Test case 2: Success - the remaining issues are allowed
+    let config = r#"
+manifest_tests:
+  - name: "model_versions"
+    type: "model_versions"
+    allow: ["missing_deprecation_date", "past_deprecation_date", "breaking_changes"]
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+    assert_eq!(findings.len(), 0);
+}
@@ -0,0 +1,96 @@
+use crate::common::TestEnvironment;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "project_name": "test_project"
+  },
+  "nodes": {
+    "model.test_project.dim_customers.v1": {
+      "name": "dim_customers",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/dim_customers_v1.sql",
+      "unique_id": "model.test_project.dim_customers.v1",
+      "version": 1,
+      "latest_version": 2,
+      "deprecation_date": "2020-01-01T00:00:00"
+    },
+    "model.test_project.dim_customers.v2": {
+      "name": "dim_customers",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/dim_customers_v2.sql",
+      "unique_id": "model.test_project.dim_customers.v2",
+      "version": 2,
+      "latest_version": 2
+    },
+    "model.test_project.orders": {
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/orders.sql",
+      "unique_id": "model.test_project.orders",
+      "depends_on": {"nodes": ["model.test_project.dim_customers.v2"], "macros": ["macro.test_project.old_cents"]}
+    },
+    "model.test_project.customer_orders": {
+      "name": "customer_orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/customer_orders.sql",
+      "unique_id": "model.test_project.customer_orders",
+      "depends_on": {"nodes": ["model.test_project.dim_customers.v1"], "macros": []}
+    }
+  },
+  "macros": {
+    "macro.test_project.old_cents": {
+      "name": "old_cents",
+      "package_name": "test_project",
+      "original_file_path": "macros/old_cents.sql",
+      "unique_id": "macro.test_project.old_cents",
+      "macro_sql": "{% macro old_cents(column) %}{{ column }} / 100{% endmacro %}"
+    }
+  }
+}"#;
+
+#[test]
+fn test_no_deprecated_references() {
+    // Test case 1: Failure - the deprecation date of dim_customers v1 has passed
+    let config = r#"
+manifest_tests:
+  - name: "no_deprecated_references"
+    type: "no_deprecated_references"
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].0.rule_name, "no_deprecated_references");
+    assert_eq!(
+        findings[0].0.message,
+        "customer_orders depends on deprecated 'model.test_project.dim_customers.v1', deprecated since 2020-01-01, use v2 instead"
+    );
+
+    // Test case 2: Failure - a macro deprecated in the config
+    let config = r#"
+manifest_tests:
+  - name: "no_deprecated_macros"
+    type: "no_deprecated_references"
+    applies_to: ["models"]
+    deprecated:
+      - unique_id: "macro.test_project.old_cents"
+        replacement: "cents_to_dollars"
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let mut findings = env.run_maniest_rules(false);
+    findings.sort_by(|a, b| a.0.message.cmp(&b.0.message));
+
+    assert_eq!(findings.len(), 2);
+    assert_eq!(
+        findings[1].0.message,
+        "orders depends on deprecated 'macro.test_project.old_cents', use cents_to_dollars instead"
+    );
+}
@@ -0,0 +1,66 @@
+use crate::common::TestEnvironment;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "project_name": "test_project"
+  },
+  "nodes": {
+    "model.test.orders": {
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/marts/orders.sql",
+      "unique_id": "model.test.orders",
+      "depends_on": {"nodes": ["model.test.stg_orders"]},
+      "raw_code": "-- from legacy.raw.orders\nselect o.*, c.name\nfrom {{ ref('stg_orders') }} o\njoin analytics.raw.customers c on o.customer_id = c.id"
+    },
+    "model.test.stg_orders": {
+      "name": "stg_orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/stg_orders.sql",
+      "unique_id": "model.test.stg_orders",
+      "depends_on": {"nodes": []},
+      "raw_code": "select * from {{ source('raw', 'orders') }} where status != 'from a.b.c'"
+    }
+  }
+}"#;
+
+#[test]
+fn test_no_hardcoded_references() {
+    // Test case 1: Failure - orders joins a hard-coded relation
+    let config = r#"
+manifest_tests:
+  - name: "use_refs"
+    type: "no_hardcoded_references"
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].0.rule_name, "use_refs");
+    assert_eq!(
+        findings[0].0.relative_path.as_deref(),
+        Some("models/marts/orders.sql")
+    );
+    assert_eq!(findings[0].0.line, Some(4));
+    assert_eq!(findings[0].0.column, Some(6));
+    assert_eq!(
+        findings[0].0.message,
+        "orders selects from hard-coded relation 'analytics.raw.customers' on line 4, use ref() or source() instead"
+    );
+
+    // Test case 2: Success - only staging models are checked
+    let config = r#"
+manifest_tests:
+  - type: "no_hardcoded_references"
+    includes: ["models/staging/*"]
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+    assert_eq!(findings.len(), 0);
+}
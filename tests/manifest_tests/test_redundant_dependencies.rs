@@ -0,0 +1,92 @@
+use crate::common::TestEnvironment;
+
+// stg_orders -> int_orders -> orders, stg_orders -> orders
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "project_name": "test_project"
+  },
+  "nodes": {
+    "model.test.stg_orders": {
+      "name": "stg_orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/stg_orders.sql",
+      "unique_id": "model.test.stg_orders",
+      "depends_on": {"nodes": ["source.test.raw.orders"]}
+    },
+    "model.test.int_orders": {
+      "name": "int_orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/intermediate/int_orders.sql",
+      "unique_id": "model.test.int_orders",
+      "depends_on": {"nodes": ["model.test.stg_orders"]}
+    },
+    "model.test.orders": {
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/marts/orders.sql",
+      "unique_id": "model.test.orders",
+      "depends_on": {"nodes": ["model.test.int_orders", "model.test.stg_orders"]}
+    }
+  },
+  "sources": {
+    "source.test.raw.orders": {
+      "name": "orders",
+      "resource_type": "source",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/_sources.yml",
+      "unique_id": "source.test.raw.orders",
+      "source_name": "raw"
+    }
+  },
+  "parent_map": {
+    "model.test.stg_orders": ["source.test.raw.orders"],
+    "model.test.int_orders": ["model.test.stg_orders"],
+    "model.test.orders": ["model.test.int_orders", "model.test.stg_orders"]
+  },
+  "child_map": {
+    "source.test.raw.orders": ["model.test.stg_orders"],
+    "model.test.stg_orders": ["model.test.int_orders", "model.test.orders"],
+    "model.test.int_orders": ["model.test.orders"],
+    "model.test.orders": []
+  }
+}"#;
+
+#[test]
+fn test_redundant_dependencies() {
+    // Test case 1: Failure - int_orders is only used by orders, which also selects from stg_orders
+    let config = r#"
+manifest_tests:
+  - name: "no_redundant_edges"
+    type: "redundant_dependencies"
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].0.rule_name, "no_redundant_edges");
+    assert_eq!(
+        findings[0].0.relative_path.as_deref(),
+        Some("models/marts/orders.sql")
+    );
+    assert_eq!(
+        findings[0].0.message,
+        "Model 'orders' depends on 'stg_orders' both directly and through stg_orders -> int_orders -> orders. 'int_orders' is only used here, consider merging it into 'orders'"
+    );
+
+    // Test case 2: Success - exempted upstream object
+    let config = r#"
+manifest_tests:
+  - type: "redundant_dependencies"
+    exemptions: ["stg_orders"]
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+    assert_eq!(findings.len(), 0);
+}
@@ -0,0 +1,102 @@
+use crate::common::TestEnvironment;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "project_name": "test_project"
+  },
+  "nodes": {
+    "model.test.stg_orders": {
+      "name": "stg_orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/stg_orders.sql",
+      "unique_id": "model.test.stg_orders",
+      "depends_on": {"nodes": ["source.test.raw.orders"]}
+    },
+    "model.test.stg_orders_v2": {
+      "name": "stg_orders_v2",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/stg_orders_v2.sql",
+      "unique_id": "model.test.stg_orders_v2",
+      "depends_on": {"nodes": ["source.test.raw.orders"]}
+    },
+    "model.test.orders": {
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/marts/orders.sql",
+      "unique_id": "model.test.orders",
+      "depends_on": {"nodes": ["model.test.stg_orders", "source.test.raw.customers"]}
+    }
+  },
+  "sources": {
+    "source.test.raw.orders": {
+      "name": "orders",
+      "resource_type": "source",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/_sources.yml",
+      "unique_id": "source.test.raw.orders",
+      "source_name": "raw"
+    },
+    "source.test.raw.customers": {
+      "name": "customers",
+      "resource_type": "source",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/_sources.yml",
+      "unique_id": "source.test.raw.customers",
+      "source_name": "raw"
+    }
+  },
+  "parent_map": {
+    "model.test.stg_orders": ["source.test.raw.orders"],
+    "model.test.stg_orders_v2": ["source.test.raw.orders"],
+    "model.test.orders": ["model.test.stg_orders", "source.test.raw.customers"]
+  },
+  "child_map": {
+    "source.test.raw.orders": ["model.test.stg_orders", "model.test.stg_orders_v2"],
+    "source.test.raw.customers": ["model.test.orders"],
+    "model.test.stg_orders": ["model.test.orders"]
+  }
+}"#;
+
+#[test]
+fn test_sources_only_in_staging() {
+    // Test case 1: Failure - a mart selects from a source, a source has two staging models
+    let config = r#"
+manifest_tests:
+  - name: "sources_in_staging"
+    type: sources_only_in_staging
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let mut findings = env.run_maniest_rules(false);
+    findings.sort_by(|a, b| a.0.message.cmp(&b.0.message));
+
+    assert_eq!(findings.len(), 2);
+    assert_eq!(findings[0].0.object_type, "Model");
+    assert_eq!(
+        findings[0].0.message,
+        "Model 'orders' selects directly from Source 'raw.customers', sources should only be used in staging models"
+    );
+    assert_eq!(findings[1].0.object_type, "Source");
+    assert_eq!(
+        findings[1].0.message,
+        "Source 'orders' is used by 2 staging models: stg_orders, stg_orders_v2. Each source should have a single staging model"
+    );
+
+    // Test case 2: Success - staging defined by path, sources excluded from the duplicate check
+    let config = r#"
+manifest_tests:
+  - type: sources_only_in_staging
+    staging_paths: ["models/staging/*", "models/marts/*"]
+    staging_prefixes: []
+    applies_to: ["models"]
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+    assert_eq!(findings.len(), 0);
+}
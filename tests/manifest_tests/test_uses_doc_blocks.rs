@@ -0,0 +1,72 @@
+use crate::common::TestEnvironment;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "project_name": "test_project"
+  },
+  "nodes": {
+    "model.test.customers": {
+      "name": "customers",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/marts/customers.sql",
+      "unique_id": "model.test.customers",
+      "columns": {
+        "customer_id": {"name": "customer_id", "description": "Id of a customer", "tags": [], "doc_blocks": ["doc.test_project.customer_id"]},
+        "country_code": {"name": "country_code", "description": "ISO country code", "tags": []}
+      }
+    },
+    "model.test.orders": {
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/marts/orders.sql",
+      "unique_id": "model.test.orders",
+      "columns": {
+        "customer_id": {"name": "customer_id", "description": "The customer", "tags": []},
+        "status": {"name": "status", "description": "Status of the order", "tags": [], "doc_blocks": ["doc.test_project.order_status"]}
+      }
+    }
+  }
+}"#;
+
+#[test]
+fn test_uses_doc_blocks() {
+    // Test case 1: Success - status uses a doc block
+    let config = r#"
+manifest_tests:
+  - name: "status_from_doc_block"
+    type: "uses_doc_blocks"
+    columns: ["status"]
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_maniest_rules(false);
+    assert_eq!(findings.len(), 0);
+
+    // Test case 2: Failure - shared columns and country codes must use a doc block
+    let config = r#"
+manifest_tests:
+  - name: "shared_columns_from_doc_blocks"
+    type: "uses_doc_blocks"
+    columns: ["^country_.*"]
+    used_in_more_than: 1
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let mut findings = env.run_maniest_rules(false);
+    findings.sort_by(|a, b| a.0.message.cmp(&b.0.message));
+
+    assert_eq!(findings.len(), 2);
+    assert_eq!(findings[0].0.rule_name, "shared_columns_from_doc_blocks");
+    assert_eq!(
+        findings[0].0.message,
+        "Column 'country_code' of customers does not use a doc block"
+    );
+    assert_eq!(
+        findings[1].0.message,
+        "Column 'customer_id' of orders does not use a doc block, it appears in 2 models"
+    );
+}
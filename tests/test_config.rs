@@ -349,6 +349,52 @@ manifest_tests:
     );
 }
 
+#[test]
+fn test_manifest_allowed_dependencies_undefined_layer() {
+    let config = r#"
+manifest_tests:
+  - type: "allowed_dependencies"
+    layers:
+      - name: staging
+        prefixes: ["stg_"]
+    allowed:
+      staging: ["sources"]
+"#;
+    let temp_file = create_temp_config(config, Some(".yml"));
+    let result = Config::from_file(temp_file.path());
+
+    assert!(
+        result.is_err_and(|err| err.to_string().contains("Undefined layers [\"sources\"]")),
+        "Should fail when 'allowed' refers to a layer that is not defined"
+    );
+}
+
+#[test]
+fn test_manifest_max_children_layer_thresholds_toml() {
+    let config = r#"
+[[manifest_tests]]
+type = "max_children"
+max = 5
+layers = [{ name = "staging", prefixes = ["stg_"], max = 10 }]
+"#;
+    let temp_file = create_temp_config(config, Some(".toml"));
+    let cfg = Config::from_file(temp_file.path()).expect("Failed to parse config");
+    let manifest_tests = cfg.manifest_tests.unwrap();
+
+    let dbtective::core::config::manifest_rule::ManifestSpecificRuleConfig::MaxChildren {
+        max,
+        layers,
+        ..
+    } = &manifest_tests[0].rule
+    else {
+        panic!("Expected a max_children rule");
+    };
+    assert_eq!(*max, 5);
+    assert_eq!(layers[0].layer.name, "staging");
+    assert_eq!(layers[0].layer.prefixes, vec!["stg_"]);
+    assert_eq!(layers[0].max, 10);
+}
+
 // ===== CATALOG_TESTS TESTS =====
 
 #[test]
@@ -1074,3 +1120,73 @@ manifest_tests:
     assert!(all_found.contains(&"dbtective.yml".to_string()));
     assert!(all_found.contains(&"dbtective.yaml".to_string()));
 }
+
+#[test]
+fn test_manifest_max_dag_depth_without_thresholds() {
+    let config = r#"
+manifest_tests:
+  - type: "max_dag_depth"
+"#;
+    let temp_file = create_temp_config(config, Some(".yml"));
+    let result = Config::from_file(temp_file.path());
+
+    assert!(
+        result.is_err_and(|err| err
+            .to_string()
+            .contains("needs at least one of 'max_depth' or 'max_chained_views'")),
+        "Should fail when max_dag_depth has no thresholds"
+    );
+}
+
+#[test]
+fn test_manifest_column_has_tests_without_selector() {
+    let config = r#"
+manifest_tests:
+  - type: "column_has_tests"
+    required_tests: ["not_null"]
+"#;
+    let temp_file = create_temp_config(config, Some(".yml"));
+    let result = Config::from_file(temp_file.path());
+
+    assert!(
+        result.is_err_and(|err| err
+            .to_string()
+            .contains("needs at least one of 'columns', 'data_types', 'tags' or 'meta'")),
+        "Should fail when column_has_tests selects no columns"
+    );
+}
+
+#[test]
+fn test_manifest_uses_doc_blocks_without_selector() {
+    let config = r#"
+manifest_tests:
+  - type: "uses_doc_blocks"
+"#;
+    let temp_file = create_temp_config(config, Some(".yml"));
+    let result = Config::from_file(temp_file.path());
+
+    assert!(
+        result.is_err_and(|err| err
+            .to_string()
+            .contains("needs at least one of 'columns' or 'used_in_more_than'")),
+        "Should fail when uses_doc_blocks selects no columns"
+    );
+}
+
+#[test]
+fn test_project_coverage_min_percent_out_of_range() {
+    let config = r#"
+project_tests:
+  - type: "test_coverage"
+    min_percent: 120
+"#;
+    let temp_file = create_temp_config(config, Some(".yml"));
+    let result = Config::from_file(temp_file.path());
+
+    assert!(
+        result.is_err_and(|err| err
+            .to_string()
+            .contains("'min_percent' of rule 'test_coverage' must be between 0 and 100")),
+        "Should fail when min_percent is not a percentage"
+    );
+}
@@ -0,0 +1,256 @@
+use dbtective::core::config::parse_config::Config;
+use dbtective::core::fix::{apply_plan, plan_fixes};
+use dbtective::core::manifest::Manifest;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+const PLACEHOLDER: &str = "TODO: add a description";
+
+const PROPERTIES: &str = "version: 2
+
+models:
+  # Orders, one row per order
+  - name: stg_orders
+    description: Orders
+    config:
+      meta:
+        maturity: gold
+    columns:
+      - name: order_id
+        tests:
+          - unique
+
+  - name: stg_customers
+    description: ''
+";
+
+const SOURCES: &str = "version: 2
+
+sources:
+  - name: raw
+    tables:
+      - name: customers # loaded nightly
+";
+
+const CONFIG: &str = r#"
+manifest_tests:
+  - type: has_description
+    applies_to: ["models", "sources"]
+  - type: has_tags
+    required_tags: ["daily", "hourly"]
+    criteria: one_of
+    applies_to: ["models"]
+  - type: has_metadata_keys
+    required_keys: ["owner"]
+    applies_to: ["models"]
+  - type: name_convention
+    pattern: snake_case
+    applies_to: ["models"]
+"#;
+
+fn model_json(name: &str, path: &str, description: &str, tags: &str, meta: &str) -> String {
+    format!(
+        r#""model.test_project.{name}": {{
+      "resource_type": "model",
+      "name": "{name}",
+      "package_name": "test_project",
+      "original_file_path": "{path}",
+      "patch_path": "test_project://models/staging/_models.yml",
+      "unique_id": "model.test_project.{name}",
+      "description": "{description}",
+      "tags": {tags},
+      "meta": {meta},
+      "columns": {{}},
+      "depends_on": {{"nodes": []}}
+    }}"#
+    )
+}
+
+fn write_project(temp_dir: &TempDir) -> (Manifest, Config) {
+    let root = temp_dir.path();
+    let nodes = [
+        model_json(
+            "stg_orders",
+            "models/staging/stg_orders.sql",
+            "Orders",
+            r#"["daily"]"#,
+            r#"{"maturity": "gold"}"#,
+        ),
+        model_json(
+            "stg_customers",
+            "models/staging/stg_customers.sql",
+            "",
+            "[]",
+            r#"{"owner": "crm"}"#,
+        ),
+        // Not documented at all: gets an entry in a new `_models.yml` next to it
+        r#""model.test_project.fct_orders": {
+      "resource_type": "model",
+      "name": "fct_orders",
+      "package_name": "test_project",
+      "original_file_path": "models/marts/fct_orders.sql",
+      "unique_id": "model.test_project.fct_orders",
+      "description": "",
+      "tags": ["hourly"],
+      "meta": {"owner": "finance"},
+      "depends_on": {"nodes": []}
+    }"#
+        .to_string(),
+        // Package models are never touched
+        r#""model.some_package.pkg_model": {
+      "resource_type": "model",
+      "name": "pkg_model",
+      "package_name": "some_package",
+      "original_file_path": "models/pkg_model.sql",
+      "unique_id": "model.some_package.pkg_model",
+      "description": "",
+      "depends_on": {"nodes": []}
+    }"#
+        .to_string(),
+    ];
+
+    let manifest = format!(
+        r#"{{
+  "metadata": {{
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "project_name": "test_project"
+  }},
+  "nodes": {{
+    {}
+  }},
+  "sources": {{
+    "source.test_project.raw.customers": {{
+      "name": "customers",
+      "source_name": "raw",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/_sources.yml",
+      "unique_id": "source.test_project.raw.customers",
+      "description": ""
+    }}
+  }}
+}}"#,
+        nodes.join(",\n    ")
+    );
+
+    let manifest_path = root.join("manifest.json");
+    fs::write(&manifest_path, manifest).unwrap();
+    let config_path = root.join("dbtective.yml");
+    fs::write(&config_path, CONFIG).unwrap();
+
+    fs::create_dir_all(root.join("models/staging")).unwrap();
+    fs::write(root.join("models/staging/_models.yml"), PROPERTIES).unwrap();
+    fs::write(root.join("models/staging/_sources.yml"), SOURCES).unwrap();
+
+    (
+        Manifest::from_file(&manifest_path).unwrap(),
+        Config::from_file(&config_path).unwrap(),
+    )
+}
+
+#[test]
+fn test_fix_plans_only_fixable_findings() {
+    let temp_dir = TempDir::new().unwrap();
+    let (manifest, config) = write_project(&temp_dir);
+
+    let plan = plan_fixes(&manifest, None, &config, PLACEHOLDER);
+    let files: Vec<&String> = plan.keys().collect();
+    assert_eq!(
+        files,
+        vec![
+            "models/marts/_models.yml",
+            "models/staging/_models.yml",
+            "models/staging/_sources.yml"
+        ]
+    );
+}
+
+#[test]
+fn test_fix_preserves_comments_and_formatting() {
+    let temp_dir = TempDir::new().unwrap();
+    let (manifest, config) = write_project(&temp_dir);
+
+    let plan = plan_fixes(&manifest, None, &config, PLACEHOLDER);
+    let changes = apply_plan(temp_dir.path(), &plan).unwrap();
+    assert_eq!(changes.len(), 3);
+
+    let models = changes
+        .iter()
+        .find(|change| change.file == "models/staging/_models.yml")
+        .unwrap();
+    assert_eq!(
+        models.after,
+        "version: 2
+
+models:
+  # Orders, one row per order
+  - name: stg_orders
+    description: Orders
+    config:
+      meta:
+        maturity: gold
+        owner: TODO
+    columns:
+      - name: order_id
+        tests:
+          - unique
+
+  - name: stg_customers
+    description: \"TODO: add a description\"
+    tags:
+      - daily
+"
+    );
+
+    let sources = changes
+        .iter()
+        .find(|change| change.file == "models/staging/_sources.yml")
+        .unwrap();
+    assert_eq!(
+        sources.after,
+        "version: 2
+
+sources:
+  - name: raw
+    tables:
+      - name: customers # loaded nightly
+        description: \"TODO: add a description\"
+"
+    );
+
+    let new_file = changes
+        .iter()
+        .find(|change| change.file == "models/marts/_models.yml")
+        .unwrap();
+    assert!(new_file.before.is_empty());
+    assert_eq!(
+        new_file.after,
+        "version: 2\n\nmodels:\n  - name: fct_orders\n    description: \"TODO: add a description\"\n"
+    );
+}
+
+#[test]
+fn test_fix_dry_run_diff() {
+    let temp_dir = TempDir::new().unwrap();
+    let (manifest, config) = write_project(&temp_dir);
+
+    let plan = plan_fixes(&manifest, None, &config, PLACEHOLDER);
+    let changes = apply_plan(temp_dir.path(), &plan).unwrap();
+    let sources = changes
+        .iter()
+        .find(|change| change.file == "models/staging/_sources.yml")
+        .unwrap();
+
+    let diff = sources.unified_diff();
+    assert!(
+        diff.starts_with("--- a/models/staging/_sources.yml\n+++ b/models/staging/_sources.yml\n")
+    );
+    assert!(diff.contains("+        description: \"TODO: add a description\"\n"));
+
+    // Planning never writes files
+    assert_eq!(
+        fs::read_to_string(temp_dir.path().join("models/staging/_sources.yml")).unwrap(),
+        SOURCES
+    );
+    assert!(!Path::new(&temp_dir.path().join("models/marts/_models.yml")).exists());
+}
@@ -1,6 +1,8 @@
 use dbtective::cli::commands::InitOptions;
 use dbtective::core::config::parse_config::Config;
 use dbtective::core::init::{create_config, InitResult};
+use dbtective::core::manifest::Manifest;
+use dbtective::core::rules::manifest::apply_manifest_node_rules::apply_manifest_node_rules;
 use std::fs;
 use tempfile::TempDir;
 
@@ -8,6 +10,7 @@ fn default_options(temp_dir: &TempDir) -> InitOptions {
     InitOptions {
         location: temp_dir.path().to_string_lossy().to_string(),
         format: "yml".to_string(),
+        from_manifest: None,
     }
 }
 
@@ -80,6 +83,7 @@ fn test_init_creates_toml_config() {
     let options = InitOptions {
         location: temp_dir.path().to_string_lossy().to_string(),
         format: "toml".to_string(),
+        from_manifest: None,
     };
 
     let result = create_config(&options);
@@ -95,6 +99,7 @@ fn test_init_toml_is_valid_config() {
     let options = InitOptions {
         location: temp_dir.path().to_string_lossy().to_string(),
         format: "toml".to_string(),
+        from_manifest: None,
     };
 
     create_config(&options);
@@ -124,6 +129,7 @@ version = "0.1.0"
     let options = InitOptions {
         location: temp_dir.path().to_string_lossy().to_string(),
         format: "pyproject".to_string(),
+        from_manifest: None,
     };
 
     create_config(&options);
@@ -151,6 +157,7 @@ requires = ["setuptools"]
     let options = InitOptions {
         location: temp_dir.path().to_string_lossy().to_string(),
         format: "pyproject".to_string(),
+        from_manifest: None,
     };
 
     let result = create_config(&options);
@@ -187,6 +194,7 @@ name = "my-project"
     let options = InitOptions {
         location: temp_dir.path().to_string_lossy().to_string(),
         format: "pyproject".to_string(),
+        from_manifest: None,
     };
 
     let result = create_config(&options);
@@ -219,6 +227,7 @@ fn test_init_does_not_overwrite_existing_toml() {
     let options = InitOptions {
         location: temp_dir.path().to_string_lossy().to_string(),
         format: "toml".to_string(),
+        from_manifest: None,
     };
 
     let result = create_config(&options);
@@ -232,6 +241,7 @@ fn test_init_fails_for_nonexistent_directory() {
     let options = InitOptions {
         location: "/nonexistent/path/that/does/not/exist".to_string(),
         format: "yml".to_string(),
+        from_manifest: None,
     };
 
     let result = create_config(&options);
@@ -251,6 +261,7 @@ fn test_init_fails_when_path_is_file() {
     let options = InitOptions {
         location: file_path.to_string_lossy().to_string(),
         format: "yml".to_string(),
+        from_manifest: None,
     };
 
     let result = create_config(&options);
@@ -269,6 +280,7 @@ fn test_init_yaml_alias_creates_yml_file() {
     let options = InitOptions {
         location: temp_dir.path().to_string_lossy().to_string(),
         format: "yaml".to_string(),
+        from_manifest: None,
     };
 
     let result = create_config(&options);
@@ -280,3 +292,175 @@ fn test_init_yaml_alias_creates_yml_file() {
         "Should create .yml file even with yaml format"
     );
 }
+
+// ===== FROM MANIFEST TESTS =====
+
+fn model_json(name: &str, path: &str, description: &str, tags: &str, meta: &str) -> String {
+    format!(
+        r#""model.test_project.{name}": {{
+      "resource_type": "model",
+      "name": "{name}",
+      "package_name": "test_project",
+      "original_file_path": "{path}",
+      "unique_id": "model.test_project.{name}",
+      "description": "{description}",
+      "tags": {tags},
+      "meta": {meta},
+      "raw_code": "select 1\nfrom somewhere",
+      "depends_on": {{"nodes": []}}
+    }}"#
+    )
+}
+
+fn write_project_manifest(temp_dir: &TempDir) -> String {
+    let models = [
+        model_json(
+            "stg_orders",
+            "models/staging/stg_orders.sql",
+            "Orders",
+            r#"["daily"]"#,
+            r#"{"owner": "data"}"#,
+        ),
+        model_json(
+            "stg_customers",
+            "models/staging/stg_customers.sql",
+            "",
+            "[]",
+            r#"{"owner": "data"}"#,
+        ),
+        model_json(
+            "stg_payments",
+            "models/staging/stg_payments.sql",
+            "Payments",
+            r#"["hourly"]"#,
+            "{}",
+        ),
+        model_json(
+            "stg_refunds",
+            "models/staging/stg_refunds.sql",
+            "Refunds",
+            r#"["daily"]"#,
+            r#"{"owner": "data"}"#,
+        ),
+        model_json(
+            "StagingLegacy",
+            "models/staging/StagingLegacy.sql",
+            "Legacy",
+            r#"["daily"]"#,
+            r#"{"owner": "data"}"#,
+        ),
+        model_json(
+            "fct_orders",
+            "models/marts/fct_orders.sql",
+            "Order facts",
+            r#"["daily"]"#,
+            r#"{"owner": "finance"}"#,
+        ),
+    ];
+
+    let manifest = format!(
+        r#"{{
+  "metadata": {{
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "project_name": "test_project"
+  }},
+  "nodes": {{
+    {}
+  }}
+}}"#,
+        models.join(",\n    ")
+    );
+
+    let manifest_path = temp_dir.path().join("manifest.json");
+    fs::write(&manifest_path, manifest).unwrap();
+    manifest_path.to_string_lossy().to_string()
+}
+
+fn findings_for(config_path: &std::path::Path, manifest_path: &str) -> usize {
+    let config = Config::from_file(config_path).expect("Generated config should be valid");
+    let manifest = Manifest::from_file(manifest_path).unwrap();
+    apply_manifest_node_rules(&manifest, &config, false)
+        .unwrap()
+        .len()
+}
+
+#[test]
+fn test_init_from_manifest_detects_project_conventions() {
+    let temp_dir = TempDir::new().unwrap();
+    let manifest_path = write_project_manifest(&temp_dir);
+    let options = InitOptions {
+        location: temp_dir.path().to_string_lossy().to_string(),
+        format: "yml".to_string(),
+        from_manifest: Some(manifest_path),
+    };
+
+    let result = create_config(&options);
+    assert!(matches!(result, InitResult::Created(_)));
+
+    let content = fs::read_to_string(temp_dir.path().join("dbtective.yml")).unwrap();
+    assert!(content.contains("name: naming_convention_staging"));
+    assert!(content.contains("^stg_[a-z0-9_]+$"));
+    assert!(content.contains("- models/staging/StagingLegacy.sql"));
+    assert!(content.contains("- daily"));
+    assert!(content.contains("- hourly"));
+    assert!(content.contains("- owner"));
+    assert!(content.contains("max_lines: 10"));
+    assert!(content.contains("- models/staging/stg_customers.sql"));
+}
+
+#[test]
+fn test_init_from_manifest_passes_on_day_one() {
+    for (format, file_name) in [("yml", "dbtective.yml"), ("toml", "dbtective.toml")] {
+        let temp_dir = TempDir::new().unwrap();
+        let manifest_path = write_project_manifest(&temp_dir);
+        let options = InitOptions {
+            location: temp_dir.path().to_string_lossy().to_string(),
+            format: format.to_string(),
+            from_manifest: Some(manifest_path.clone()),
+        };
+
+        let result = create_config(&options);
+        assert!(matches!(result, InitResult::Created(_)));
+        assert_eq!(
+            findings_for(&temp_dir.path().join(file_name), &manifest_path),
+            0,
+            "Generated {format} config should not report findings"
+        );
+    }
+}
+
+#[test]
+fn test_init_from_manifest_pyproject_passes_on_day_one() {
+    let temp_dir = TempDir::new().unwrap();
+    let manifest_path = write_project_manifest(&temp_dir);
+    let pyproject_path = temp_dir.path().join("pyproject.toml");
+    fs::write(&pyproject_path, "[project]\nname = \"test-project\"\n").unwrap();
+
+    let options = InitOptions {
+        location: temp_dir.path().to_string_lossy().to_string(),
+        format: "pyproject".to_string(),
+        from_manifest: Some(manifest_path.clone()),
+    };
+
+    let result = create_config(&options);
+    assert!(matches!(result, InitResult::PyprojectUpdated(_)));
+
+    let content = fs::read_to_string(&pyproject_path).unwrap();
+    assert!(content.contains("[project]"));
+    assert!(content.contains("[[tool.dbtective.manifest_tests]]"));
+    assert_eq!(findings_for(&pyproject_path, &manifest_path), 0);
+}
+
+#[test]
+fn test_init_from_missing_manifest_errors() {
+    let temp_dir = TempDir::new().unwrap();
+    let options = InitOptions {
+        location: temp_dir.path().to_string_lossy().to_string(),
+        format: "yml".to_string(),
+        from_manifest: Some("does/not/exist/manifest.json".to_string()),
+    };
+
+    let result = create_config(&options);
+    assert!(matches!(result, InitResult::Error(_)));
+    assert!(!temp_dir.path().join("dbtective.yml").exists());
+}
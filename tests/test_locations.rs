@@ -0,0 +1,134 @@
+use dbtective::cli::table::RuleResult;
+use dbtective::core::config::parse_config::Config;
+use dbtective::core::config::severity::Severity;
+use dbtective::core::manifest::Manifest;
+use dbtective::core::run::collect_findings;
+use std::fs;
+use tempfile::TempDir;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "project_name": "test_project"
+  },
+  "nodes": {
+    "model.test_project.stg_orders": {
+      "resource_type": "model",
+      "name": "stg_orders",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/stg_orders.sql",
+      "patch_path": "test_project://models/staging/_models.yml",
+      "unique_id": "model.test_project.stg_orders",
+      "description": "",
+      "tags": [],
+      "depends_on": {"nodes": []}
+    },
+    "model.test_project.fct_orders": {
+      "resource_type": "model",
+      "name": "fct_orders",
+      "package_name": "test_project",
+      "original_file_path": "models/marts/fct_orders.sql",
+      "unique_id": "model.test_project.fct_orders",
+      "description": "",
+      "tags": [],
+      "depends_on": {"nodes": []}
+    }
+  },
+  "sources": {
+    "source.test_project.raw.customers": {
+      "name": "customers",
+      "source_name": "raw",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/_sources.yml",
+      "unique_id": "source.test_project.raw.customers",
+      "description": ""
+    }
+  }
+}"#;
+
+const MODELS: &str = "version: 2
+
+models:
+  - name: stg_orders
+    description: ''
+    config:
+      tags: []
+";
+
+const SOURCES: &str = "version: 2
+
+sources:
+  - name: raw
+    tables:
+      - name: customers
+";
+
+const CONFIG: &str = r#"
+manifest_tests:
+  - type: has_description
+    applies_to: ["models", "sources"]
+  - type: has_tags
+    required_tags: ["daily"]
+    applies_to: ["models"]
+  - type: has_refs
+    applies_to: ["models"]
+"#;
+
+fn location(
+    findings: &[(RuleResult, &Severity)],
+    rule: &str,
+    message: &str,
+) -> (Option<String>, Option<usize>, Option<usize>) {
+    let (result, _) = findings
+        .iter()
+        .find(|(result, _)| result.rule_name == rule && result.message.contains(message))
+        .unwrap_or_else(|| panic!("No '{rule}' finding for '{message}'"));
+    (result.relative_path.clone(), result.line, result.column)
+}
+
+#[test]
+fn test_findings_point_at_properties_in_yaml() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    fs::write(root.join("manifest.json"), MANIFEST).unwrap();
+    fs::write(root.join("dbtective.yml"), CONFIG).unwrap();
+    fs::create_dir_all(root.join("models/staging")).unwrap();
+    fs::write(root.join("models/staging/_models.yml"), MODELS).unwrap();
+    fs::write(root.join("models/staging/_sources.yml"), SOURCES).unwrap();
+
+    let manifest = Manifest::from_file(root.join("manifest.json")).unwrap();
+    let config = Config::from_file(root.join("dbtective.yml")).unwrap();
+    let findings = collect_findings(&config, &manifest, None, root, false).unwrap();
+
+    let models = Some("models/staging/_models.yml".to_string());
+    assert_eq!(
+        location(&findings, "has_description", "stg_orders"),
+        (models.clone(), Some(5), Some(5))
+    );
+    assert_eq!(
+        location(&findings, "has_tags", "stg_orders"),
+        (models, Some(7), Some(7))
+    );
+    // A missing property points at the entry of the object
+    assert_eq!(
+        location(&findings, "has_description", "customers"),
+        (
+            Some("models/staging/_sources.yml".to_string()),
+            Some(6),
+            Some(9)
+        )
+    );
+    // Without a properties file, or for rules about the SQL, the finding stays on the SQL file
+    assert_eq!(
+        location(&findings, "has_description", "fct_orders"),
+        (Some("models/marts/fct_orders.sql".to_string()), None, None)
+    );
+    assert_eq!(
+        location(&findings, "has_refs", "stg_orders"),
+        (
+            Some("models/staging/stg_orders.sql".to_string()),
+            None,
+            None
+        )
+    );
+}
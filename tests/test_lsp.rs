@@ -0,0 +1,296 @@
+use dbtective::cli::commands::LspOptions;
+use dbtective::core::lsp::serve;
+use lsp_server::{Connection, Message, Notification, Request, RequestId, Response};
+use lsp_types::{
+    CodeActionContext, CodeActionOrCommand, CodeActionParams, DocumentChangeOperation,
+    DocumentChanges, InitializeParams, PartialResultParams, Position, PublishDiagnosticsParams,
+    Range, TextDocumentIdentifier, Url, WorkDoneProgressParams,
+};
+use std::fs;
+use std::io::{BufReader, Read};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+use tempfile::TempDir;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "project_name": "test_project"
+  },
+  "nodes": {
+    "model.test_project.stg_orders": {
+      "resource_type": "model",
+      "name": "stg_orders",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/stg_orders.sql",
+      "unique_id": "model.test_project.stg_orders",
+      "description": "",
+      "depends_on": {"nodes": []}
+    },
+    "model.test_project.stg_customers": {
+      "resource_type": "model",
+      "name": "stg_customers",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/stg_customers.sql",
+      "unique_id": "model.test_project.stg_customers",
+      "description": "Customers",
+      "depends_on": {"nodes": []}
+    }
+  }
+}"#;
+
+const CONFIG: &str = r#"
+manifest_tests:
+  - type: has_description
+    applies_to: ["models"]
+"#;
+
+// A catalog with a node that is no longer in the manifest
+const STALE_CATALOG: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/catalog/v1.json",
+    "dbt_version": "1.10.0",
+    "generated_at": "2025-01-01T00:00:00.000000Z",
+    "env": {}
+  },
+  "nodes": {
+    "model.test_project.old_orders": {
+      "unique_id": "model.test_project.old_orders",
+      "metadata": {
+        "type": "BASE TABLE",
+        "database": "analytics",
+        "schema": "public",
+        "name": "old_orders"
+      },
+      "columns": {},
+      "stats": {}
+    }
+  },
+  "sources": {}
+}"#;
+
+fn receive(client: &Connection) -> Message {
+    client
+        .receiver
+        .recv_timeout(Duration::from_secs(10))
+        .expect("no message from the language server")
+}
+
+fn receive_diagnostics(client: &Connection) -> PublishDiagnosticsParams {
+    loop {
+        if let Message::Notification(notification) = receive(client) {
+            if notification.method == "textDocument/publishDiagnostics" {
+                return serde_json::from_value(notification.params).unwrap();
+            }
+        }
+    }
+}
+
+fn receive_response(client: &Connection, id: i32) -> Response {
+    loop {
+        if let Message::Response(response) = receive(client) {
+            assert_eq!(response.id, RequestId::from(id));
+            return response;
+        }
+    }
+}
+
+#[test]
+fn test_lsp_publishes_diagnostics_and_code_actions() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().canonicalize().unwrap();
+    fs::create_dir_all(root.join("target")).unwrap();
+    fs::write(root.join("target/manifest.json"), MANIFEST).unwrap();
+    fs::write(root.join("dbtective.yml"), CONFIG).unwrap();
+
+    let options = LspOptions {
+        entry_point: Some(root.to_string_lossy().to_string()),
+        config_file: None,
+        manifest_file: "target/manifest.json".to_string(),
+        catalog_file: "target/catalog.json".to_string(),
+        only_manifest: true,
+        placeholder: "TODO: add a description".to_string(),
+    };
+
+    let (server, client) = Connection::memory();
+    let server_thread = std::thread::spawn(move || serve(&server, &options, false));
+
+    client
+        .sender
+        .send(
+            Request::new(
+                1.into(),
+                "initialize".to_string(),
+                InitializeParams::default(),
+            )
+            .into(),
+        )
+        .unwrap();
+    let initialize = receive_response(&client, 1);
+    assert!(initialize.error.is_none());
+    client
+        .sender
+        .send(Notification::new("initialized".to_string(), serde_json::json!({})).into())
+        .unwrap();
+
+    // Only the model without a description has a finding, on its sql file
+    let diagnostics = receive_diagnostics(&client);
+    let model_uri = Url::from_file_path(root.join("models/staging/stg_orders.sql")).unwrap();
+    assert_eq!(diagnostics.uri, model_uri);
+    assert_eq!(diagnostics.diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics.diagnostics[0].source.as_deref(),
+        Some("dbtective")
+    );
+
+    let params = CodeActionParams {
+        text_document: TextDocumentIdentifier { uri: model_uri },
+        range: Range::new(Position::new(0, 0), Position::new(1, 0)),
+        context: CodeActionContext::default(),
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+    };
+    client
+        .sender
+        .send(Request::new(2.into(), "textDocument/codeAction".to_string(), params).into())
+        .unwrap();
+    let response = receive_response(&client, 2);
+    let actions: Vec<CodeActionOrCommand> =
+        serde_json::from_value(response.result.unwrap()).unwrap();
+    assert_eq!(actions.len(), 1);
+    let CodeActionOrCommand::CodeAction(action) = &actions[0] else {
+        panic!("Expected a code action");
+    };
+
+    // The properties file doesn't exist yet: it is created, then filled in
+    let Some(DocumentChanges::Operations(operations)) =
+        action.edit.as_ref().unwrap().document_changes.as_ref()
+    else {
+        panic!("Expected document change operations");
+    };
+    assert_eq!(operations.len(), 2);
+    assert!(matches!(operations[0], DocumentChangeOperation::Op(_)));
+    let DocumentChangeOperation::Edit(edit) = &operations[1] else {
+        panic!("Expected a text document edit");
+    };
+    assert_eq!(
+        edit.text_document.uri,
+        Url::from_file_path(root.join("models/staging/_models.yml")).unwrap()
+    );
+    let lsp_types::OneOf::Left(text_edit) = &edit.edits[0] else {
+        panic!("Expected a text edit");
+    };
+    assert_eq!(
+        text_edit.new_text,
+        "version: 2\n\nmodels:\n  - name: stg_orders\n    description: \"TODO: add a description\"\n"
+    );
+
+    client
+        .sender
+        .send(Request::new(3.into(), "shutdown".to_string(), serde_json::Value::Null).into())
+        .unwrap();
+    receive_response(&client, 3);
+    client
+        .sender
+        .send(Notification::new("exit".to_string(), serde_json::Value::Null).into())
+        .unwrap();
+    server_thread.join().unwrap().unwrap();
+}
+
+#[test]
+fn test_lsp_stdout_only_contains_protocol_messages() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path().canonicalize().unwrap();
+    fs::create_dir_all(root.join("target")).unwrap();
+    fs::write(root.join("target/manifest.json"), MANIFEST).unwrap();
+    fs::write(root.join("target/catalog.json"), STALE_CATALOG).unwrap();
+    fs::write(
+        root.join("dbtective.yml"),
+        format!("{CONFIG}\ncatalog_tests:\n  - type: columns_all_documented\n"),
+    )
+    .unwrap();
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_dbtective"))
+        .args(["lsp", "--entry-point"])
+        .arg(&root)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut stdin = child.stdin.take().unwrap();
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+
+    let initialize = Request::new(
+        1.into(),
+        "initialize".to_string(),
+        InitializeParams::default(),
+    );
+    Message::from(initialize).write(&mut stdin).unwrap();
+    Message::from(Notification::new(
+        "initialized".to_string(),
+        serde_json::json!({}),
+    ))
+    .write(&mut stdin)
+    .unwrap();
+
+    // Read stdout on its own thread: writing anything else to stdout either fails to parse,
+    // or blocks the server as its writer thread holds the stdout lock
+    let (sender, receiver) = mpsc::channel();
+    let reader = std::thread::spawn(move || {
+        while let Some(message) = Message::read(&mut stdout).transpose() {
+            sender.send(message).unwrap();
+        }
+    });
+    let mut receive_stdout = || {
+        let message = receiver
+            .recv_timeout(Duration::from_secs(10))
+            .unwrap_or_else(|_| {
+                child.kill().ok();
+                panic!("no message from the language server");
+            });
+        message.expect("stdout should only contain protocol messages")
+    };
+
+    loop {
+        if let Message::Notification(notification) = receive_stdout() {
+            assert_ne!(
+                notification.method, "window/showMessage",
+                "{}",
+                notification.params
+            );
+            if notification.method == "textDocument/publishDiagnostics" {
+                break;
+            }
+        }
+    }
+
+    Message::from(Request::new(
+        2.into(),
+        "shutdown".to_string(),
+        serde_json::Value::Null,
+    ))
+    .write(&mut stdin)
+    .unwrap();
+    assert!(matches!(receive_stdout(), Message::Response(_)));
+    Message::from(Notification::new(
+        "exit".to_string(),
+        serde_json::Value::Null,
+    ))
+    .write(&mut stdin)
+    .unwrap();
+    drop(stdin);
+    reader.join().unwrap();
+    assert!(child.wait().unwrap().success());
+
+    // The stale catalog warning is written to stderr instead
+    let mut stderr = String::new();
+    child
+        .stderr
+        .take()
+        .unwrap()
+        .read_to_string(&mut stderr)
+        .unwrap();
+    assert!(stderr.contains("No matching manifest node found for catalog node 'old_orders'"));
+}
@@ -0,0 +1,345 @@
+mod common;
+
+use common::TestEnvironment;
+
+// Two staging models in the project (one documented and tested) and a package model
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "project_name": "test_project"
+  },
+  "nodes": {
+    "model.test_project.stg_orders": {
+      "name": "stg_orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/stg_orders.sql",
+      "unique_id": "model.test_project.stg_orders",
+      "description": "Orders",
+      "columns": {
+        "id": {"name": "id", "description": "Order id", "tags": []}
+      }
+    },
+    "model.test_project.stg_customers": {
+      "name": "stg_customers",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/stg_customers.sql",
+      "unique_id": "model.test_project.stg_customers",
+      "description": "",
+      "columns": {
+        "id": {"name": "id", "description": "Customer id", "tags": []}
+      }
+    },
+    "model.dbt_utils.utils_model": {
+      "name": "utils_model",
+      "resource_type": "model",
+      "package_name": "dbt_utils",
+      "original_file_path": "models/utils_model.sql",
+      "unique_id": "model.dbt_utils.utils_model"
+    },
+    "test.test_project.unique_stg_orders_id": {
+      "name": "unique_stg_orders_id",
+      "resource_type": "test",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/_models.yml",
+      "unique_id": "test.test_project.unique_stg_orders_id",
+      "attached_node": "model.test_project.stg_orders"
+    }
+  }
+}"#;
+
+#[test]
+fn test_documentation_coverage() {
+    // Test case 1: Failure - half of the models are documented, package models are not counted
+    let config = r#"
+project_tests:
+  - name: "docs_coverage"
+    type: "documentation_coverage"
+    min_percent: 80
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_project_rules();
+
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].0.rule_name, "docs_coverage");
+    assert_eq!(findings[0].0.object_type, "Project");
+    assert_eq!(findings[0].0.relative_path, None);
+    assert_eq!(
+        findings[0].0.message,
+        "Documentation coverage of models is 50.0% (1 of 2), below the minimum of 80%"
+    );
+
+    // Test case 2: Success - only the documented model is included
+    let config = r#"
+project_tests:
+  - type: "documentation_coverage"
+    min_percent: 100
+    includes: ["models/staging/stg_orders.sql"]
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    assert_eq!(env.run_project_rules().len(), 0);
+}
+
+#[test]
+fn test_test_coverage_grouped_by_directory() {
+    let config = r#"
+project_tests:
+  - type: "test_coverage"
+    min_percent: 75.5
+    group_by: "directory"
+    severity: "warning"
+"#;
+
+    let env = TestEnvironment::new(MANIFEST, config);
+    let findings = env.run_project_rules();
+
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].0.severity, "WARN");
+    assert_eq!(findings[0].0.object_type, "Directory");
+    assert_eq!(
+        findings[0].0.message,
+        "Test coverage of models in directory 'models/staging' is 50.0% (1 of 2), below the minimum of 75.5%"
+    );
+}
+
+#[test]
+fn test_duplicate_descriptions() {
+    let manifest = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "project_name": "test_project"
+  },
+  "nodes": {
+    "model.test_project.stg_orders": {
+      "name": "stg_orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/stg_orders.sql",
+      "unique_id": "model.test_project.stg_orders",
+      "description": "Orders as loaded from the shop, one row per order.",
+      "columns": {
+        "customer_id": {"name": "customer_id", "description": "Id of the customer", "tags": []},
+        "ordered_at": {"name": "ordered_at", "description": "When the order was placed", "tags": []},
+        "shipped_at": {"name": "shipped_at", "description": "when the order was  placed", "tags": []}
+      }
+    },
+    "model.test_project.stg_payments": {
+      "name": "stg_payments",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/stg_payments.sql",
+      "unique_id": "model.test_project.stg_payments",
+      "description": "Orders as loaded from the shop,\n one row per order.",
+      "columns": {
+        "customer_id": {"name": "customer_id", "description": "Id of the customer", "tags": []}
+      }
+    },
+    "model.dbt_utils.utils_model": {
+      "name": "utils_model",
+      "resource_type": "model",
+      "package_name": "dbt_utils",
+      "original_file_path": "models/utils_model.sql",
+      "unique_id": "model.dbt_utils.utils_model",
+      "description": "Orders as loaded from the shop, one row per order."
+    }
+  }
+}"#;
+
+    // Test case 1: Failure - copied model description and copied column description
+    let config = r#"
+project_tests:
+  - type: "duplicate_descriptions"
+    severity: "warning"
+"#;
+
+    let env = TestEnvironment::new(manifest, config);
+    let findings = env.run_project_rules();
+
+    assert_eq!(findings.len(), 2);
+    assert_eq!(findings[0].0.rule_name, "duplicate_descriptions");
+    assert_eq!(findings[0].0.object_type, "Project");
+    assert_eq!(findings[0].0.relative_path, None);
+    assert_eq!(
+        findings[0].0.message,
+        "Models 'stg_orders', 'stg_payments' have the same description \"Orders as loaded from the shop, one row per order.\""
+    );
+    assert_eq!(
+        findings[1].0.relative_path,
+        Some("models/staging/stg_orders.sql".to_string())
+    );
+    assert_eq!(
+        findings[1].0.message,
+        "Columns 'ordered_at', 'shipped_at' of stg_orders have the same description \"When the order was placed\""
+    );
+
+    // Test case 2: Success - a single model has no other models to share a description with
+    let config = r#"
+project_tests:
+  - type: "duplicate_descriptions"
+    includes: ["models/staging/stg_payments.sql"]
+"#;
+
+    let env = TestEnvironment::new(manifest, config);
+    assert_eq!(env.run_project_rules().len(), 0);
+}
+
+#[test]
+fn test_consistent_column_descriptions() {
+    let manifest = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "project_name": "test_project"
+  },
+  "nodes": {
+    "model.test_project.stg_customers": {
+      "name": "stg_customers",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/stg_customers.sql",
+      "unique_id": "model.test_project.stg_customers",
+      "columns": {
+        "customer_id": {"name": "customer_id", "description": "Unique id of a customer.", "tags": []},
+        "zip_code": {"name": "zip_code", "description": "Postal code of the address", "tags": []}
+      }
+    },
+    "model.test_project.customers": {
+      "name": "customers",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/marts/customers.sql",
+      "unique_id": "model.test_project.customers",
+      "depends_on": {"nodes": ["model.test_project.stg_customers"]},
+      "columns": {
+        "customer_id": {"name": "customer_id", "description": "Unique id of a customer.", "tags": []},
+        "zipcode": {"name": "zipcode", "description": "Zip", "tags": []}
+      }
+    }
+  },
+  "docs": {
+    "doc.test_project.customer_id": {
+      "name": "customer_id",
+      "resource_type": "doc",
+      "package_name": "test_project",
+      "path": "docs.md",
+      "original_file_path": "models/docs.md",
+      "unique_id": "doc.test_project.customer_id",
+      "block_contents": "Unique id of a customer."
+    }
+  }
+}"#;
+
+    // Test case 1: Success - customer_id reuses a doc block, zipcode is a different column
+    let config = r#"
+project_tests:
+  - type: "consistent_column_descriptions"
+    require_doc_blocks: true
+"#;
+
+    let env = TestEnvironment::new(manifest, config);
+    assert_eq!(env.run_project_rules().len(), 0);
+
+    // Test case 2: Failure - zipcode is zip_code according to columns_canonical_name
+    let config = r#"
+catalog_tests:
+  - type: "columns_canonical_name"
+    canonical: "zip_code"
+    invalid_names: ["zipcode", "postal_code"]
+project_tests:
+  - name: "same_column_same_description"
+    type: "consistent_column_descriptions"
+    use_canonical_names: true
+"#;
+
+    let env = TestEnvironment::new(manifest, config);
+    let findings = env.run_project_rules();
+
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].0.rule_name, "same_column_same_description");
+    assert_eq!(
+        findings[0].0.relative_path,
+        Some("models/marts/customers.sql".to_string())
+    );
+    assert_eq!(
+        findings[0].0.message,
+        "Column 'zipcode' of customers is described differently than in stg_customers, suggested description: \"Postal code of the address\""
+    );
+}
+
+#[test]
+fn test_unused_doc_blocks() {
+    let manifest = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "dbt_version": "1.10.0",
+    "project_name": "test_project"
+  },
+  "nodes": {
+    "model.test_project.orders": {
+      "name": "orders",
+      "resource_type": "model",
+      "package_name": "test_project",
+      "original_file_path": "models/marts/orders.sql",
+      "unique_id": "model.test_project.orders",
+      "columns": {
+        "status": {"name": "status", "description": "Status", "tags": [], "doc_blocks": ["doc.test_project.order_status"]}
+      }
+    }
+  },
+  "docs": {
+    "doc.test_project.order_status": {
+      "name": "order_status",
+      "resource_type": "doc",
+      "package_name": "test_project",
+      "path": "docs.md",
+      "original_file_path": "models/marts/docs.md",
+      "unique_id": "doc.test_project.order_status",
+      "block_contents": "Status"
+    },
+    "doc.test_project.legacy_status": {
+      "name": "legacy_status",
+      "resource_type": "doc",
+      "package_name": "test_project",
+      "path": "legacy.md",
+      "original_file_path": "models/legacy/legacy.md",
+      "unique_id": "doc.test_project.legacy_status",
+      "block_contents": "Status in the old shop"
+    }
+  }
+}"#;
+
+    // Test case 1: Failure - legacy_status is not used anywhere
+    let config = r#"
+project_tests:
+  - type: "unused_doc_blocks"
+"#;
+
+    let env = TestEnvironment::new(manifest, config);
+    let findings = env.run_project_rules();
+
+    assert_eq!(findings.len(), 1);
+    assert_eq!(findings[0].0.object_type, "Doc block");
+    assert_eq!(
+        findings[0].0.relative_path,
+        Some("models/legacy/legacy.md".to_string())
+    );
+    assert_eq!(
+        findings[0].0.message,
+        "Doc block 'legacy_status' is never used"
+    );
+
+    // Test case 2: Success - the legacy directory is excluded
+    let config = r#"
+project_tests:
+  - type: "unused_doc_blocks"
+    excludes: ["models/legacy/*"]
+"#;
+
+    let env = TestEnvironment::new(manifest, config);
+    assert_eq!(env.run_project_rules().len(), 0);
+}
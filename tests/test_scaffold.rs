@@ -0,0 +1,179 @@
+use dbtective::core::catalog::Catalog;
+use dbtective::core::fix::apply_plan;
+use dbtective::core::manifest::Manifest;
+use dbtective::core::scaffold::plan_scaffold;
+use std::fs;
+use tempfile::TempDir;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "project_name": "test_project",
+    "adapter_type": "snowflake"
+  },
+  "nodes": {
+    "model.test_project.stg_orders": {
+      "resource_type": "model",
+      "name": "stg_orders",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/stg_orders.sql",
+      "unique_id": "model.test_project.stg_orders",
+      "description": "",
+      "columns": {},
+      "depends_on": {"nodes": []}
+    },
+    "model.test_project.stg_customers": {
+      "resource_type": "model",
+      "name": "stg_customers",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/stg_customers.sql",
+      "patch_path": "test_project://models/staging/_models.yml",
+      "unique_id": "model.test_project.stg_customers",
+      "description": "Customers",
+      "columns": {
+        "customer_id": {"name": "customer_id", "description": "", "tags": []}
+      },
+      "depends_on": {"nodes": []}
+    }
+  },
+  "sources": {
+    "source.test_project.raw.orders": {
+      "name": "orders",
+      "source_name": "raw",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/_sources.yml",
+      "unique_id": "source.test_project.raw.orders",
+      "description": ""
+    }
+  }
+}"#;
+
+const CATALOG: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/catalog/v1.json",
+    "dbt_version": "1.10.0",
+    "generated_at": "2025-01-01T00:00:00.000000Z",
+    "env": {}
+  },
+  "nodes": {
+    "model.test_project.stg_orders": {
+      "unique_id": "model.test_project.stg_orders",
+      "metadata": {"type": "VIEW", "schema": "staging", "name": "STG_ORDERS", "database": "analytics", "comment": "One row per order"},
+      "columns": {
+        "STATUS": {"type": "TEXT", "name": "STATUS", "index": 2, "comment": "Order status"},
+        "ORDER_ID": {"type": "NUMBER(38,0)", "name": "ORDER_ID", "index": 1}
+      },
+      "stats": {}
+    },
+    "model.test_project.stg_customers": {
+      "unique_id": "model.test_project.stg_customers",
+      "metadata": {"type": "VIEW", "schema": "staging", "name": "STG_CUSTOMERS", "database": "analytics"},
+      "columns": {
+        "CUSTOMER_ID": {"type": "NUMBER(38,0)", "name": "CUSTOMER_ID", "index": 1},
+        "NAME": {"type": "TEXT", "name": "NAME", "index": 2}
+      },
+      "stats": {}
+    }
+  },
+  "sources": {
+    "source.test_project.raw.orders": {
+      "unique_id": "source.test_project.raw.orders",
+      "metadata": {"type": "BASE TABLE", "schema": "raw", "name": "ORDERS", "database": "raw"},
+      "columns": {
+        "ID": {"type": "NUMBER(38,0)", "name": "ID", "index": 1, "comment": "Primary key"}
+      },
+      "stats": {}
+    }
+  }
+}"#;
+
+const SOURCES: &str = "version: 2
+
+sources:
+  - name: raw
+    database: raw
+    tables:
+      - name: orders
+";
+
+fn load(temp_dir: &TempDir) -> (Manifest, Catalog) {
+    let manifest_path = temp_dir.path().join("manifest.json");
+    let catalog_path = temp_dir.path().join("catalog.json");
+    fs::write(&manifest_path, MANIFEST).unwrap();
+    fs::write(&catalog_path, CATALOG).unwrap();
+    fs::create_dir_all(temp_dir.path().join("models/staging")).unwrap();
+    fs::write(temp_dir.path().join("models/staging/_sources.yml"), SOURCES).unwrap();
+    (
+        Manifest::from_file(&manifest_path).unwrap(),
+        Catalog::from_file(&catalog_path).unwrap(),
+    )
+}
+
+#[test]
+fn test_scaffold_skips_objects_with_documented_columns() {
+    let temp_dir = TempDir::new().unwrap();
+    let (manifest, catalog) = load(&temp_dir);
+
+    let plan = plan_scaffold(&manifest, &catalog);
+    let files: Vec<&String> = plan.keys().collect();
+    // stg_customers already documents columns, so its `_models.yml` is left alone
+    assert_eq!(
+        files,
+        vec!["models/staging/_models.yml", "models/staging/_sources.yml"]
+    );
+    assert_eq!(plan["models/staging/_models.yml"].len(), 1);
+}
+
+#[test]
+fn test_scaffold_generates_columns_with_types_and_comments() {
+    let temp_dir = TempDir::new().unwrap();
+    let (manifest, catalog) = load(&temp_dir);
+
+    let plan = plan_scaffold(&manifest, &catalog);
+    let changes = apply_plan(temp_dir.path(), &plan).unwrap();
+
+    let models = changes
+        .iter()
+        .find(|change| change.file == "models/staging/_models.yml")
+        .unwrap();
+    assert_eq!(
+        models.after,
+        "version: 2
+
+models:
+  - name: stg_orders
+    description: One row per order
+    columns:
+      - name: order_id
+        data_type: \"NUMBER(38,0)\"
+      - name: status
+        description: Order status
+        data_type: TEXT
+"
+    );
+
+    let sources = changes
+        .iter()
+        .find(|change| change.file == "models/staging/_sources.yml")
+        .unwrap();
+    assert_eq!(
+        sources.after,
+        "version: 2
+
+sources:
+  - name: raw
+    database: raw
+    tables:
+      - name: orders
+        columns:
+          - name: id
+            description: Primary key
+            data_type: \"NUMBER(38,0)\"
+"
+    );
+
+    // The generated YAML is valid and documents every catalog column
+    let parsed: serde_yaml::Value = serde_yaml::from_str(&models.after).unwrap();
+    let columns = parsed["models"][0]["columns"].as_sequence().unwrap();
+    assert_eq!(columns.len(), 2);
+}
@@ -0,0 +1,80 @@
+use std::fs;
+use std::process::Command;
+use tempfile::TempDir;
+
+const MANIFEST: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/manifest/v12.json",
+    "project_name": "test_project"
+  },
+  "nodes": {
+    "model.test_project.stg_orders": {
+      "resource_type": "model",
+      "name": "stg_orders",
+      "package_name": "test_project",
+      "original_file_path": "models/staging/stg_orders.sql",
+      "unique_id": "model.test_project.stg_orders",
+      "description": "",
+      "depends_on": {"nodes": []}
+    }
+  }
+}"#;
+
+// The catalog still contains a model that was removed from the manifest
+const CATALOG: &str = r#"{
+  "metadata": {
+    "dbt_schema_version": "https://schemas.getdbt.com/dbt/catalog/v1.json",
+    "dbt_version": "1.10.0",
+    "generated_at": "2025-01-01T00:00:00.000000Z",
+    "env": {}
+  },
+  "nodes": {
+    "model.test_project.old_orders": {
+      "unique_id": "model.test_project.old_orders",
+      "metadata": {
+        "type": "BASE TABLE",
+        "database": "analytics",
+        "schema": "public",
+        "name": "old_orders"
+      },
+      "columns": {},
+      "stats": {}
+    }
+  },
+  "sources": {}
+}"#;
+
+const CONFIG: &str = r#"
+manifest_tests:
+  - type: has_description
+    applies_to: ["models"]
+
+catalog_tests:
+  - type: columns_all_documented
+"#;
+
+#[test]
+fn test_stats_json_output_is_valid_json() {
+    let temp_dir = TempDir::new().unwrap();
+    let root = temp_dir.path();
+    fs::create_dir_all(root.join("target")).unwrap();
+    fs::write(root.join("target/manifest.json"), MANIFEST).unwrap();
+    fs::write(root.join("target/catalog.json"), CATALOG).unwrap();
+    fs::write(root.join("dbtective.yml"), CONFIG).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_dbtective"))
+        .args(["stats", "-f", "json", "--entry-point"])
+        .arg(root)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stats: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout should only contain the JSON report");
+    assert_eq!(stats["models"], 1);
+    assert_eq!(stats["findings_per_rule"][0]["name"], "has_description");
+
+    // Warnings about the stale catalog go to stderr
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("No matching manifest node found for catalog node 'old_orders'"));
+}